@@ -0,0 +1,302 @@
+use core::cmp::{Eq, Ord};
+
+use std::cmp::{FuzzyEq, FUZZY_EPSILON};
+use numeric::*;
+use numeric::number::Number;
+use numeric::number::Number::{zero};
+
+/**
+ * An angle measured in radians
+ */
+pub enum Rad<T> = T;
+
+/**
+ * An angle measured in degrees
+ */
+pub enum Deg<T> = T;
+
+/**
+ * The base angle trait
+ *
+ * This is implemented by the `Rad` and `Deg` types, allowing functions that
+ * construct rotations to accept an angle in either unit without any
+ * ambiguity over which one is intended.
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the underlying value. Should be a floating point type.
+ */
+pub trait Angle<T>: Add<Self,Self> Sub<Self,Self> Neg<Self> {
+    /**
+     * # Return value
+     *
+     * An angle of zero
+     */
+    static pure fn zero() -> Self;
+    
+    /**
+     * # Return value
+     *
+     * The angle of a full turn, ie. `2π` radians or 360 degrees
+     */
+    static pure fn full_turn() -> Self;
+    
+    /**
+     * # Return value
+     *
+     * The angle of a half turn, ie. `π` radians or 180 degrees
+     */
+    static pure fn half_turn() -> Self;
+    
+    /**
+     * # Return value
+     *
+     * The angle of a quarter turn, ie. `π/2` radians or 90 degrees
+     */
+    static pure fn quarter_turn() -> Self;
+    
+    /**
+     * # Return value
+     *
+     * The angle measured in radians
+     */
+    pure fn to_rad(&self) -> Rad<T>;
+    
+    /**
+     * # Return value
+     *
+     * The angle measured in degrees
+     */
+    pure fn to_deg(&self) -> Deg<T>;
+    
+    /**
+     * # Return value
+     *
+     * The angle multiplied by `value`
+     */
+    pure fn mul_t(&self, value: T) -> Self;
+    
+    /**
+     * # Return value
+     *
+     * The angle divided by `value`
+     */
+    pure fn div_t(&self, value: T) -> Self;
+    
+    /**
+     * # Return value
+     *
+     * The equivalent angle in the range `[0, full_turn)`
+     */
+    pure fn wrap(&self) -> Self;
+    
+    /**
+     * # Return value
+     *
+     * The equivalent angle in the range `[-half_turn, half_turn)`
+     */
+    pure fn wrap_signed(&self) -> Self;
+    
+    /**
+     * # Return value
+     *
+     * The sine of the angle
+     */
+    pure fn sin(&self) -> T;
+    
+    /**
+     * # Return value
+     *
+     * The cosine of the angle
+     */
+    pure fn cos(&self) -> T;
+    
+    /**
+     * # Return value
+     *
+     * The tangent of the angle
+     */
+    pure fn tan(&self) -> T;
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Rad<T>: Angle<T> {
+    #[inline(always)] static pure fn zero() -> Rad<T> { Rad(zero()) }
+    #[inline(always)] static pure fn full_turn() -> Rad<T> { let pi: T = Float::pi(); Rad(pi * Number::from(2)) }
+    #[inline(always)] static pure fn half_turn() -> Rad<T> { Rad(Float::pi()) }
+    #[inline(always)] static pure fn quarter_turn() -> Rad<T> { Rad(Float::frac_pi_2()) }
+    
+    #[inline(always)] pure fn to_rad(&self) -> Rad<T> { *self }
+    #[inline(always)] pure fn to_deg(&self) -> Deg<T> { Deg(degrees(**self)) }
+    
+    #[inline(always)] pure fn mul_t(&self, value: T) -> Rad<T> { Rad(**self * value) }
+    #[inline(always)] pure fn div_t(&self, value: T) -> Rad<T> { Rad(**self / value) }
+    
+    #[inline(always)]
+    pure fn wrap(&self) -> Rad<T> {
+        let pi: T = Float::pi();
+        let full = pi * Number::from(2);
+        Rad(**self - full * floor(**self / full))
+    }
+    
+    #[inline(always)]
+    pure fn wrap_signed(&self) -> Rad<T> {
+        let pi: T = Float::pi();
+        Rad(*Rad(**self + pi).wrap() - pi)
+    }
+    
+    #[inline(always)] pure fn sin(&self) -> T { sin(**self) }
+    #[inline(always)] pure fn cos(&self) -> T { cos(**self) }
+    #[inline(always)] pure fn tan(&self) -> T { tan(**self) }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Deg<T>: Angle<T> {
+    #[inline(always)] static pure fn zero() -> Deg<T> { Deg(zero()) }
+    #[inline(always)] static pure fn full_turn() -> Deg<T> { Deg(Number::from(360)) }
+    #[inline(always)] static pure fn half_turn() -> Deg<T> { Deg(Number::from(180)) }
+    #[inline(always)] static pure fn quarter_turn() -> Deg<T> { Deg(Number::from(90)) }
+    
+    #[inline(always)] pure fn to_rad(&self) -> Rad<T> { Rad(radians(**self)) }
+    #[inline(always)] pure fn to_deg(&self) -> Deg<T> { *self }
+    
+    #[inline(always)] pure fn mul_t(&self, value: T) -> Deg<T> { Deg(**self * value) }
+    #[inline(always)] pure fn div_t(&self, value: T) -> Deg<T> { Deg(**self / value) }
+    
+    #[inline(always)]
+    pure fn wrap(&self) -> Deg<T> {
+        let full: T = Number::from(360);
+        Deg(**self - full * floor(**self / full))
+    }
+    
+    #[inline(always)]
+    pure fn wrap_signed(&self) -> Deg<T> {
+        let half: T = Number::from(180);
+        Deg(*Deg(**self + half).wrap() - half)
+    }
+    
+    #[inline(always)] pure fn sin(&self) -> T { self.to_rad().sin() }
+    #[inline(always)] pure fn cos(&self) -> T { self.to_rad().cos() }
+    #[inline(always)] pure fn tan(&self) -> T { self.to_rad().tan() }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Rad<T> {
+    /**
+     * # Return value
+     *
+     * The angle whose sine is `value`, in the range `[-π/2, π/2]`
+     */
+    #[inline(always)]
+    static pure fn asin(value: T) -> Rad<T> { Rad(asin(value)) }
+    
+    /**
+     * # Return value
+     *
+     * The angle whose cosine is `value`, in the range `[0, π]`
+     */
+    #[inline(always)]
+    static pure fn acos(value: T) -> Rad<T> { Rad(acos(value)) }
+    
+    /**
+     * # Return value
+     *
+     * The angle whose tangent is `value`, in the range `[-π/2, π/2]`
+     */
+    #[inline(always)]
+    static pure fn atan(value: T) -> Rad<T> { Rad(atan(value)) }
+    
+    /**
+     * # Return value
+     *
+     * The angle between the positive `x` axis and the point `(x, y)`, in the
+     * range `[-π, π]`
+     */
+    #[inline(always)]
+    static pure fn atan2(y: T, x: T) -> Rad<T> { Rad(atan2(y, x)) }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Rad<T>: Add<Rad<T>, Rad<T>> {
+    #[inline(always)] pure fn add(&self, other: &Rad<T>) -> Rad<T> { Rad(**self + **other) }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Rad<T>: Sub<Rad<T>, Rad<T>> {
+    #[inline(always)] pure fn sub(&self, other: &Rad<T>) -> Rad<T> { Rad(**self - **other) }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Rad<T>: Mul<T, Rad<T>> {
+    #[inline(always)] pure fn mul(&self, value: &T) -> Rad<T> { self.mul_t(*value) }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Rad<T>: Div<T, Rad<T>> {
+    #[inline(always)] pure fn div(&self, value: &T) -> Rad<T> { self.div_t(*value) }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Rad<T>: Neg<Rad<T>> {
+    #[inline(always)] pure fn neg(&self) -> Rad<T> { Rad(-**self) }
+}
+
+pub impl<T:Copy Eq> Rad<T>: Eq {
+    #[inline(always)] pure fn eq(&self, other: &Rad<T>) -> bool { **self == **other }
+    #[inline(always)] pure fn ne(&self, other: &Rad<T>) -> bool { **self != **other }
+}
+
+pub impl<T:Copy Ord> Rad<T>: Ord {
+    #[inline(always)] pure fn lt(&self, other: &Rad<T>) -> bool { **self <  **other }
+    #[inline(always)] pure fn le(&self, other: &Rad<T>) -> bool { **self <= **other }
+    #[inline(always)] pure fn ge(&self, other: &Rad<T>) -> bool { **self >= **other }
+    #[inline(always)] pure fn gt(&self, other: &Rad<T>) -> bool { **self >  **other }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Rad<T>: FuzzyEq<T> {
+    #[inline(always)]
+    pure fn fuzzy_eq(&self, other: &Rad<T>) -> bool {
+        self.fuzzy_eq_eps(other, &Number::from(FUZZY_EPSILON))
+    }
+    
+    #[inline(always)]
+    pure fn fuzzy_eq_eps(&self, other: &Rad<T>, epsilon: &T) -> bool {
+        (**self).fuzzy_eq_eps(&**other, epsilon)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Deg<T>: Add<Deg<T>, Deg<T>> {
+    #[inline(always)] pure fn add(&self, other: &Deg<T>) -> Deg<T> { Deg(**self + **other) }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Deg<T>: Sub<Deg<T>, Deg<T>> {
+    #[inline(always)] pure fn sub(&self, other: &Deg<T>) -> Deg<T> { Deg(**self - **other) }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Deg<T>: Mul<T, Deg<T>> {
+    #[inline(always)] pure fn mul(&self, value: &T) -> Deg<T> { self.mul_t(*value) }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Deg<T>: Div<T, Deg<T>> {
+    #[inline(always)] pure fn div(&self, value: &T) -> Deg<T> { self.div_t(*value) }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Deg<T>: Neg<Deg<T>> {
+    #[inline(always)] pure fn neg(&self) -> Deg<T> { Deg(-**self) }
+}
+
+pub impl<T:Copy Eq> Deg<T>: Eq {
+    #[inline(always)] pure fn eq(&self, other: &Deg<T>) -> bool { **self == **other }
+    #[inline(always)] pure fn ne(&self, other: &Deg<T>) -> bool { **self != **other }
+}
+
+pub impl<T:Copy Ord> Deg<T>: Ord {
+    #[inline(always)] pure fn lt(&self, other: &Deg<T>) -> bool { **self <  **other }
+    #[inline(always)] pure fn le(&self, other: &Deg<T>) -> bool { **self <= **other }
+    #[inline(always)] pure fn ge(&self, other: &Deg<T>) -> bool { **self >= **other }
+    #[inline(always)] pure fn gt(&self, other: &Deg<T>) -> bool { **self >  **other }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Deg<T>: FuzzyEq<T> {
+    #[inline(always)]
+    pure fn fuzzy_eq(&self, other: &Deg<T>) -> bool {
+        self.fuzzy_eq_eps(other, &Number::from(FUZZY_EPSILON))
+    }
+    
+    #[inline(always)]
+    pure fn fuzzy_eq_eps(&self, other: &Deg<T>, epsilon: &T) -> bool {
+        (**self).fuzzy_eq_eps(&**other, epsilon)
+    }
+}
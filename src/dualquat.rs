@@ -0,0 +1,329 @@
+use core::cmp::Eq;
+
+use std::cmp::{FuzzyEq, FUZZY_EPSILON};
+use numeric::*;
+use numeric::number::Number;
+use numeric::number::Number::{zero,one};
+
+use mat::{
+    Mat4,
+    Matrix4,
+    mat4,
+    dmat4,
+};
+
+use quat::{Quat, quat, dquat};
+
+use vec::{
+    Vec3,
+    Vector3,
+    NumericVector,
+    EuclideanVector,
+    vec3,
+    dvec3,
+};
+
+/**
+ * A dual quaternion, representing a rigid transformation
+ *
+ * The real part holds the rotation, and the dual part holds the translation
+ * in the form `t * real / 2`, where `t` is the translation as a pure
+ * quaternion. Unlike a separate rotation and translation, dual quaternions
+ * can be blended with a weighted sum without distorting the result, which
+ * makes them suited to skinning.
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the components. Should be a floating point type.
+ *
+ * # Fields
+ *
+ * * `real` - the real part. This should be normalized.
+ * * `dual` - the dual part. This should be orthogonal to `real`.
+ */
+#[deriving_eq]
+pub struct DualQuat<T> { real: Quat<T>, dual: Quat<T> }
+
+pub impl<T:Copy Float FuzzyEq<T>> DualQuat<T> {
+    /**
+     * Construct the dual quaternion from its real and dual parts
+     */
+    #[inline(always)]
+    static pure fn new(real: Quat<T>, dual: Quat<T>) -> DualQuat<T> {
+        DualQuat { real: real, dual: dual }
+    }
+    
+    /**
+     * # Return value
+     *
+     * The dual quaternion that leaves every point unchanged
+     */
+    #[inline(always)]
+    static pure fn identity() -> DualQuat<T> {
+        DualQuat::new(Quat::identity(), Quat::zero())
+    }
+    
+    /**
+     * Construct the dual quaternion that rotates, then translates
+     *
+     * # Arguments
+     *
+     * * `rotation` - the rotation. This should be normalized.
+     * * `translation` - the translation along each axis
+     */
+    #[inline(always)]
+    static pure fn from_rotation_translation(rotation: Quat<T>, translation: &Vec3<T>) -> DualQuat<T> {
+        let _2: T = Number::from(2);
+        let t = Quat::from_sv(zero(), *translation);
+        DualQuat::new(rotation, t.mul_q(&rotation).div_t(_2))
+    }
+    
+    /**
+     * Construct the dual quaternion from a rigid transformation matrix
+     *
+     * The upper-left 3 x 3 part of the matrix should be a rotation, and the
+     * bottom row should be `[0, 0, 0, 1]`.
+     */
+    #[inline(always)]
+    static pure fn from_mat4(m: &Mat4<T>) -> DualQuat<T> {
+        DualQuat::from_rotation_translation(m.to_quat(), &Vector3::new(m[3][0], m[3][1], m[3][2]))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The rotation of the transformation
+     */
+    #[inline(always)]
+    pure fn rotation(&self) -> Quat<T> { self.real }
+    
+    /**
+     * # Return value
+     *
+     * The translation of the transformation
+     */
+    #[inline(always)]
+    pure fn translation(&self) -> Vec3<T> {
+        self.dual.mul_q(&self.real.conjugate()).v.mul_t(Number::from(2))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The transformation that applies `other` and then this transformation,
+     * ie. the equivalent of `self.to_mat4() * other.to_mat4()`
+     */
+    #[inline(always)]
+    pure fn mul_dq(&self, other: &DualQuat<T>) -> DualQuat<T> {
+        DualQuat::new(self.real.mul_q(&other.real),
+                      self.real.mul_q(&other.dual).add_q(&self.dual.mul_q(&other.real)))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The dual quaternion with the quaternion conjugate of each of its
+     * parts. For a normalized dual quaternion this is the inverse.
+     */
+    #[inline(always)]
+    pure fn conjugate(&self) -> DualQuat<T> {
+        DualQuat::new(self.real.conjugate(), self.dual.conjugate())
+    }
+    
+    /**
+     * # Return value
+     *
+     * The multiplicative inverse of the dual quaternion
+     */
+    #[inline(always)]
+    pure fn inverse(&self) -> DualQuat<T> {
+        let real = self.real.inverse();
+        DualQuat::new(real, -real.mul_q(&self.dual).mul_q(&real))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The normalized dual quaternion, with a real part of unit length and a
+     * dual part that is orthogonal to the real part
+     */
+    #[inline(always)]
+    pure fn normalize(&self) -> DualQuat<T> {
+        let magnitude = self.real.magnitude();
+        let real = self.real.div_t(magnitude);
+        let dual = self.dual.div_t(magnitude);
+        
+        DualQuat::new(real, dual.sub_q(&real.mul_t(real.dot(&dual))))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The point transformed by the rotation and translation
+     */
+    #[inline(always)]
+    pure fn transform_point(&self, point: &Vec3<T>) -> Vec3<T> {
+        self.real.mul_v(point).add_v(&self.translation())
+    }
+    
+    /**
+     * # Return value
+     *
+     * The vector transformed by the rotation. Unlike a point, a vector is not
+     * affected by the translation.
+     */
+    #[inline(always)]
+    pure fn transform_vector(&self, vec: &Vec3<T>) -> Vec3<T> {
+        self.real.mul_v(vec)
+    }
+    
+    /**
+     * # Return value
+     *
+     * The dual quaternion raised to the power of `n`. Every rigid
+     * transformation is a rotation around an axis combined with a
+     * translation along the same axis (a screw motion), and this scales both
+     * the angle and the distance of the screw motion by `n`. The dual
+     * quaternion should be normalized.
+     */
+    pure fn pow(&self, n: T) -> DualQuat<T> {
+        let _2: T = Number::from(2);
+        let sin_half = self.real.v.length();
+        
+        if sin_half.fuzzy_eq(&zero()) {
+            // there is no rotation, so only the translation is scaled. The
+            // real part is either the identity or its negation, and the dual
+            // part is negated along with it to keep the same translation.
+            let dual = if self.real.s < zero() { -self.dual } else { self.dual };
+            DualQuat::new(Quat::identity(), dual.mul_t(n))
+        } else {
+            // extract the half angle, distance, axis and moment of the screw
+            let half = atan2(sin_half, self.real.s);
+            let axis = self.real.v.div_t(sin_half);
+            let distance = -_2 * self.dual.s / sin_half;
+            let moment = self.dual.v.sub_v(&axis.mul_t(distance / _2 * self.real.s))
+                                    .div_t(sin_half);
+            
+            let half = half * n;
+            let distance = distance * n;
+            let sin_half = sin(half);
+            let cos_half = cos(half);
+            
+            DualQuat::new(Quat::from_sv(cos_half, axis.mul_t(sin_half)),
+                          Quat::from_sv(-distance / _2 * sin_half,
+                                        moment.mul_t(sin_half).add_v(&axis.mul_t(distance / _2 * cos_half))))
+        }
+    }
+    
+    /**
+     * Screw linear interpolation
+     *
+     * Interpolate between the dual quaternion and `other` along the screw
+     * motion that takes one to the other, which moves with a constant
+     * angular and linear velocity. The interpolation takes the shortest
+     * path. Both dual quaternions should be normalized.
+     *
+     * # Return value
+     *
+     * The interpolated dual quaternion
+     */
+    #[inline(always)]
+    pure fn sclerp(&self, other: &DualQuat<T>, amount: T) -> DualQuat<T> {
+        // take the shortest path by negating the whole of `other`, not just
+        // its real part, so that it still represents the same transformation
+        let other = if self.real.dot(&other.real) < zero() { -*other } else { *other };
+        
+        self.mul_dq(&self.conjugate().mul_dq(&other).pow(amount))
+    }
+    
+    /**
+     * Convert the dual quaternion to a 4 x 4 rigid transformation matrix
+     */
+    #[inline(always)]
+    pure fn to_mat4(&self) -> Mat4<T> {
+        let r = self.real.to_mat3();
+        let t = self.translation();
+        
+        Matrix4::new(r[0][0], r[0][1], r[0][2], zero(),
+                     r[1][0], r[1][1], r[1][2], zero(),
+                     r[2][0], r[2][1], r[2][2], zero(),
+                         t.x,     t.y,     t.z,  one())
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> DualQuat<T>: Neg<DualQuat<T>> {
+    #[inline(always)]
+    pure fn neg(&self) -> DualQuat<T> {
+        DualQuat::new(-self.real, -self.dual)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> DualQuat<T>: FuzzyEq<T> {
+    #[inline(always)]
+    pure fn fuzzy_eq(&self, other: &DualQuat<T>) -> bool {
+        self.fuzzy_eq_eps(other, &Number::from(FUZZY_EPSILON))
+    }
+    
+    #[inline(always)]
+    pure fn fuzzy_eq_eps(&self, other: &DualQuat<T>, epsilon: &T) -> bool {
+        self.real.fuzzy_eq_eps(&other.real, epsilon) &&
+        self.dual.fuzzy_eq_eps(&other.dual, epsilon)
+    }
+}
+
+/**
+ * Dual quaternion linear blending
+ *
+ * Blend a set of rigid transformations by normalizing their weighted sum.
+ * Each dual quaternion is negated if necessary to lie in the same hemisphere
+ * as the first, so that the blend takes the shortest path.
+ *
+ * # Arguments
+ *
+ * * `dqs` - the transformations to blend. These should be normalized.
+ * * `weights` - the weight of each transformation. There should be one
+ *               weight for each dual quaternion.
+ *
+ * # Return value
+ *
+ * The blended transformation
+ *
+ * - [Kavan et al., Skinning with Dual Quaternions]
+ *   (http://www.cs.utah.edu/~ladislav/kavan07skinning/kavan07skinning.pdf)
+ */
+pub pure fn dlb<T:Copy Float FuzzyEq<T>>(dqs: &[DualQuat<T>], weights: &[T]) -> DualQuat<T> {
+    assert dqs.len() == weights.len();
+    
+    let mut real = Quat::zero();
+    let mut dual = Quat::zero();
+    
+    for dqs.eachi |i, dq| {
+        let weight = if dq.real.dot(&dqs[0].real) < zero() { -weights[i] } else { weights[i] };
+        
+        real = real.add_q(&dq.real.mul_t(weight));
+        dual = dual.add_q(&dq.dual.mul_t(weight));
+    }
+    
+    DualQuat::new(real, dual).normalize()
+}
+
+// Type aliases following the nomenclature of the GLSL-style types
+
+pub type dualquat  = DualQuat<f32>;     /// a single-precision floating-point dual quaternion
+pub type ddualquat = DualQuat<f64>;     /// a double-precision floating-point dual quaternion
+
+// Static method wrappers for GLSL-style types
+
+pub impl dualquat {
+    #[inline(always)] static pure fn new(real: quat, dual: quat) -> dualquat { DualQuat::new(real, dual) }
+    #[inline(always)] static pure fn identity() -> dualquat { DualQuat::identity() }
+    #[inline(always)] static pure fn from_rotation_translation(rotation: quat, translation: &vec3) -> dualquat { DualQuat::from_rotation_translation(rotation, translation) }
+    #[inline(always)] static pure fn from_mat4(m: &mat4) -> dualquat { DualQuat::from_mat4(m) }
+}
+
+pub impl ddualquat {
+    #[inline(always)] static pure fn new(real: dquat, dual: dquat) -> ddualquat { DualQuat::new(real, dual) }
+    #[inline(always)] static pure fn identity() -> ddualquat { DualQuat::identity() }
+    #[inline(always)] static pure fn from_rotation_translation(rotation: dquat, translation: &dvec3) -> ddualquat { DualQuat::from_rotation_translation(rotation, translation) }
+    #[inline(always)] static pure fn from_mat4(m: &dmat4) -> ddualquat { DualQuat::from_mat4(m) }
+}
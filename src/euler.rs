@@ -0,0 +1,279 @@
+use core::cmp::Eq;
+
+use std::cmp::{FuzzyEq, FUZZY_EPSILON};
+use numeric::*;
+use numeric::number::Number;
+use numeric::number::Number::{zero};
+
+use angle::{Angle, Rad};
+
+use mat::{
+    Mat3,
+    Matrix,
+    Matrix3,
+    mat3,
+    dmat3,
+};
+
+use quat::{Quat, quat, dquat};
+
+/**
+ * The sequence of axes that the rotations of a set of Euler angles are
+ * applied around
+ *
+ * The first six orders use three different axes (Tait-Bryan angles), and the
+ * last six repeat the first axis (proper Euler angles).
+ */
+#[deriving_eq]
+pub enum EulerOrder {
+    XYZ, XZY, YXZ, YZX, ZXY, ZYX,
+    XYX, XZX, YXY, YZY, ZXZ, ZYZ,
+}
+
+pub impl EulerOrder {
+    /**
+     * # Return value
+     *
+     * The indices of the three axes, in the order that they are rotated
+     * around
+     */
+    #[inline(always)]
+    pure fn axes(&self) -> (uint, uint, uint) {
+        match *self {
+            XYZ => (0, 1, 2), XZY => (0, 2, 1),
+            YXZ => (1, 0, 2), YZX => (1, 2, 0),
+            ZXY => (2, 0, 1), ZYX => (2, 1, 0),
+            XYX => (0, 1, 0), XZX => (0, 2, 0),
+            YXY => (1, 0, 1), YZY => (1, 2, 1),
+            ZXZ => (2, 0, 2), ZYZ => (2, 1, 2),
+        }
+    }
+}
+
+/**
+ * The frame of reference that the axes of a set of Euler angles belong to
+ */
+#[deriving_eq]
+pub enum EulerFrame {
+    /// Each rotation is around an axis of the original, fixed frame
+    Extrinsic,
+    /// Each rotation is around an axis of the frame produced by the previous
+    /// rotations
+    Intrinsic,
+}
+
+/**
+ * A rotation described by three successive angular rotations around the
+ * coordinate axes
+ *
+ * Extrinsic angles `(a, b, c)` with the order `XYZ` produce the same rotation
+ * as intrinsic angles `(c, b, a)` with the order `ZYX`. The extrinsic `XYZ`
+ * order is the one used by `Matrix3::from_angle_xyz` and
+ * `Quat::from_angle_xyz`.
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the angles. Should be a floating point type.
+ *
+ * # Fields
+ *
+ * * `a` - the rotation around the first axis of the order
+ * * `b` - the rotation around the second axis of the order
+ * * `c` - the rotation around the third axis of the order
+ * * `order` - the axes that the rotations are applied around
+ * * `frame` - whether the axes are fixed or rotate with the body
+ */
+#[deriving_eq]
+pub struct Euler<T> { a: Rad<T>, b: Rad<T>, c: Rad<T>, order: EulerOrder, frame: EulerFrame }
+
+pub impl<T:Copy Float FuzzyEq<T>> Euler<T> {
+    /**
+     * Construct a set of Euler angles
+     *
+     * # Arguments
+     *
+     * * `a` - the rotation around the first axis of `order`
+     * * `b` - the rotation around the second axis of `order`
+     * * `c` - the rotation around the third axis of `order`
+     * * `order` - the axes that the rotations are applied around
+     * * `frame` - whether the axes are fixed or rotate with the body
+     */
+    #[inline(always)]
+    static pure fn new<A:Angle<T>>(a: A, b: A, c: A, order: EulerOrder, frame: EulerFrame) -> Euler<T> {
+        Euler { a: a.to_rad(), b: b.to_rad(), c: c.to_rad(), order: order, frame: frame }
+    }
+    
+    /**
+     * Extract the Euler angles of a rotation matrix
+     *
+     * The second angle is in the range `[-π/2, π/2]` for Tait-Bryan orders,
+     * and `[0, π]` for proper Euler orders. The other two angles are in the
+     * range `[-π, π]`. At a gimbal lock the first and third axes coincide and
+     * only their combined rotation can be recovered, in which case it is
+     * assigned entirely to the first angle and the third angle is zero.
+     *
+     * # Arguments
+     *
+     * * `m` - the rotation matrix. This should be orthonormal, with a
+     *         determinant of `1`.
+     * * `order` - the axes of the resulting angles
+     * * `frame` - the frame of reference of the resulting angles
+     */
+    static pure fn from_mat3(m: &Mat3<T>, order: EulerOrder, frame: EulerFrame) -> Euler<T> {
+        // Based on Ken Shoemake's "Euler Angle Conversion", Graphics Gems IV
+        //
+        // The angles are extracted as extrinsic angles, because intrinsic
+        // angles are equivalent to extrinsic angles around the reversed axes,
+        // in reverse order.
+        let (first, j, last) = order.axes();
+        let (i, k) = match frame { Extrinsic => (first, last), Intrinsic => (last, first) };
+        
+        let (a, b, c, locked) = if i == k {
+            // proper Euler angles, rotating around `i`, `j`, then `i` again
+            let k = 3 - i - j;
+            let sy = sqrt(m[j][i] * m[j][i] + m[k][i] * m[k][i]);
+            if sy.fuzzy_eq(&zero()) {
+                (atan2(-m[k][j], m[j][j]), atan2(sy, m[i][i]), zero(), true)
+            } else {
+                (atan2(m[j][i], m[k][i]), atan2(sy, m[i][i]), atan2(m[i][j], -m[i][k]), false)
+            }
+        } else {
+            // Tait-Bryan angles, rotating around `i`, `j`, then `k`
+            let cy = sqrt(m[i][i] * m[i][i] + m[i][j] * m[i][j]);
+            if cy.fuzzy_eq(&zero()) {
+                (atan2(-m[k][j], m[j][j]), atan2(-m[i][k], cy), zero(), true)
+            } else {
+                (atan2(m[j][k], m[k][k]), atan2(-m[i][k], cy), atan2(m[i][j], m[i][i]), false)
+            }
+        };
+        
+        // the formulas above assume that `j` follows `i` cyclically, so the
+        // angles are reversed for the other orders
+        let (a, b, c) = if (j + 3 - i) % 3 == 1 { (a, b, c) } else { (-a, -b, -c) };
+        
+        match frame {
+            Extrinsic => Euler::new(Rad(a), Rad(b), Rad(c), order, frame),
+            Intrinsic if locked => {
+                // the rotation around the last axis is equivalent to a
+                // rotation around the first axis, with the sign depending on
+                // which way the second rotation has aligned them
+                let s = axis_mat3(j, Rad(b))[last][first];
+                Euler::new(Rad(a * s), Rad(b), Rad(zero()), order, frame)
+            }
+            Intrinsic => Euler::new(Rad(c), Rad(b), Rad(a), order, frame),
+        }
+    }
+    
+    /**
+     * Extract the Euler angles of a rotation quaternion
+     *
+     * See `Euler::from_mat3` for the ranges of the resulting angles.
+     *
+     * # Arguments
+     *
+     * * `q` - the rotation quaternion. This should be normalized.
+     * * `order` - the axes of the resulting angles
+     * * `frame` - the frame of reference of the resulting angles
+     */
+    #[inline(always)]
+    static pure fn from_quat(q: &Quat<T>, order: EulerOrder, frame: EulerFrame) -> Euler<T> {
+        Euler::from_mat3(&q.to_mat3(), order, frame)
+    }
+    
+    /**
+     * # Return value
+     *
+     * The same rotation, expressed with a different order and frame
+     */
+    #[inline(always)]
+    pure fn to_order(&self, order: EulerOrder, frame: EulerFrame) -> Euler<T> {
+        Euler::from_mat3(&self.to_mat3(), order, frame)
+    }
+    
+    /**
+     * # Return value
+     *
+     * The rotation matrix of the angles
+     */
+    pure fn to_mat3(&self) -> Mat3<T> {
+        let (i, j, k) = self.order.axes();
+        let ra = axis_mat3(i, self.a);
+        let rb = axis_mat3(j, self.b);
+        let rc = axis_mat3(k, self.c);
+        
+        match self.frame {
+            Extrinsic => rc.mul_m(&rb).mul_m(&ra),
+            Intrinsic => ra.mul_m(&rb).mul_m(&rc),
+        }
+    }
+    
+    /**
+     * # Return value
+     *
+     * The rotation quaternion of the angles
+     */
+    pure fn to_quat(&self) -> Quat<T> {
+        let (i, j, k) = self.order.axes();
+        let qa = axis_quat(i, self.a);
+        let qb = axis_quat(j, self.b);
+        let qc = axis_quat(k, self.c);
+        
+        match self.frame {
+            Extrinsic => qc.mul_q(&qb).mul_q(&qa),
+            Intrinsic => qa.mul_q(&qb).mul_q(&qc),
+        }
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Euler<T>: FuzzyEq<T> {
+    #[inline(always)]
+    pure fn fuzzy_eq(&self, other: &Euler<T>) -> bool {
+        self.fuzzy_eq_eps(other, &Number::from(FUZZY_EPSILON))
+    }
+    
+    #[inline(always)]
+    pure fn fuzzy_eq_eps(&self, other: &Euler<T>, epsilon: &T) -> bool {
+        self.order == other.order &&
+        self.frame == other.frame &&
+        self.a.fuzzy_eq_eps(&other.a, epsilon) &&
+        self.b.fuzzy_eq_eps(&other.b, epsilon) &&
+        self.c.fuzzy_eq_eps(&other.c, epsilon)
+    }
+}
+
+#[inline(always)]
+priv pure fn axis_mat3<T:Copy Float FuzzyEq<T>>(axis: uint, theta: Rad<T>) -> Mat3<T> {
+    match axis {
+        0 => Matrix3::from_angle_x(theta),
+        1 => Matrix3::from_angle_y(theta),
+        _ => Matrix3::from_angle_z(theta),
+    }
+}
+
+#[inline(always)]
+priv pure fn axis_quat<T:Copy Float FuzzyEq<T>>(axis: uint, theta: Rad<T>) -> Quat<T> {
+    match axis {
+        0 => Quat::from_angle_x(theta),
+        1 => Quat::from_angle_y(theta),
+        _ => Quat::from_angle_z(theta),
+    }
+}
+
+// Type aliases following the nomenclature of the GLSL-style types
+
+pub type euler  = Euler<f32>;   /// a set of single-precision floating-point Euler angles
+pub type deuler = Euler<f64>;   /// a set of double-precision floating-point Euler angles
+
+// Static method wrappers for GLSL-style types
+
+pub impl euler {
+    #[inline(always)] static pure fn new<A:Angle<f32>>(a: A, b: A, c: A, order: EulerOrder, frame: EulerFrame) -> euler { Euler::new(a, b, c, order, frame) }
+    #[inline(always)] static pure fn from_mat3(m: &mat3, order: EulerOrder, frame: EulerFrame) -> euler { Euler::from_mat3(m, order, frame) }
+    #[inline(always)] static pure fn from_quat(q: &quat, order: EulerOrder, frame: EulerFrame) -> euler { Euler::from_quat(q, order, frame) }
+}
+
+pub impl deuler {
+    #[inline(always)] static pure fn new<A:Angle<f64>>(a: A, b: A, c: A, order: EulerOrder, frame: EulerFrame) -> deuler { Euler::new(a, b, c, order, frame) }
+    #[inline(always)] static pure fn from_mat3(m: &dmat3, order: EulerOrder, frame: EulerFrame) -> deuler { Euler::from_mat3(m, order, frame) }
+    #[inline(always)] static pure fn from_quat(q: &dquat, order: EulerOrder, frame: EulerFrame) -> deuler { Euler::from_quat(q, order, frame) }
+}
@@ -0,0 +1,244 @@
+use core::cmp::Eq;
+
+use std::cmp::{FuzzyEq, FUZZY_EPSILON};
+use numeric::*;
+use numeric::number::Number;
+use numeric::number::Number::{zero};
+
+use mat::{Mat4, Matrix};
+use plane::Plane;
+use projection;
+
+use vec::{
+    Vec3,
+    Vector3,
+    NumericVector,
+};
+
+/**
+ * The result of testing a point or a volume against a frustum
+ */
+#[deriving_eq]
+pub enum Intersection {
+    /// completely inside the frustum
+    Inside,
+    /// completely outside the frustum
+    Outside,
+    /// partially inside the frustum, crossing one or more of its planes
+    Intersecting,
+}
+
+/**
+ * A view frustum, bounded by six planes
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the components. Should be a floating point type.
+ *
+ * # Fields
+ *
+ * The bounding planes of the frustum. Each plane is normalized and faces
+ * towards the inside of the frustum.
+ */
+#[deriving_eq]
+pub struct Frustum<T> {
+    left:   Plane<T>,
+    right:  Plane<T>,
+    bottom: Plane<T>,
+    top:    Plane<T>,
+    near:   Plane<T>,
+    far:    Plane<T>,
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Frustum<T> {
+    /**
+     * Construct the frustum from its bounding planes
+     */
+    #[inline(always)]
+    static pure fn new(left: Plane<T>, right: Plane<T>,
+                       bottom: Plane<T>, top: Plane<T>,
+                       near: Plane<T>, far: Plane<T>) -> Frustum<T> {
+        Frustum {
+            left:   left,
+            right:  right,
+            bottom: bottom,
+            top:    top,
+            near:   near,
+            far:    far,
+        }
+    }
+    
+    /**
+     * Extract the planes of the frustum from a projection or a combined
+     * view-projection matrix that maps depth to the range [-1, 1]
+     *
+     * This uses the method described by Gil Gribb and Klaus Hartmann in
+     * [Fast Extraction of Viewing Frustum Planes from the World-View-Projection
+     * Matrix](http://www.cs.otago.ac.nz/postgrads/alexis/planeExtraction.pdf).
+     * The planes are in the space that the matrix transforms from, so a
+     * projection matrix gives a frustum in eye space and a view-projection
+     * matrix gives a frustum in world space.
+     */
+    #[inline(always)]
+    static pure fn from_mat4(mat: &Mat4<T>) -> Frustum<T> {
+        let r0 = mat.row(0);
+        let r1 = mat.row(1);
+        let r2 = mat.row(2);
+        let r3 = mat.row(3);
+        
+        Frustum::new(Plane::from_vec4(&r3.add_v(&r0)).normalize(),
+                     Plane::from_vec4(&r3.sub_v(&r0)).normalize(),
+                     Plane::from_vec4(&r3.add_v(&r1)).normalize(),
+                     Plane::from_vec4(&r3.sub_v(&r1)).normalize(),
+                     Plane::from_vec4(&r3.add_v(&r2)).normalize(),
+                     Plane::from_vec4(&r3.sub_v(&r2)).normalize())
+    }
+    
+    /**
+     * Extract the planes of the frustum from a projection or a combined
+     * view-projection matrix that maps depth to the range [0, 1]
+     */
+    #[inline(always)]
+    static pure fn from_mat4_zo(mat: &Mat4<T>) -> Frustum<T> {
+        let r0 = mat.row(0);
+        let r1 = mat.row(1);
+        let r2 = mat.row(2);
+        let r3 = mat.row(3);
+        
+        Frustum::new(Plane::from_vec4(&r3.add_v(&r0)).normalize(),
+                     Plane::from_vec4(&r3.sub_v(&r0)).normalize(),
+                     Plane::from_vec4(&r3.add_v(&r1)).normalize(),
+                     Plane::from_vec4(&r3.sub_v(&r1)).normalize(),
+                     Plane::from_vec4(&r2).normalize(),
+                     Plane::from_vec4(&r3.sub_v(&r2)).normalize())
+    }
+    
+    /**
+     * Construct the eye space frustum described by the same parameters as
+     * `projection::frustum`
+     */
+    #[inline(always)]
+    static pure fn from_bounds(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Frustum<T> {
+        Frustum::from_mat4(&projection::frustum(left, right, bottom, top, near, far))
+    }
+    
+    /**
+     * # Return value
+     *
+     * `Inside` if the point is within the frustum or on its boundary,
+     * otherwise `Outside`
+     */
+    #[inline(always)]
+    pure fn classify_point(&self, point: &Vec3<T>) -> Intersection {
+        let planes = [self.left, self.right, self.bottom, self.top, self.near, self.far];
+        let mut result = Inside;
+        
+        for uint::range(0, 6) |i| {
+            if planes[i].distance(point) < zero() {
+                result = Outside;
+            }
+        }
+        
+        result
+    }
+    
+    /**
+     * Test a sphere against the frustum
+     *
+     * # Arguments
+     *
+     * * `center` - the center of the sphere
+     * * `radius` - the radius of the sphere
+     */
+    #[inline(always)]
+    pure fn classify_sphere(&self, center: &Vec3<T>, radius: T) -> Intersection {
+        let planes = [self.left, self.right, self.bottom, self.top, self.near, self.far];
+        let mut result = Inside;
+        
+        for uint::range(0, 6) |i| {
+            let dist = planes[i].distance(center);
+            
+            if dist < -radius {
+                result = Outside;
+            } else if dist < radius && result == Inside {
+                result = Intersecting;
+            }
+        }
+        
+        result
+    }
+    
+    /**
+     * Test an axis-aligned bounding box against the frustum
+     *
+     * This is conservative: a box near the corner of the frustum may be
+     * reported as `Intersecting` even though it is actually outside.
+     *
+     * # Arguments
+     *
+     * * `min` - the corner of the box with the smallest components
+     * * `max` - the corner of the box with the largest components
+     */
+    #[inline(always)]
+    pure fn classify_aabb(&self, min: &Vec3<T>, max: &Vec3<T>) -> Intersection {
+        let planes = [self.left, self.right, self.bottom, self.top, self.near, self.far];
+        let mut result = Inside;
+        
+        for uint::range(0, 6) |i| {
+            let n = planes[i].n;
+            
+            // the corners of the box furthest along and furthest against the
+            // direction of the plane's normal
+            let p: Vec3<T> = Vector3::new(if n.x >= zero() { max.x } else { min.x },
+                                          if n.y >= zero() { max.y } else { min.y },
+                                          if n.z >= zero() { max.z } else { min.z });
+            let q: Vec3<T> = Vector3::new(if n.x >= zero() { min.x } else { max.x },
+                                          if n.y >= zero() { min.y } else { max.y },
+                                          if n.z >= zero() { min.z } else { max.z });
+            
+            if planes[i].distance(&p) < zero() {
+                result = Outside;
+            } else if planes[i].distance(&q) < zero() && result == Inside {
+                result = Intersecting;
+            }
+        }
+        
+        result
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Frustum<T>: FuzzyEq<T> {
+    #[inline(always)]
+    pure fn fuzzy_eq(&self, other: &Frustum<T>) -> bool {
+        self.fuzzy_eq_eps(other, &Number::from(FUZZY_EPSILON))
+    }
+    
+    #[inline(always)]
+    pure fn fuzzy_eq_eps(&self, other: &Frustum<T>, epsilon: &T) -> bool {
+        self.left.fuzzy_eq_eps(&other.left, epsilon) &&
+        self.right.fuzzy_eq_eps(&other.right, epsilon) &&
+        self.bottom.fuzzy_eq_eps(&other.bottom, epsilon) &&
+        self.top.fuzzy_eq_eps(&other.top, epsilon) &&
+        self.near.fuzzy_eq_eps(&other.near, epsilon) &&
+        self.far.fuzzy_eq_eps(&other.far, epsilon)
+    }
+}
+
+// Type aliases following the nomenclature of the GLSL-style types
+
+pub type frustum  = Frustum<f32>;   /// a single-precision floating-point frustum
+pub type dfrustum = Frustum<f64>;   /// a double-precision floating-point frustum
+
+// Static method wrappers for GLSL-style types
+
+pub impl frustum {
+    #[inline(always)] static pure fn from_mat4(mat: &Mat4<f32>) -> frustum { Frustum::from_mat4(mat) }
+    #[inline(always)] static pure fn from_mat4_zo(mat: &Mat4<f32>) -> frustum { Frustum::from_mat4_zo(mat) }
+    #[inline(always)] static pure fn from_bounds(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> frustum { Frustum::from_bounds(left, right, bottom, top, near, far) }
+}
+
+pub impl dfrustum {
+    #[inline(always)] static pure fn from_mat4(mat: &Mat4<f64>) -> dfrustum { Frustum::from_mat4(mat) }
+    #[inline(always)] static pure fn from_mat4_zo(mat: &Mat4<f64>) -> dfrustum { Frustum::from_mat4_zo(mat) }
+    #[inline(always)] static pure fn from_bounds(left: f64, right: f64, bottom: f64, top: f64, near: f64, far: f64) -> dfrustum { Frustum::from_bounds(left, right, bottom, top, near, far) }
+}
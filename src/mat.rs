@@ -1,12 +1,22 @@
 use core::cmp::Eq;
-use std::cmp::FuzzyEq;
+use std::cmp::{FuzzyEq, FUZZY_EPSILON};
+use numeric::*;
+use numeric::number::Number;
+use numeric::number::Number::{zero};
 
-use vec::Vec3;
+use angle::Angle;
+use vec::{Vec3, MutableVector};
 use quat::Quat;
 
 pub use mat2::{Mat2, mat2, dmat2};
 pub use mat3::{Mat3, mat3, dmat3};
-pub use mat4::{Mat4, mat4, dmat4};
+pub use mat4::{Mat4, Decomposed, mat4, dmat4};
+pub use mat2x3::{Mat2x3, mat2x3, dmat2x3};
+pub use mat2x4::{Mat2x4, mat2x4, dmat2x4};
+pub use mat3x2::{Mat3x2, mat3x2, dmat3x2};
+pub use mat3x4::{Mat3x4, mat3x4, dmat3x4};
+pub use mat4x2::{Mat4x2, mat4x2, dmat4x2};
+pub use mat4x3::{Mat4x3, mat4x3, dmat4x3};
 
 /**
  * The base square matrix trait
@@ -118,6 +128,100 @@ pub trait Matrix<T,V>: Index<uint, V> Eq Neg<Self> {
      */
     pure fn inverse(&self) -> Option<Self>;
     
+    /**
+     * Compute the LU decomposition of the matrix, with partial pivoting
+     *
+     * # Return value
+     *
+     * A tuple `(p, l, u)`, where `p` is a permutation matrix, `l` is a lower
+     * triangular matrix with ones on its diagonal, and `u` is an upper
+     * triangular matrix, such that `p * self == l * u`
+     */
+    pure fn lu(&self) -> (Self, Self, Self);
+    
+    /**
+     * Solve the linear system `self * x == b` for `x`. This uses the LU
+     * decomposition of the matrix, and is faster and more accurate than
+     * multiplying `b` by the inverse.
+     *
+     * # Return value
+     *
+     * * `Some(x)` - if the system has a unique solution
+     * * `None` - if the matrix is singular
+     */
+    pure fn solve(&self, b: &V) -> Option<V>;
+    
+    /**
+     * Compute the QR decomposition of the matrix using Householder
+     * reflections
+     *
+     * # Return value
+     *
+     * A tuple `(q, r)`, where `q` is an orthogonal matrix and `r` is an upper
+     * triangular matrix with a non-negative diagonal, such that
+     * `self == q * r`
+     */
+    pure fn qr(&self) -> (Self, Self);
+    
+    /**
+     * Compute the singular value decomposition of the matrix
+     *
+     * # Return value
+     *
+     * A tuple `(u, s, vt)`, where `u` and `vt` are orthogonal matrices and `s`
+     * holds the singular values sorted in descending order, such that
+     * `self == u * diag(s) * vt`
+     */
+    pure fn svd(&self) -> (Self, V, Self);
+    
+    /**
+     * Compute the singular value decomposition of the matrix, with `u` and
+     * `vt` guaranteed to be proper rotations (ie. with a determinant of `1`).
+     * This is useful for extracting the closest rotation to a matrix, for
+     * example when aligning point sets.
+     *
+     * # Return value
+     *
+     * A tuple `(u, s, vt)` in the same form as `svd`, except that the last
+     * singular value is negated if the matrix has a negative determinant
+     */
+    pure fn proper_svd(&self) -> (Self, V, Self);
+    
+    /**
+     * # Return value
+     *
+     * The Moore-Penrose pseudo-inverse of the matrix. This is equal to the
+     * inverse if the matrix is invertible, and otherwise gives the least
+     * squares solution of `self * x == b` when multiplied by `b`.
+     */
+    pure fn pseudo_inverse(&self) -> Self;
+    
+    /**
+     * # Return value
+     *
+     * The number of singular values of the matrix greater than `epsilon`
+     */
+    pure fn rank(&self, epsilon: T) -> uint;
+    
+    /**
+     * # Return value
+     *
+     * The ratio of the largest singular value of the matrix to the smallest.
+     * This is infinite if the matrix is singular.
+     */
+    pure fn condition_number(&self) -> T;
+    
+    /**
+     * Compute the polar decomposition of the matrix
+     *
+     * # Return value
+     *
+     * A tuple `(r, s)`, where `r` is a rotation and `s` is a symmetric matrix,
+     * such that `self == r * s`. If the matrix has a negative determinant the
+     * reflection is included in `s`.
+     */
+    pure fn polar(&self) -> (Self, Self);
+    
     /**
      * # Return value
      *
@@ -179,6 +283,520 @@ pub trait Matrix<T,V>: Index<uint, V> Eq Neg<Self> {
     pure fn to_ptr(&self) -> *T;
 }
 
+/**
+ * Compute the LU decomposition of the `n` x `n` matrix `m` using Doolittle's
+ * algorithm with partial pivoting. This is shared by the implementations of
+ * `Matrix::lu`.
+ *
+ * # Return value
+ *
+ * A tuple `(p, l, u, odd)` such that `p * m == l * u`, where `odd` is `true`
+ * if `p` swaps an odd number of rows
+ */
+pub pure fn lu_decompose<T:Copy Float FuzzyEq<T>,
+                         V:Copy MutableVector<T>,
+                         M:Copy MutableMatrix<T,V>>(m: &M, n: uint) -> (M, M, M, bool) {
+    let mut perm: M = Matrix::identity();
+    let mut l: M = Matrix::zero();
+    let mut u = *m;
+    let mut odd = false;
+    
+    for uint::range(0, n) |k| {
+        // Find the row with the largest element in column k
+        let mut p = k;
+        for uint::range(k + 1, n) |i| {
+            if abs(u[k][i]) > abs(u[k][p]) {
+                p = i;
+            }
+        }
+        
+        unsafe {
+            // Swap it into the pivot position, along with the multipliers
+            // that have already been found for that row
+            if p != k {
+                perm.swap_rows(p, k);
+                l.swap_rows(p, k);
+                u.swap_rows(p, k);
+                odd = !odd;
+            }
+            
+            // Eliminate the elements below the pivot, recording the
+            // multipliers in `l`
+            if u[k][k] != zero() {
+                for uint::range(k + 1, n) |i| {
+                    let f = u[k][i] / u[k][k];
+                    
+                    *l.col_mut(k).index_mut(i) = f;
+                    *u.col_mut(k).index_mut(i) = zero();
+                    
+                    for uint::range(k + 1, n) |j| {
+                        let x = u[j][i] - f * u[j][k];
+                        *u.col_mut(j).index_mut(i) = x;
+                    }
+                }
+            }
+        }
+    }
+    
+    (perm, l.add_m(&Matrix::identity()), u, odd)
+}
+
+/**
+ * # Return value
+ *
+ * `true` if the upper triangular `n` x `n` matrix `u` from an LU decomposition
+ * has a zero on its diagonal, ie. if the decomposed matrix is singular. A
+ * pivot counts as zero if it is negligible compared to the largest pivot, so
+ * that the result doesn't depend on the scale of the matrix.
+ */
+pub pure fn lu_is_singular<T:Copy Float FuzzyEq<T>,
+                           V:Copy MutableVector<T>,
+                           M:Copy MutableMatrix<T,V>>(u: &M, n: uint) -> bool {
+    let mut max: T = zero();
+    
+    for uint::range(0, n) |i| {
+        if abs(u[i][i]) > max {
+            max = abs(u[i][i]);
+        }
+    }
+    
+    let tolerance = max * Number::from(FUZZY_EPSILON);
+    let mut singular = false;
+    
+    for uint::range(0, n) |i| {
+        if abs(u[i][i]) <= tolerance {
+            singular = true;
+        }
+    }
+    
+    singular
+}
+
+/**
+ * Solve the linear system `a * x == b` for `x`, given the LU decomposition
+ * `(p, l, u)` of the `n` x `n` matrix `a`
+ *
+ * # Return value
+ *
+ * * `Some(x)` - if the system has a unique solution
+ * * `None` - if `a` is singular
+ */
+pub pure fn lu_solve<T:Copy Float FuzzyEq<T>,
+                     V:Copy MutableVector<T>,
+                     M:Copy MutableMatrix<T,V>>(p: &M, l: &M, u: &M, b: &V, n: uint) -> Option<V> {
+    if lu_is_singular::<T,V,M>(u, n) {
+        None
+    } else {
+        let mut x = p.mul_v(b);
+        
+        unsafe {
+            // Solve `l * y == p * b` by forward substitution
+            for uint::range(0, n) |i| {
+                let mut sum = x[i];
+                for uint::range(0, i) |j| {
+                    sum -= l[j][i] * x[j];
+                }
+                *x.index_mut(i) = sum;
+            }
+            
+            // Then solve `u * x == y` by back substitution
+            for uint::range(0, n) |k| {
+                let i = n - 1 - k;
+                let mut sum = x[i];
+                for uint::range(i + 1, n) |j| {
+                    sum -= u[j][i] * x[j];
+                }
+                *x.index_mut(i) = sum / u[i][i];
+            }
+        }
+        
+        Some(x)
+    }
+}
+
+/**
+ * Compute the QR decomposition of the `n` x `n` matrix `m` using Householder
+ * reflections. This is shared by the implementations of `Matrix::qr`.
+ *
+ * # Return value
+ *
+ * A tuple `(q, r)` such that `m == q * r`, where `q` is orthogonal and `r` is
+ * upper triangular with a non-negative diagonal
+ */
+pub pure fn qr_decompose<T:Copy Float FuzzyEq<T>,
+                         V:Copy MutableVector<T>,
+                         M:Copy MutableMatrix<T,V>>(m: &M, n: uint) -> (M, M) {
+    let _1: T = Number::from(1);
+    let _2: T = Number::from(2);
+    
+    let mut q: M = Matrix::identity();
+    let mut r = *m;
+    
+    for uint::range(0, n - 1) |k| {
+        // Construct the vector `v` that reflects the part of column k on or
+        // below the diagonal onto the diagonal. The sign of `alpha` is
+        // chosen to avoid cancellation.
+        let mut v = r[k];
+        let mut norm2: T = zero();
+        
+        unsafe {
+            for uint::range(0, n) |i| {
+                if i < k {
+                    *v.index_mut(i) = zero();
+                } else {
+                    norm2 += v[i] * v[i];
+                }
+            }
+        }
+        
+        // Only an exactly zero column needs no reflection. Any other column,
+        // however small, still has to be reflected onto the diagonal.
+        if norm2 != zero() {
+            let alpha = if v[k] > zero() { -norm2.sqrt() } else { norm2.sqrt() };
+            let vv = norm2 - _2 * alpha * v[k] + alpha * alpha;
+            
+            unsafe { *v.index_mut(k) = v[k] - alpha; }
+            
+            // Apply the reflection `h = I - 2 * v * v^T / (v^T * v)`
+            let mut h: M = Matrix::identity();
+            
+            unsafe {
+                for uint::range(k, n) |j| {
+                    for uint::range(k, n) |i| {
+                        let x = h[j][i] - _2 * v[i] * v[j] / vv;
+                        *h.col_mut(j).index_mut(i) = x;
+                    }
+                }
+            }
+            
+            r = h.mul_m(&r);
+            q = q.mul_m(&h);
+        }
+    }
+    
+    // Flip the signs of the rows of `r`, and the columns of `q`, where
+    // necessary to make the diagonal of `r` non-negative
+    unsafe {
+        for uint::range(0, n) |i| {
+            if r[i][i] < zero() {
+                for uint::range(0, n) |j| {
+                    let x = -q[i][j];
+                    *q.col_mut(i).index_mut(j) = x;
+                }
+                for uint::range(i, n) |j| {
+                    let x = -r[j][i];
+                    *r.col_mut(j).index_mut(i) = x;
+                }
+            }
+        }
+    }
+    
+    (q, r)
+}
+
+/**
+ * Compute the singular value decomposition of the `n` x `n` matrix `m` using
+ * one-sided Jacobi rotations. This is shared by the implementations of
+ * `Matrix::svd`.
+ *
+ * # Return value
+ *
+ * A tuple `(u, s, vt)` such that `m == u * diag(s) * vt`, where `u` and `vt`
+ * are orthogonal and `s` is sorted in descending order
+ */
+pub pure fn svd_decompose<T:Copy Float FuzzyEq<T>,
+                          V:Copy MutableVector<T>,
+                          M:Copy MutableMatrix<T,V>>(m: &M, n: uint) -> (M, V, M) {
+    let _1: T = Number::from(1);
+    let _2: T = Number::from(2);
+    
+    // Rotate pairs of columns of `a` until they are all orthogonal, at which
+    // point `a == u * diag(s)`. The rotations are accumulated in `w`, so that
+    // `m == a * w^T`.
+    let mut a = *m;
+    let mut w: M = Matrix::identity();
+    
+    let mut sweep = 0;
+    let mut converged = false;
+    
+    while !converged && sweep < 50 {
+        converged = true;
+        
+        for uint::range(0, n) |p| {
+            for uint::range(p + 1, n) |q| {
+                let mut alpha: T = zero();
+                let mut beta:  T = zero();
+                let mut gamma: T = zero();
+                
+                for uint::range(0, n) |i| {
+                    alpha += a[p][i] * a[p][i];
+                    beta  += a[q][i] * a[q][i];
+                    gamma += a[p][i] * a[q][i];
+                }
+                
+                // Skip the rotation if the columns are already orthogonal to
+                // within the precision of `T`
+                let norm = (alpha * beta).sqrt();
+                
+                if norm + abs(gamma) != norm {
+                    converged = false;
+                    
+                    let zeta = (beta - alpha) / (_2 * gamma);
+                    let t = if zeta >= zero() {
+                        _1 / (zeta + (zeta * zeta + _1).sqrt())
+                    } else {
+                        -_1 / (-zeta + (zeta * zeta + _1).sqrt())
+                    };
+                    let c = _1 / (t * t + _1).sqrt();
+                    let s = c * t;
+                    
+                    unsafe {
+                        for uint::range(0, n) |i| {
+                            let ap = a[p][i];
+                            let aq = a[q][i];
+                            *a.col_mut(p).index_mut(i) = c * ap - s * aq;
+                            *a.col_mut(q).index_mut(i) = s * ap + c * aq;
+                            
+                            let wp = w[p][i];
+                            let wq = w[q][i];
+                            *w.col_mut(p).index_mut(i) = c * wp - s * wq;
+                            *w.col_mut(q).index_mut(i) = s * wp + c * wq;
+                        }
+                    }
+                }
+            }
+        }
+        
+        sweep += 1;
+    }
+    
+    // The singular values are the lengths of the columns of `a`
+    let mut s = a[0];
+    
+    unsafe {
+        for uint::range(0, n) |j| {
+            let mut norm2: T = zero();
+            for uint::range(0, n) |i| {
+                norm2 += a[j][i] * a[j][i];
+            }
+            *s.index_mut(j) = norm2.sqrt();
+        }
+        
+        // Sort the singular values in descending order
+        for uint::range(0, n) |j| {
+            let mut k = j;
+            for uint::range(j + 1, n) |i| {
+                if s[i] > s[k] {
+                    k = i;
+                }
+            }
+            
+            if k != j {
+                s.swap(j, k);
+                a.swap_cols(j, k);
+                w.swap_cols(j, k);
+            }
+        }
+    }
+    
+    // Normalize the columns of `a` to find `u`. The columns for zero singular
+    // values are arbitrary, so complete the basis with the standard basis
+    // vector that is furthest from the span of the columns found so far. A
+    // singular value counts as zero if it is negligible compared to the
+    // largest one, so that the result doesn't depend on the scale of `m`.
+    let tolerance = s[0] * Number::from(FUZZY_EPSILON);
+    let mut u = a;
+    
+    unsafe {
+        for uint::range(0, n) |j| {
+            if s[j] > tolerance {
+                for uint::range(0, n) |i| {
+                    *u.col_mut(j).index_mut(i) = a[j][i] / s[j];
+                }
+            } else {
+                let mut best = u[j];
+                let mut best_norm: T = -_1;
+                
+                for uint::range(0, n) |k| {
+                    let mut e = u[j];
+                    for uint::range(0, n) |i| {
+                        *e.index_mut(i) = if i == k { _1 } else { zero() };
+                    }
+                    
+                    for uint::range(0, j) |l| {
+                        let mut d: T = zero();
+                        for uint::range(0, n) |i| {
+                            d += u[l][i] * e[i];
+                        }
+                        for uint::range(0, n) |i| {
+                            *e.index_mut(i) = e[i] - d * u[l][i];
+                        }
+                    }
+                    
+                    let mut norm2: T = zero();
+                    for uint::range(0, n) |i| {
+                        norm2 += e[i] * e[i];
+                    }
+                    
+                    if norm2 > best_norm {
+                        best = e;
+                        best_norm = norm2;
+                    }
+                }
+                
+                let norm = best_norm.sqrt();
+                for uint::range(0, n) |i| {
+                    *u.col_mut(j).index_mut(i) = best[i] / norm;
+                }
+            }
+        }
+    }
+    
+    (u, s, w.transpose())
+}
+
+/**
+ * Compute the singular value decomposition of the `n` x `n` matrix `m` with
+ * `u` and `vt` adjusted to be proper rotations. This is shared by the
+ * implementations of `Matrix::proper_svd`.
+ */
+pub pure fn svd_decompose_proper<T:Copy Float FuzzyEq<T>,
+                                 V:Copy MutableVector<T>,
+                                 M:Copy MutableMatrix<T,V>>(m: &M, n: uint) -> (M, V, M) {
+    let (u, s, vt) = svd_decompose::<T,V,M>(m, n);
+    let mut u = u;
+    let mut s = s;
+    let mut vt = vt;
+    
+    // Negating the last column of `u`, or the last row of `vt`, flips the sign
+    // of its determinant. Negating the smallest singular value at the same time
+    // keeps the product unchanged.
+    unsafe {
+        if u.determinant() < zero() {
+            for uint::range(0, n) |i| {
+                let x = -u[n - 1][i];
+                *u.col_mut(n - 1).index_mut(i) = x;
+            }
+            *s.index_mut(n - 1) = -s[n - 1];
+        }
+        
+        if vt.determinant() < zero() {
+            for uint::range(0, n) |j| {
+                let x = -vt[j][n - 1];
+                *vt.col_mut(j).index_mut(n - 1) = x;
+            }
+            *s.index_mut(n - 1) = -s[n - 1];
+        }
+    }
+    
+    (u, s, vt)
+}
+
+/**
+ * Compute the pseudo-inverse of the `n` x `n` matrix `m` from its singular
+ * value decomposition `u * diag(s) * vt`, as `v * diag(s)^+ * u^T`, where
+ * `diag(s)^+` inverts the singular values that are not negligible compared to
+ * the largest one. This is shared by the implementations of
+ * `Matrix::pseudo_inverse`.
+ */
+pub pure fn svd_pseudo_inverse<T:Copy Float FuzzyEq<T>,
+                               V:Copy MutableVector<T>,
+                               M:Copy MutableMatrix<T,V>>(m: &M, n: uint) -> M {
+    let _1: T = Number::from(1);
+    let (u, s, vt) = svd_decompose::<T,V,M>(m, n);
+    let tolerance = s[0] * Number::from(FUZZY_EPSILON);
+    let mut d: M = Matrix::zero();
+    
+    unsafe {
+        for uint::range(0, n) |i| {
+            if s[i] > tolerance {
+                *d.col_mut(i).index_mut(i) = _1 / s[i];
+            }
+        }
+    }
+    
+    vt.transpose().mul_m(&d).mul_m(&u.transpose())
+}
+
+/**
+ * # Return value
+ *
+ * The number of singular values of the `n` x `n` matrix `m` that are greater
+ * than `epsilon`. This is shared by the implementations of `Matrix::rank`.
+ */
+pub pure fn svd_rank<T:Copy Float FuzzyEq<T>,
+                     V:Copy MutableVector<T>,
+                     M:Copy MutableMatrix<T,V>>(m: &M, epsilon: T, n: uint) -> uint {
+    let (_, s, _) = svd_decompose::<T,V,M>(m, n);
+    let mut rank = 0;
+    
+    for uint::range(0, n) |i| {
+        if s[i] > epsilon {
+            rank += 1;
+        }
+    }
+    
+    rank
+}
+
+/**
+ * # Return value
+ *
+ * The ratio of the largest to the smallest singular value of the `n` x `n`
+ * matrix `m`. This is shared by the implementations of
+ * `Matrix::condition_number`.
+ */
+pub pure fn svd_condition_number<T:Copy Float FuzzyEq<T>,
+                                 V:Copy MutableVector<T>,
+                                 M:Copy MutableMatrix<T,V>>(m: &M, n: uint) -> T {
+    let (_, s, _) = svd_decompose::<T,V,M>(m, n);
+    
+    s[0] / s[n - 1]
+}
+
+/**
+ * Compute the polar decomposition `(r, s)` of the `n` x `n` matrix `m` from
+ * its singular value decomposition `u * diag(s) * vt`, where `r` is
+ * `u * vt` and `s` is `v * diag(s) * vt`. This is shared by the
+ * implementations of `Matrix::polar`.
+ */
+pub pure fn polar_decompose<T:Copy Float FuzzyEq<T>,
+                            V:Copy MutableVector<T>,
+                            M:Copy MutableMatrix<T,V>>(m: &M, n: uint) -> (M, M) {
+    let (u, s, vt) = svd_decompose_proper::<T,V,M>(m, n);
+    let mut d: M = Matrix::zero();
+    
+    unsafe {
+        for uint::range(0, n) |i| {
+            *d.col_mut(i).index_mut(i) = s[i];
+        }
+    }
+    
+    (u.mul_m(&vt), vt.transpose().mul_m(&d).mul_m(&vt))
+}
+
+/**
+ * Construct the Jacobi rotation in the plane of the `p` and `q` axes, such that
+ * for a symmetric matrix `a`, the element at column `q`, row `p` of
+ * `rot.transpose() * a * rot` is eliminated for a suitable choice of `c` and
+ * `s`. This is shared by the implementations of `symmetric_eigen`.
+ */
+#[inline(always)]
+pub pure fn jacobi_rotation<T:Copy Float FuzzyEq<T>,
+                            V:Copy MutableVector<T>,
+                            M:Copy MutableMatrix<T,V>>(p: uint, q: uint, c: T, s: T) -> M {
+    let mut rot: M = Matrix::identity();
+    
+    unsafe {
+        *rot.col_mut(p).index_mut(p) = c;
+        *rot.col_mut(q).index_mut(q) = c;
+        *rot.col_mut(q).index_mut(p) = s;
+        *rot.col_mut(p).index_mut(q) = -s;
+    }
+    
+    rot
+}
+
 /**
  * A 2 x 2 matrix
  */
@@ -188,7 +806,7 @@ pub trait Matrix2<T,V>: Matrix<T,V> {
     
     static pure fn from_cols(c0: V, c1: V) -> Self;
     
-    static pure fn from_angle(radians: T) -> Self;
+    static pure fn from_angle<A:Angle<T>>(theta: A) -> Self;
     
     pure fn to_mat3(&self) -> Mat3<T>;
     
@@ -205,23 +823,50 @@ pub trait Matrix3<T,V>: Matrix<T,V> {
     
     static pure fn from_cols(c0: V, c1: V, c2: V) -> Self;
     
-    static pure fn from_angle_x(radians: T) -> Self;
+    static pure fn from_angle_x<A:Angle<T>>(theta: A) -> Self;
     
-    static pure fn from_angle_y(radians: T) -> Self;
+    static pure fn from_angle_y<A:Angle<T>>(theta: A) -> Self;
     
-    static pure fn from_angle_z(radians: T) -> Self;
+    static pure fn from_angle_z<A:Angle<T>>(theta: A) -> Self;
     
-    static pure fn from_angle_xyz(radians_x: T, radians_y: T, radians_z: T) -> Self;
+    static pure fn from_angle_xyz<A:Angle<T>>(theta_x: A, theta_y: A, theta_z: A) -> Self;
     
-    static pure fn from_angle_axis(radians: T, axis: &Vec3<T>) -> Self;
+    static pure fn from_angle_axis<A:Angle<T>>(theta: A, axis: &Vec3<T>) -> Self;
     
     static pure fn from_axes(x: V, y: V, z: V) -> Self;
     
     static pure fn look_at(dir: &Vec3<T>, up: &Vec3<T>) -> Self;
     
+    static pure fn from_two_vectors(a: &Vec3<T>, b: &Vec3<T>) -> Self;
+    
+    pure fn to_mat2(&self) -> Mat2<T>;
+    
     pure fn to_mat4(&self) -> Mat4<T>;
     
     pure fn to_quat(&self) -> Quat<T>;
+    
+    /**
+     * Compute the eigen-decomposition of a symmetric matrix, such that
+     * `self == vectors * diag(values) * vectors.transpose()`
+     *
+     * # Return value
+     *
+     * * `Some((values, vectors))` - if the matrix is symmetric, where `values`
+     *   holds the eigenvalues sorted in ascending order and the columns of
+     *   `vectors` are the corresponding eigenvectors. The eigenvectors are
+     *   orthonormal and form a right-handed basis, so `vectors` is a rotation.
+     * * `None` - if the matrix is not symmetric
+     */
+    pure fn symmetric_eigen(&self) -> Option<(V, Self)>;
+    
+    /**
+     * # Return value
+     *
+     * The matrix with its columns made orthonormal using the Gram-Schmidt
+     * process. This is useful for correcting the drift that accumulates in
+     * rotation matrices after many successive transformations.
+     */
+    pure fn orthonormalize(&self) -> Self;
 }
 
 /**
@@ -234,6 +879,111 @@ pub trait Matrix4<T,V>: Matrix<T,V> {
                        c3r0: T, c3r1: T, c3r2: T, c3r3: T) -> Self;
     
     static pure fn from_cols(c0: V, c1: V, c2: V, c3: V) -> Self;
+    
+    /**
+     * Construct a matrix that translates by `v`
+     */
+    static pure fn from_translation(v: &Vec3<T>) -> Self;
+    
+    /**
+     * Construct a matrix that scales each axis by the corresponding
+     * component of `v`
+     */
+    static pure fn from_scale(v: &Vec3<T>) -> Self;
+    
+    /**
+     * Construct a matrix that scales each axis by `value`
+     */
+    static pure fn from_uniform_scale(value: T) -> Self;
+    
+    /**
+     * Construct a matrix from the rotation of a 3 x 3 matrix
+     */
+    static pure fn from_mat3(m: &Mat3<T>) -> Self;
+    
+    /**
+     * Construct a matrix from the rotation of a quaternion
+     */
+    static pure fn from_quat(q: &Quat<T>) -> Self;
+    
+    /**
+     * Construct a matrix from an axis and an angular rotation
+     */
+    static pure fn from_angle_axis<A:Angle<T>>(theta: A, axis: &Vec3<T>) -> Self;
+    
+    /**
+     * Construct a view matrix for a camera at `eye` looking towards `center`
+     *
+     * This is the equivalent of the [gluLookAt]
+     * (http://www.opengl.org/sdk/docs/man2/xhtml/gluLookAt.xml) function.
+     */
+    static pure fn look_at(eye: &Vec3<T>, center: &Vec3<T>, up: &Vec3<T>) -> Self;
+    
+    /**
+     * # Return value
+     *
+     * The matrix multiplied by a translation of `v`, ie. the translation is
+     * applied before the transformation represented by the matrix
+     */
+    pure fn translate(&self, v: &Vec3<T>) -> Self;
+    
+    /**
+     * # Return value
+     *
+     * The matrix multiplied by a scale of `v`, ie. the scale is applied before
+     * the transformation represented by the matrix
+     */
+    pure fn scale(&self, v: &Vec3<T>) -> Self;
+    
+    /**
+     * # Return value
+     *
+     * The matrix multiplied by a rotation of `theta` around `axis`, ie. the
+     * rotation is applied before the transformation represented by the matrix
+     */
+    pure fn rotate<A:Angle<T>>(&self, theta: A, axis: &Vec3<T>) -> Self;
+    
+    /**
+     * # Return value
+     *
+     * The upper-left 3 x 3 part of the matrix, which holds the rotation,
+     * scale and shear of an affine transformation
+     */
+    pure fn to_mat3(&self) -> Mat3<T>;
+    
+    /**
+     * # Return value
+     *
+     * The rotation of an affine transformation, ignoring its translation and
+     * scale. The matrix should not contain a shear or a reflection.
+     */
+    pure fn to_quat(&self) -> Quat<T>;
+    
+    /**
+     * Decompose an affine transformation into a translation, a rotation, a
+     * scale and a shear
+     *
+     * # Return value
+     *
+     * * `Some(d)` - if the matrix is an invertible affine transformation,
+     *   where `d.compose()` is approximately equal to the matrix
+     * * `None` - if the matrix has a projective component or is singular
+     */
+    pure fn decompose(&self) -> Option<Decomposed<T>>;
+    
+    /**
+     * Compute the eigen-decomposition of a symmetric matrix, such that
+     * `self == vectors * diag(values) * vectors.transpose()`
+     *
+     * # Return value
+     *
+     * * `Some((values, vectors))` - if the matrix is symmetric, where `values`
+     *   holds the eigenvalues sorted in ascending order and the columns of
+     *   `vectors` are the corresponding orthonormal eigenvectors
+     * * `None` - if the matrix is not symmetric, or if the iteration fails to
+     *   converge
+     */
+    pure fn symmetric_eigen(&self) -> Option<(V, Self)>;
 }
 
 /**
@@ -301,4 +1051,64 @@ pub trait MutableMatrix<T,V>: Matrix<T,V> {
      * Sets the matrix to its transpose
      */
     fn transpose_self(&mut self);
-}
\ No newline at end of file
+}
+
+/**
+ * A mutable 3 x 3 matrix
+ */
+pub trait MutableMatrix3<T,V>: MutableMatrix<T,V> {
+    /**
+     * Make the columns of the matrix orthonormal using the Gram-Schmidt
+     * process
+     */
+    fn orthonormalize_self(&mut self);
+}
+
+/**
+ * A type that can appear on the right hand side of the `*` operator when a
+ * matrix of type `M` is on the left hand side. This is implemented for
+ * scalars (scalar multiplication), column vectors (the matrix-vector product)
+ * and matrices (the matrix product). As with `VectorMulRHS`, a scalar can only
+ * be on the right hand side.
+ *
+ * # Type parameters
+ *
+ * * `M` - The type of the matrix on the left hand side of the operator
+ * * `Result` - The type of the product
+ */
+pub trait MatrixMulRHS<M, Result> {
+    /**
+     * # Return value
+     *
+     * The result of `lhs * self`
+     */
+    pure fn rmul_m(&self, lhs: &M) -> Result;
+}
+
+/**
+ * A matrix that can be multiplied by a scalar. This is what the scalar
+ * `MatrixMulRHS` implementations dispatch to, so that `m * 2.0` also works for
+ * the non-square matrices, which do not implement `Matrix`.
+ */
+pub trait MatrixScalarMul<T> {
+    /**
+     * # Return value
+     *
+     * The matrix with each component multiplied by `value`
+     */
+    pure fn mul_scalar(&self, value: T) -> Self;
+}
+
+// Scalar multiplication, eg. `m * 2.0`
+
+pub impl<M:MatrixScalarMul<float>> float: MatrixMulRHS<M, M> {
+    #[inline(always)] pure fn rmul_m(&self, lhs: &M) -> M { lhs.mul_scalar(*self) }
+}
+
+pub impl<M:MatrixScalarMul<f32>> f32: MatrixMulRHS<M, M> {
+    #[inline(always)] pure fn rmul_m(&self, lhs: &M) -> M { lhs.mul_scalar(*self) }
+}
+
+pub impl<M:MatrixScalarMul<f64>> f64: MatrixMulRHS<M, M> {
+    #[inline(always)] pure fn rmul_m(&self, lhs: &M) -> M { lhs.mul_scalar(*self) }
+}
@@ -10,6 +10,8 @@ use numeric::*;
 use numeric::number::Number;
 use numeric::number::Number::{zero,one};
 
+use angle::Angle;
+
 use vec::{
     Vec2,
     Vector2,
@@ -28,6 +30,17 @@ use mat::{
     Matrix3,
     Matrix4,
     MutableMatrix,
+    MatrixMulRHS,
+    MatrixScalarMul,
+    lu_decompose,
+    lu_solve,
+    qr_decompose,
+    svd_decompose,
+    svd_decompose_proper,
+    svd_pseudo_inverse,
+    svd_rank,
+    svd_condition_number,
+    polar_decompose,
 };
 
 /**
@@ -165,6 +178,55 @@ pub impl<T:Copy Float FuzzyEq<T>> Mat2<T>: Matrix<T, Vec2<T>> {
         }
     }
     
+    #[inline(always)]
+    pure fn lu(&self) -> (Mat2<T>, Mat2<T>, Mat2<T>) {
+        match lu_decompose::<T, Vec2<T>, Mat2<T>>(self, 2) {
+            (p, l, u, _) => (p, l, u)
+        }
+    }
+    
+    #[inline(always)]
+    pure fn solve(&self, b: &Vec2<T>) -> Option<Vec2<T>> {
+        match self.lu() {
+            (p, l, u) => lu_solve(&p, &l, &u, b, 2)
+        }
+    }
+    
+    #[inline(always)]
+    pure fn qr(&self) -> (Mat2<T>, Mat2<T>) {
+        qr_decompose::<T, Vec2<T>, Mat2<T>>(self, 2)
+    }
+    
+    #[inline(always)]
+    pure fn svd(&self) -> (Mat2<T>, Vec2<T>, Mat2<T>) {
+        svd_decompose::<T, Vec2<T>, Mat2<T>>(self, 2)
+    }
+    
+    #[inline(always)]
+    pure fn proper_svd(&self) -> (Mat2<T>, Vec2<T>, Mat2<T>) {
+        svd_decompose_proper::<T, Vec2<T>, Mat2<T>>(self, 2)
+    }
+    
+    #[inline(always)]
+    pure fn pseudo_inverse(&self) -> Mat2<T> {
+        svd_pseudo_inverse::<T, Vec2<T>, Mat2<T>>(self, 2)
+    }
+    
+    #[inline(always)]
+    pure fn rank(&self, epsilon: T) -> uint {
+        svd_rank::<T, Vec2<T>, Mat2<T>>(self, epsilon, 2)
+    }
+    
+    #[inline(always)]
+    pure fn condition_number(&self) -> T {
+        svd_condition_number::<T, Vec2<T>, Mat2<T>>(self, 2)
+    }
+    
+    #[inline(always)]
+    pure fn polar(&self) -> (Mat2<T>, Mat2<T>) {
+        polar_decompose::<T, Vec2<T>, Mat2<T>>(self, 2)
+    }
+    
     #[inline(always)]
     pure fn transpose(&self) -> Mat2<T> {
         Matrix2::new(self[0][0], self[1][0],
@@ -327,9 +389,9 @@ pub impl<T:Copy Float FuzzyEq<T>> Mat2<T>: Matrix2<T, Vec2<T>> {
     }
     
     #[inline(always)]
-    static pure fn from_angle(radians: T) -> Mat2<T> {
-        let cos_theta = cos(radians);
-        let sin_theta = sin(radians);
+    static pure fn from_angle<A:Angle<T>>(theta: A) -> Mat2<T> {
+        let cos_theta = theta.cos();
+        let sin_theta = theta.sin();
         
         Matrix2::new(cos_theta, -sin_theta,
                      sin_theta,  cos_theta)
@@ -396,6 +458,55 @@ pub impl<T:Copy Float FuzzyEq<T>> Mat2<T>: Neg<Mat2<T>> {
     }
 }
 
+pub impl<T:Copy Float FuzzyEq<T>> Mat2<T>: Add<Mat2<T>, Mat2<T>> {
+    #[inline(always)]
+    pure fn add(&self, rhs: &Mat2<T>) -> Mat2<T> {
+        self.add_m(rhs)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat2<T>: Sub<Mat2<T>, Mat2<T>> {
+    #[inline(always)]
+    pure fn sub(&self, rhs: &Mat2<T>) -> Mat2<T> {
+        self.sub_m(rhs)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>, R, RHS:MatrixMulRHS<Mat2<T>, R>> Mat2<T>: Mul<RHS, R> {
+    #[inline(always)]
+    pure fn mul(&self, rhs: &RHS) -> R {
+        rhs.rmul_m(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat2<T>: Div<T, Mat2<T>> {
+    #[inline(always)]
+    pure fn div(&self, rhs: &T) -> Mat2<T> {
+        self.mul_t(one::<T>() / *rhs)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat2<T>: MatrixScalarMul<T> {
+    #[inline(always)]
+    pure fn mul_scalar(&self, value: T) -> Mat2<T> {
+        self.mul_t(value)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat2<T>: MatrixMulRHS<Mat2<T>, Mat2<T>> {
+    #[inline(always)]
+    pure fn rmul_m(&self, lhs: &Mat2<T>) -> Mat2<T> {
+        lhs.mul_m(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Vec2<T>: MatrixMulRHS<Mat2<T>, Vec2<T>> {
+    #[inline(always)]
+    pure fn rmul_m(&self, lhs: &Mat2<T>) -> Vec2<T> {
+        lhs.mul_v(self)
+    }
+}
+
 pub impl<T:Copy Float FuzzyEq<T>> Mat2<T>: FuzzyEq<T> {
     #[inline(always)]
     pure fn fuzzy_eq(&self, other: &Mat2<T>) -> bool {
@@ -427,7 +538,7 @@ pub impl mat2 {
     #[inline(always)] static pure fn identity() -> mat2 { Matrix::identity() }
     #[inline(always)] static pure fn zero() -> mat2 { Matrix::zero() }
     
-    #[inline(always)] static pure fn from_angle(radians: f32) -> mat2 { Matrix2::from_angle(radians) }
+    #[inline(always)] static pure fn from_angle<A:Angle<f32>>(theta: A) -> mat2 { Matrix2::from_angle(theta) }
     
     #[inline(always)] static pure fn dim() -> uint { 2 }
     #[inline(always)] static pure fn rows() -> uint { 2 }
@@ -445,7 +556,7 @@ pub impl dmat2 {
     #[inline(always)] static pure fn identity() -> dmat2 { Matrix::identity() }
     #[inline(always)] static pure fn zero() -> dmat2 { Matrix::zero() }
     
-    #[inline(always)] static pure fn from_angle(radians: f64) -> dmat2 { Matrix2::from_angle(radians) }
+    #[inline(always)] static pure fn from_angle<A:Angle<f64>>(theta: A) -> dmat2 { Matrix2::from_angle(theta) }
     
     #[inline(always)] static pure fn dim() -> uint { 2 }
     #[inline(always)] static pure fn rows() -> uint { 2 }
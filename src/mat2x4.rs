@@ -0,0 +1,395 @@
+use core::cast::transmute;
+use core::cmp::Eq;
+use core::ptr::to_unsafe_ptr;
+use core::sys::size_of;
+use core::vec::raw::buf_as_slice;
+
+use std::cmp::{FuzzyEq, FUZZY_EPSILON};
+use numeric::*;
+use numeric::number::Number;
+use numeric::number::Number::{zero,one};
+
+use vec::{
+    Vec2,
+    Vec4,
+    Vector2,
+    Vector4,
+    NumericVector,
+    vec2,
+    dvec2,
+    vec4,
+    dvec4,
+};
+
+use mat::{
+    Mat2,
+    Mat4,
+    Mat3x2,
+    Mat3x4,
+    Mat4x2,
+    Matrix4,
+    MatrixMulRHS,
+    MatrixScalarMul,
+};
+
+/**
+ * A 2 x 4 column major matrix, with 2 columns and 4 rows
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the elements of the matrix. Should be a floating point type.
+ *
+ * # Fields
+ *
+ * * `x` - the first column vector of the matrix
+ * * `y` - the second column vector of the matrix
+ */
+#[deriving_eq]
+pub struct Mat2x4<T> { x: Vec4<T>, y: Vec4<T> }
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat2x4<T> {
+    /**
+     * Construct a 2 x 4 matrix
+     *
+     * # Arguments
+     *
+     * * `c0r0`, `c0r1`, `c0r2`, `c0r3` - the first column of the matrix
+     * * `c1r0`, `c1r1`, `c1r2`, `c1r3` - the second column of the matrix
+     *
+     * ~~~
+     *        c0     c1
+     *     +------+------+
+     *  r0 | c0r0 | c1r0 |
+     *     +------+------+
+     *  r1 | c0r1 | c1r1 |
+     *     +------+------+
+     *  r2 | c0r2 | c1r2 |
+     *     +------+------+
+     *  r3 | c0r3 | c1r3 |
+     *     +------+------+
+     * ~~~
+     */
+    #[inline(always)]
+    static pure fn new(c0r0: T, c0r1: T, c0r2: T, c0r3: T,
+                       c1r0: T, c1r1: T, c1r2: T, c1r3: T) -> Mat2x4<T> {
+        Mat2x4::from_cols(Vector4::new::<T,Vec4<T>>(c0r0, c0r1, c0r2, c0r3),
+                          Vector4::new::<T,Vec4<T>>(c1r0, c1r1, c1r2, c1r3))
+    }
+    
+    /**
+     * Construct a 2 x 4 matrix from column vectors
+     *
+     * # Arguments
+     *
+     * * `c0` - the first column vector of the matrix
+     * * `c1` - the second column vector of the matrix
+     *
+     * ~~~
+     *        c0     c1
+     *     +------+------+
+     *  r0 | c0.x | c1.x |
+     *     +------+------+
+     *  r1 | c0.y | c1.y |
+     *     +------+------+
+     *  r2 | c0.z | c1.z |
+     *     +------+------+
+     *  r3 | c0.w | c1.w |
+     *     +------+------+
+     * ~~~
+     */
+    #[inline(always)]
+    static pure fn from_cols(c0: Vec4<T>,
+                             c1: Vec4<T>) -> Mat2x4<T> {
+        Mat2x4 { x: c0, y: c1 }
+    }
+    
+    /**
+     * Construct a 2 x 4 matrix with the major diagonal set to `value` and
+     * all other elements set to zero
+     */
+    #[inline(always)]
+    static pure fn from_value(value: T) -> Mat2x4<T> {
+        Mat2x4::new( value, zero(), zero(), zero(),
+                    zero(),  value, zero(), zero())
+    }
+    
+    /**
+     * # Return value
+     *
+     * A matrix with ones on the major diagonal and zeros elsewhere
+     */
+    #[inline(always)]
+    static pure fn identity() -> Mat2x4<T> {
+        Mat2x4::from_value(one())
+    }
+    
+    /**
+     * # Return value
+     *
+     * A matrix with all elements set to zero
+     */
+    #[inline(always)]
+    static pure fn zero() -> Mat2x4<T> {
+        Mat2x4::from_value(zero())
+    }
+    
+    /**
+     * # Return value
+     *
+     * The column vector at `i`
+     */
+    #[inline(always)]
+    pure fn col(&self, i: uint) -> Vec4<T> { self[i] }
+    
+    /**
+     * # Return value
+     *
+     * The row vector at `i`
+     */
+    #[inline(always)]
+    pure fn row(&self, i: uint) -> Vec2<T> {
+        Vector2::new(self[0][i],
+                     self[1][i])
+    }
+    
+    /**
+     * # Return value
+     *
+     * The scalar multiplication of this matrix and `value`
+     */
+    #[inline(always)]
+    pure fn mul_t(&self, value: T) -> Mat2x4<T> {
+        Mat2x4::from_cols(self[0].mul_t(value),
+                          self[1].mul_t(value))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The product of the matrix and the 2-dimensional column vector `vec`,
+     * which is a 4-dimensional vector
+     */
+    #[inline(always)]
+    pure fn mul_v(&self, vec: &Vec2<T>) -> Vec4<T> {
+        Vector4::new(self.row(0).dot(vec),
+                     self.row(1).dot(vec),
+                     self.row(2).dot(vec),
+                     self.row(3).dot(vec))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The matrix addition of the matrix and `other`
+     */
+    #[inline(always)]
+    pure fn add_m(&self, other: &Mat2x4<T>) -> Mat2x4<T> {
+        Mat2x4::from_cols(self[0].add_v(&other[0]),
+                          self[1].add_v(&other[1]))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The difference between the matrix and `other`
+     */
+    #[inline(always)]
+    pure fn sub_m(&self, other: &Mat2x4<T>) -> Mat2x4<T> {
+        Mat2x4::from_cols(self[0].sub_v(&other[0]),
+                          self[1].sub_v(&other[1]))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The matrix product of the matrix and the 2 x 2 matrix `other`
+     */
+    #[inline(always)]
+    pure fn mul_m2(&self, other: &Mat2<T>) -> Mat2x4<T> {
+        Mat2x4::from_cols(self.mul_v(&other[0]),
+                          self.mul_v(&other[1]))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The matrix product of the matrix and the 3 x 2 matrix `other`
+     */
+    #[inline(always)]
+    pure fn mul_m3x2(&self, other: &Mat3x2<T>) -> Mat3x4<T> {
+        Mat3x4::from_cols(self.mul_v(&other[0]),
+                          self.mul_v(&other[1]),
+                          self.mul_v(&other[2]))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The matrix product of the matrix and the 4 x 2 matrix `other`
+     */
+    #[inline(always)]
+    pure fn mul_m4x2(&self, other: &Mat4x2<T>) -> Mat4<T> {
+        Matrix4::from_cols(self.mul_v(&other[0]),
+                           self.mul_v(&other[1]),
+                           self.mul_v(&other[2]),
+                           self.mul_v(&other[3]))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The transposed matrix, which has 4 columns and 2 rows
+     */
+    #[inline(always)]
+    pure fn transpose(&self) -> Mat4x2<T> {
+        Mat4x2::from_cols(self.row(0),
+                          self.row(1),
+                          self.row(2),
+                          self.row(3))
+    }
+    
+    /**
+     * # Return value
+     *
+     * A pointer to the first element of the matrix
+     */
+    #[inline(always)]
+    pure fn to_ptr(&self) -> *T {
+        unsafe {
+            transmute::<*Mat2x4<T>, *T>(
+                to_unsafe_ptr(self)
+            )
+        }
+    }
+}
+
+pub impl<T:Copy> Mat2x4<T>: Index<uint, Vec4<T>> {
+    #[inline(always)]
+    pure fn index(&self, i: uint) -> Vec4<T> {
+        unsafe { do buf_as_slice(
+            transmute::<*Mat2x4<T>, *Vec4<T>>(
+                to_unsafe_ptr(self)), 2) |slice| { slice[i] }
+        }
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat2x4<T>: Neg<Mat2x4<T>> {
+    #[inline(always)]
+    pure fn neg(&self) -> Mat2x4<T> {
+        Mat2x4::from_cols(-self[0], -self[1])
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat2x4<T>: Add<Mat2x4<T>, Mat2x4<T>> {
+    #[inline(always)]
+    pure fn add(&self, rhs: &Mat2x4<T>) -> Mat2x4<T> {
+        self.add_m(rhs)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat2x4<T>: Sub<Mat2x4<T>, Mat2x4<T>> {
+    #[inline(always)]
+    pure fn sub(&self, rhs: &Mat2x4<T>) -> Mat2x4<T> {
+        self.sub_m(rhs)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>, R, RHS:MatrixMulRHS<Mat2x4<T>, R>> Mat2x4<T>: Mul<RHS, R> {
+    #[inline(always)]
+    pure fn mul(&self, rhs: &RHS) -> R {
+        rhs.rmul_m(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat2x4<T>: Div<T, Mat2x4<T>> {
+    #[inline(always)]
+    pure fn div(&self, rhs: &T) -> Mat2x4<T> {
+        self.mul_t(one::<T>() / *rhs)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat2x4<T>: MatrixScalarMul<T> {
+    #[inline(always)]
+    pure fn mul_scalar(&self, value: T) -> Mat2x4<T> {
+        self.mul_t(value)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Vec2<T>: MatrixMulRHS<Mat2x4<T>, Vec4<T>> {
+    #[inline(always)]
+    pure fn rmul_m(&self, lhs: &Mat2x4<T>) -> Vec4<T> {
+        lhs.mul_v(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat2<T>: MatrixMulRHS<Mat2x4<T>, Mat2x4<T>> {
+    #[inline(always)]
+    pure fn rmul_m(&self, lhs: &Mat2x4<T>) -> Mat2x4<T> {
+        lhs.mul_m2(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat3x2<T>: MatrixMulRHS<Mat2x4<T>, Mat3x4<T>> {
+    #[inline(always)]
+    pure fn rmul_m(&self, lhs: &Mat2x4<T>) -> Mat3x4<T> {
+        lhs.mul_m3x2(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat4x2<T>: MatrixMulRHS<Mat2x4<T>, Mat4<T>> {
+    #[inline(always)]
+    pure fn rmul_m(&self, lhs: &Mat2x4<T>) -> Mat4<T> {
+        lhs.mul_m4x2(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat2x4<T>: FuzzyEq<T> {
+    #[inline(always)]
+    pure fn fuzzy_eq(&self, other: &Mat2x4<T>) -> bool {
+        self.fuzzy_eq_eps(other, &Number::from(FUZZY_EPSILON))
+    }
+    
+    #[inline(always)]
+    pure fn fuzzy_eq_eps(&self, other: &Mat2x4<T>, epsilon: &T) -> bool {
+        self[0].fuzzy_eq_eps(&other[0], epsilon) &&
+        self[1].fuzzy_eq_eps(&other[1], epsilon)
+    }
+}
+
+// GLSL-style type aliases, corresponding to Section 4.1.6 of the [GLSL 4.30.6 specification]
+// (http://www.opengl.org/registry/doc/GLSLangSpec.4.30.6.pdf).
+
+pub type mat2x4 = Mat2x4<f32>;    // a 2×4 single-precision floating-point matrix
+pub type dmat2x4 = Mat2x4<f64>;   // a 2×4 double-precision floating-point matrix
+
+// Static method wrappers for GLSL-style types
+
+pub impl mat2x4 {
+    #[inline(always)] static pure fn new(c0r0: f32, c0r1: f32, c0r2: f32, c0r3: f32, c1r0: f32, c1r1: f32, c1r2: f32, c1r3: f32)
+        -> mat2x4 { Mat2x4::new(c0r0, c0r1, c0r2, c0r3, c1r0, c1r1, c1r2, c1r3) }
+    #[inline(always)] static pure fn from_cols(c0: vec4, c1: vec4)
+        -> mat2x4 { Mat2x4::from_cols(move c0, move c1) }
+    #[inline(always)] static pure fn from_value(v: f32) -> mat2x4 { Mat2x4::from_value(v) }
+    
+    #[inline(always)] static pure fn identity() -> mat2x4 { Mat2x4::identity() }
+    #[inline(always)] static pure fn zero() -> mat2x4 { Mat2x4::zero() }
+    
+    #[inline(always)] static pure fn rows() -> uint { 4 }
+    #[inline(always)] static pure fn cols() -> uint { 2 }
+    #[inline(always)] static pure fn size_of() -> uint { size_of::<mat2x4>() }
+}
+
+pub impl dmat2x4 {
+    #[inline(always)] static pure fn new(c0r0: f64, c0r1: f64, c0r2: f64, c0r3: f64, c1r0: f64, c1r1: f64, c1r2: f64, c1r3: f64)
+        -> dmat2x4 { Mat2x4::new(c0r0, c0r1, c0r2, c0r3, c1r0, c1r1, c1r2, c1r3) }
+    #[inline(always)] static pure fn from_cols(c0: dvec4, c1: dvec4)
+        -> dmat2x4 { Mat2x4::from_cols(move c0, move c1) }
+    #[inline(always)] static pure fn from_value(v: f64) -> dmat2x4 { Mat2x4::from_value(v) }
+    
+    #[inline(always)] static pure fn identity() -> dmat2x4 { Mat2x4::identity() }
+    #[inline(always)] static pure fn zero() -> dmat2x4 { Mat2x4::zero() }
+    
+    #[inline(always)] static pure fn rows() -> uint { 4 }
+    #[inline(always)] static pure fn cols() -> uint { 2 }
+    #[inline(always)] static pure fn size_of() -> uint { size_of::<dmat2x4>() }
+}
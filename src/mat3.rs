@@ -10,6 +10,7 @@ use numeric::*;
 use numeric::number::Number;
 use numeric::number::Number::{zero,one};
 
+use angle::Angle;
 use quat::Quat;
 
 use vec::{
@@ -25,11 +26,26 @@ use vec::{
 };
 
 use mat::{
+    Mat2,
     Mat4,
     Matrix,
+    Matrix2,
     Matrix3,
     Matrix4,
     MutableMatrix,
+    MutableMatrix3,
+    MatrixMulRHS,
+    MatrixScalarMul,
+    lu_decompose,
+    lu_solve,
+    qr_decompose,
+    svd_decompose,
+    svd_decompose_proper,
+    svd_pseudo_inverse,
+    svd_rank,
+    svd_condition_number,
+    polar_decompose,
+    jacobi_rotation,
 };
 
 /**
@@ -192,6 +208,55 @@ pub impl<T:Copy Float FuzzyEq<T>> Mat3<T>: Matrix<T, Vec3<T>> {
         }
     }
     
+    #[inline(always)]
+    pure fn lu(&self) -> (Mat3<T>, Mat3<T>, Mat3<T>) {
+        match lu_decompose::<T, Vec3<T>, Mat3<T>>(self, 3) {
+            (p, l, u, _) => (p, l, u)
+        }
+    }
+    
+    #[inline(always)]
+    pure fn solve(&self, b: &Vec3<T>) -> Option<Vec3<T>> {
+        match self.lu() {
+            (p, l, u) => lu_solve(&p, &l, &u, b, 3)
+        }
+    }
+    
+    #[inline(always)]
+    pure fn qr(&self) -> (Mat3<T>, Mat3<T>) {
+        qr_decompose::<T, Vec3<T>, Mat3<T>>(self, 3)
+    }
+    
+    #[inline(always)]
+    pure fn svd(&self) -> (Mat3<T>, Vec3<T>, Mat3<T>) {
+        svd_decompose::<T, Vec3<T>, Mat3<T>>(self, 3)
+    }
+    
+    #[inline(always)]
+    pure fn proper_svd(&self) -> (Mat3<T>, Vec3<T>, Mat3<T>) {
+        svd_decompose_proper::<T, Vec3<T>, Mat3<T>>(self, 3)
+    }
+    
+    #[inline(always)]
+    pure fn pseudo_inverse(&self) -> Mat3<T> {
+        svd_pseudo_inverse::<T, Vec3<T>, Mat3<T>>(self, 3)
+    }
+    
+    #[inline(always)]
+    pure fn rank(&self, epsilon: T) -> uint {
+        svd_rank::<T, Vec3<T>, Mat3<T>>(self, epsilon, 3)
+    }
+    
+    #[inline(always)]
+    pure fn condition_number(&self) -> T {
+        svd_condition_number::<T, Vec3<T>, Mat3<T>>(self, 3)
+    }
+    
+    #[inline(always)]
+    pure fn polar(&self) -> (Mat3<T>, Mat3<T>) {
+        polar_decompose::<T, Vec3<T>, Mat3<T>>(self, 3)
+    }
+    
     #[inline(always)]
     pure fn transpose(&self) -> Mat3<T> {
         Matrix3::new(self[0][0], self[1][0], self[2][0],
@@ -309,10 +374,10 @@ pub impl<T:Copy Float FuzzyEq<T>> Mat3<T>: Matrix3<T, Vec3<T>> {
      * Construct a matrix from an angular rotation around the `x` axis
      */
     #[inline(always)]
-    static pure fn from_angle_x(radians: T) -> Mat3<T> {
+    static pure fn from_angle_x<A:Angle<T>>(theta: A) -> Mat3<T> {
         // http://en.wikipedia.org/wiki/Rotation_matrix#Basic_rotations
-        let cos_theta = cos(radians);
-        let sin_theta = sin(radians);
+        let cos_theta = theta.cos();
+        let sin_theta = theta.sin();
         
         Matrix3::new( one(),     zero(),    zero(),
                      zero(),  cos_theta, sin_theta,
@@ -323,10 +388,10 @@ pub impl<T:Copy Float FuzzyEq<T>> Mat3<T>: Matrix3<T, Vec3<T>> {
      * Construct a matrix from an angular rotation around the `y` axis
      */
     #[inline(always)]
-    static pure fn from_angle_y(radians: T) -> Mat3<T> {
+    static pure fn from_angle_y<A:Angle<T>>(theta: A) -> Mat3<T> {
         // http://en.wikipedia.org/wiki/Rotation_matrix#Basic_rotations
-        let cos_theta = cos(radians);
-        let sin_theta = sin(radians);
+        let cos_theta = theta.cos();
+        let sin_theta = theta.sin();
         
         Matrix3::new(cos_theta, zero(), -sin_theta,
                         zero(),  one(),     zero(),
@@ -337,10 +402,10 @@ pub impl<T:Copy Float FuzzyEq<T>> Mat3<T>: Matrix3<T, Vec3<T>> {
      * Construct a matrix from an angular rotation around the `z` axis
      */
     #[inline(always)]
-    static pure fn from_angle_z(radians: T) -> Mat3<T> {
+    static pure fn from_angle_z<A:Angle<T>>(theta: A) -> Mat3<T> {
         // http://en.wikipedia.org/wiki/Rotation_matrix#Basic_rotations
-        let cos_theta = cos(radians);
-        let sin_theta = sin(radians);
+        let cos_theta = theta.cos();
+        let sin_theta = theta.sin();
         
         Matrix3::new( cos_theta, sin_theta, zero(),
                      -sin_theta, cos_theta, zero(),
@@ -357,14 +422,14 @@ pub impl<T:Copy Float FuzzyEq<T>> Mat3<T>: Matrix3<T, Vec3<T>> {
      * * `theta_z` - the angular rotation around the `z` axis (roll)
      */
     #[inline(always)]
-    static pure fn from_angle_xyz(radians_x: T, radians_y: T, radians_z: T) -> Mat3<T> {
+    static pure fn from_angle_xyz<A:Angle<T>>(theta_x: A, theta_y: A, theta_z: A) -> Mat3<T> {
         // http://en.wikipedia.org/wiki/Rotation_matrix#General_rotations
-        let cx = cos(radians_x);
-        let sx = sin(radians_x);
-        let cy = cos(radians_y);
-        let sy = sin(radians_y);
-        let cz = cos(radians_z);
-        let sz = sin(radians_z);
+        let cx = theta_x.cos();
+        let sx = theta_x.sin();
+        let cy = theta_y.cos();
+        let sy = theta_y.sin();
+        let cz = theta_z.cos();
+        let sz = theta_z.sin();
         
         Matrix3::new(            cy*cz,             cy*sz,   -sy,
                      -cx*sz + sx*sy*cz,  cx*cz + sx*sy*sz, sx*cy,
@@ -375,9 +440,9 @@ pub impl<T:Copy Float FuzzyEq<T>> Mat3<T>: Matrix3<T, Vec3<T>> {
      * Construct a matrix from an axis and an angular rotation
      */
     #[inline(always)]
-    static pure fn from_angle_axis(radians: T, axis: &Vec3<T>) -> Mat3<T> {
-        let c = cos(radians);
-        let s = sin(radians);
+    static pure fn from_angle_axis<A:Angle<T>>(theta: A, axis: &Vec3<T>) -> Mat3<T> {
+        let c = theta.cos();
+        let s = theta.sin();
         let _1_c = one::<T>() - c;
         
         let x = axis.x;
@@ -403,6 +468,37 @@ pub impl<T:Copy Float FuzzyEq<T>> Mat3<T>: Matrix3<T, Vec3<T>> {
         Matrix3::from_axes(up_, side, dir_)
     }
     
+    /**
+     * Construct the shortest rotation that takes the direction of `a` to the
+     * direction of `b`
+     *
+     * See `Quat::from_two_vectors` for the handling of parallel and
+     * anti-parallel vectors.
+     */
+    #[inline(always)]
+    static pure fn from_two_vectors(a: &Vec3<T>, b: &Vec3<T>) -> Mat3<T> {
+        let q: Quat<T> = Quat::from_two_vectors(a, b); q.to_mat3()
+    }
+    
+    /**
+     * Returns the the matrix with the last row and column removed
+     * ~~~
+     *       c0   c1   c2                 c0   c1
+     *     +----+----+----+             +----+----+
+     *  r0 |  a |  b |  c |          r0 |  a |  b |
+     *     +----+----+----+             +----+----+
+     *  r1 |  d |  e |  f |    =>    r1 |  d |  e |
+     *     +----+----+----+             +----+----+
+     *  r2 |  g |  h |  i |
+     *     +----+----+----+
+     * ~~~
+     */
+    #[inline(always)]
+    pure fn to_mat2(&self) -> Mat2<T> {
+        Matrix2::new(self[0][0], self[0][1],
+                     self[1][0], self[1][1])
+    }
+    
     /**
      * Returns the the matrix with an extra row and column added
      * ~~~
@@ -449,30 +545,123 @@ pub impl<T:Copy Float FuzzyEq<T>> Mat3<T>: Matrix3<T, Vec3<T>> {
             y = (self[2][0] - self[0][2]) * s;
             z = (self[0][1] - self[1][0]) * s;
         } else if (self[0][0] > self[1][1]) && (self[0][0] > self[2][2]) {
-            s = (half + (self[0][0] - self[1][1] - self[2][2])).sqrt();
-            w = half * s;
+            s = (_1 + self[0][0] - self[1][1] - self[2][2]).sqrt();
+            x = half * s;
             s = half / s;
-            x = (self[0][1] - self[1][0]) * s;
-            y = (self[2][0] - self[0][2]) * s;
-            z = (self[1][2] - self[2][1]) * s;
+            w = (self[1][2] - self[2][1]) * s;
+            y = (self[0][1] + self[1][0]) * s;
+            z = (self[2][0] + self[0][2]) * s;
         } else if self[1][1] > self[2][2] {
-            s = (half + (self[1][1] - self[0][0] - self[2][2])).sqrt();
-            w = half * s;
+            s = (_1 + self[1][1] - self[0][0] - self[2][2]).sqrt();
+            y = half * s;
             s = half / s;
-            x = (self[0][1] - self[1][0]) * s;
-            y = (self[1][2] - self[2][1]) * s;
-            z = (self[2][0] - self[0][2]) * s;
+            w = (self[2][0] - self[0][2]) * s;
+            x = (self[0][1] + self[1][0]) * s;
+            z = (self[1][2] + self[2][1]) * s;
         } else {
-            s = (half + (self[2][2] - self[0][0] - self[1][1])).sqrt();
-            w = half * s;
+            s = (_1 + self[2][2] - self[0][0] - self[1][1]).sqrt();
+            z = half * s;
             s = half / s;
-            x = (self[2][0] - self[0][2]) * s;
-            y = (self[1][2] - self[2][1]) * s;
-            z = (self[0][1] - self[1][0]) * s;
+            w = (self[0][1] - self[1][0]) * s;
+            x = (self[2][0] + self[0][2]) * s;
+            y = (self[1][2] + self[2][1]) * s;
         }
         
         Quat::new(w, x, y, z)
     }
+    
+    pure fn symmetric_eigen(&self) -> Option<(Vec3<T>, Mat3<T>)> {
+        if !self.is_symmetric() {
+            None
+        } else {
+            
+            // Cyclic Jacobi eigenvalue algorithm: repeatedly apply plane
+            // rotations to eliminate the off-diagonal elements of `a`,
+            // accumulating the rotations in `vecs`. The diagonal of `a`
+            // converges to the eigenvalues, and the columns of `vecs` to the
+            // eigenvectors.
+            
+            let _1: T = Number::from(1);
+            let _2: T = Number::from(2);
+            let _100: T = Number::from(100);
+            
+            let mut a = *self;
+            let mut vecs: Mat3<T> = Matrix::identity();
+            let mut sweep = 0;
+            let mut converged = false;
+            
+            while !converged && sweep < 50 {
+                converged = true;
+                
+                for uint::range(0, 3) |k| {
+                    // visit the pivots (0, 1), (0, 2) and (1, 2) in turn
+                    let p = if k == 2 { 1 } else { 0 };
+                    let q = if k == 0 { 1 } else { 2 };
+                    let apq = a[q][p];
+                    
+                    // skip the rotation once the off-diagonal element is
+                    // negligible relative to the diagonal elements
+                    let g = abs(apq) * _100;
+                    
+                    if abs(a[p][p]) + g != abs(a[p][p]) ||
+                       abs(a[q][q]) + g != abs(a[q][q]) {
+                        converged = false;
+                        
+                        // choose the smaller of the two rotation angles that
+                        // zero `a[q][p]`, for stability
+                        let theta = (a[q][q] - a[p][p]) / (_2 * apq);
+                        let t = if theta >= zero() {
+                            _1 / (theta + (theta * theta + _1).sqrt())
+                        } else {
+                            -_1 / (-theta + (theta * theta + _1).sqrt())
+                        };
+                        let c = _1 / (t * t + _1).sqrt();
+                        let s = t * c;
+                        
+                        let rot = jacobi_rotation::<T, Vec3<T>, Mat3<T>>(p, q, c, s);
+                        
+                        a = rot.transpose().mul_m(&a).mul_m(&rot);
+                        vecs = vecs.mul_m(&rot);
+                    }
+                }
+                
+                sweep += 1;
+            }
+            
+            // Sort the eigenvalues, and their eigenvectors, in ascending order
+            let (d0, d1, d2) = (a[0][0], a[1][1], a[2][2]);
+            let (i0, i1, i2) = if d0 <= d1 {
+                if d1 <= d2 { (0, 1, 2) } else if d0 <= d2 { (0, 2, 1) } else { (2, 0, 1) }
+            } else {
+                if d0 <= d2 { (1, 0, 2) } else if d1 <= d2 { (1, 2, 0) } else { (2, 1, 0) }
+            };
+            
+            let values = Vector3::new(a[i0][i0], a[i1][i1], a[i2][i2]);
+            let sorted: Mat3<T> = Matrix3::from_cols(vecs[i0], vecs[i1], vecs[i2]);
+            
+            // Make sure that the eigenvectors form a right-handed basis
+            let vectors = if sorted.determinant() < zero() {
+                Matrix3::from_cols(sorted[0], sorted[1], -sorted[2])
+            } else {
+                sorted
+            };
+            
+            Some((values, vectors))
+        }
+    }
+    
+    #[inline(always)]
+    pure fn orthonormalize(&self) -> Mat3<T> {
+        // Modified Gram-Schmidt: remove the components of each column that
+        // lie along the previous columns, then normalize it
+        let x = self[0].normalize();
+        let y = self[1].sub_v(&x.mul_t(x.dot(&self[1]))).normalize();
+        
+        let z = self[2].sub_v(&x.mul_t(x.dot(&self[2])));
+        let z = z.sub_v(&y.mul_t(y.dot(&z))).normalize();
+        
+        Matrix3::from_cols(x, y, z)
+    }
 }
 
 pub impl<T:Copy Float FuzzyEq<T>> Mat3<T>: MutableMatrix<T, Vec3<T>> {
@@ -556,6 +745,13 @@ pub impl<T:Copy Float FuzzyEq<T>> Mat3<T>: MutableMatrix<T, Vec3<T>> {
     }
 }
 
+pub impl<T:Copy Float FuzzyEq<T>> Mat3<T>: MutableMatrix3<T, Vec3<T>> {
+    #[inline(always)]
+    fn orthonormalize_self(&mut self) {
+        (*self) = self.orthonormalize();
+    }
+}
+
 pub impl<T:Copy> Mat3<T>: Index<uint, Vec3<T>> {
     #[inline(always)]
     pure fn index(&self, i: uint) -> Vec3<T> {
@@ -573,6 +769,55 @@ pub impl<T:Copy Float FuzzyEq<T>> Mat3<T>: Neg<Mat3<T>> {
     }
 }
 
+pub impl<T:Copy Float FuzzyEq<T>> Mat3<T>: Add<Mat3<T>, Mat3<T>> {
+    #[inline(always)]
+    pure fn add(&self, rhs: &Mat3<T>) -> Mat3<T> {
+        self.add_m(rhs)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat3<T>: Sub<Mat3<T>, Mat3<T>> {
+    #[inline(always)]
+    pure fn sub(&self, rhs: &Mat3<T>) -> Mat3<T> {
+        self.sub_m(rhs)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>, R, RHS:MatrixMulRHS<Mat3<T>, R>> Mat3<T>: Mul<RHS, R> {
+    #[inline(always)]
+    pure fn mul(&self, rhs: &RHS) -> R {
+        rhs.rmul_m(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat3<T>: Div<T, Mat3<T>> {
+    #[inline(always)]
+    pure fn div(&self, rhs: &T) -> Mat3<T> {
+        self.mul_t(one::<T>() / *rhs)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat3<T>: MatrixScalarMul<T> {
+    #[inline(always)]
+    pure fn mul_scalar(&self, value: T) -> Mat3<T> {
+        self.mul_t(value)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat3<T>: MatrixMulRHS<Mat3<T>, Mat3<T>> {
+    #[inline(always)]
+    pure fn rmul_m(&self, lhs: &Mat3<T>) -> Mat3<T> {
+        lhs.mul_m(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Vec3<T>: MatrixMulRHS<Mat3<T>, Vec3<T>> {
+    #[inline(always)]
+    pure fn rmul_m(&self, lhs: &Mat3<T>) -> Vec3<T> {
+        lhs.mul_v(self)
+    }
+}
+
 pub impl<T:Copy Float FuzzyEq<T>> Mat3<T>: FuzzyEq<T> {
     #[inline(always)]
     pure fn fuzzy_eq(&self, other: &Mat3<T>) -> bool {
@@ -605,13 +850,14 @@ pub impl mat3 {
     #[inline(always)] static pure fn identity() -> mat3 { Matrix::identity() }
     #[inline(always)] static pure fn zero() -> mat3 { Matrix::zero() }
     
-    #[inline(always)] static pure fn from_angle_x(radians: f32) -> mat3 { Matrix3::from_angle_x(radians) }
-    #[inline(always)] static pure fn from_angle_y(radians: f32) -> mat3 { Matrix3::from_angle_y(radians) }
-    #[inline(always)] static pure fn from_angle_z(radians: f32) -> mat3 { Matrix3::from_angle_z(radians) }
-    #[inline(always)] static pure fn from_angle_xyz(radians_x: f32, radians_y: f32, radians_z: f32) -> mat3 { Matrix3::from_angle_xyz(radians_x, radians_y, radians_z) }
-    #[inline(always)] static pure fn from_angle_axis(radians: f32, axis: &vec3) -> mat3 { Matrix3::from_angle_axis(radians, axis) }
+    #[inline(always)] static pure fn from_angle_x<A:Angle<f32>>(theta: A) -> mat3 { Matrix3::from_angle_x(theta) }
+    #[inline(always)] static pure fn from_angle_y<A:Angle<f32>>(theta: A) -> mat3 { Matrix3::from_angle_y(theta) }
+    #[inline(always)] static pure fn from_angle_z<A:Angle<f32>>(theta: A) -> mat3 { Matrix3::from_angle_z(theta) }
+    #[inline(always)] static pure fn from_angle_xyz<A:Angle<f32>>(theta_x: A, theta_y: A, theta_z: A) -> mat3 { Matrix3::from_angle_xyz(theta_x, theta_y, theta_z) }
+    #[inline(always)] static pure fn from_angle_axis<A:Angle<f32>>(theta: A, axis: &vec3) -> mat3 { Matrix3::from_angle_axis(theta, axis) }
     #[inline(always)] static pure fn from_axes(x: vec3, y: vec3, z: vec3) -> mat3 { Matrix3::from_axes(x, y, z) }
     #[inline(always)] static pure fn look_at(dir: &vec3, up: &vec3) -> mat3 { Matrix3::look_at(dir, up) }
+    #[inline(always)] static pure fn from_two_vectors(a: &vec3, b: &vec3) -> mat3 { Matrix3::from_two_vectors(a, b) }
     
     #[inline(always)] static pure fn dim() -> uint { 3 }
     #[inline(always)] static pure fn rows() -> uint { 3 }
@@ -630,6 +876,15 @@ pub impl dmat3 {
     #[inline(always)] static pure fn identity() -> dmat3 { Matrix::identity() }
     #[inline(always)] static pure fn zero() -> dmat3 { Matrix::zero() }
     
+    #[inline(always)] static pure fn from_angle_x<A:Angle<f64>>(theta: A) -> dmat3 { Matrix3::from_angle_x(theta) }
+    #[inline(always)] static pure fn from_angle_y<A:Angle<f64>>(theta: A) -> dmat3 { Matrix3::from_angle_y(theta) }
+    #[inline(always)] static pure fn from_angle_z<A:Angle<f64>>(theta: A) -> dmat3 { Matrix3::from_angle_z(theta) }
+    #[inline(always)] static pure fn from_angle_xyz<A:Angle<f64>>(theta_x: A, theta_y: A, theta_z: A) -> dmat3 { Matrix3::from_angle_xyz(theta_x, theta_y, theta_z) }
+    #[inline(always)] static pure fn from_angle_axis<A:Angle<f64>>(theta: A, axis: &dvec3) -> dmat3 { Matrix3::from_angle_axis(theta, axis) }
+    #[inline(always)] static pure fn from_axes(x: dvec3, y: dvec3, z: dvec3) -> dmat3 { Matrix3::from_axes(x, y, z) }
+    #[inline(always)] static pure fn look_at(dir: &dvec3, up: &dvec3) -> dmat3 { Matrix3::look_at(dir, up) }
+    #[inline(always)] static pure fn from_two_vectors(a: &dvec3, b: &dvec3) -> dmat3 { Matrix3::from_two_vectors(a, b) }
+    
     #[inline(always)] static pure fn dim() -> uint { 3 }
     #[inline(always)] static pure fn rows() -> uint { 3 }
     #[inline(always)] static pure fn cols() -> uint { 3 }
@@ -0,0 +1,407 @@
+use core::cast::transmute;
+use core::cmp::Eq;
+use core::ptr::to_unsafe_ptr;
+use core::sys::size_of;
+use core::vec::raw::buf_as_slice;
+
+use std::cmp::{FuzzyEq, FUZZY_EPSILON};
+use numeric::*;
+use numeric::number::Number;
+use numeric::number::Number::{zero,one};
+
+use vec::{
+    Vec3,
+    Vec4,
+    Vector3,
+    Vector4,
+    NumericVector,
+    vec3,
+    dvec3,
+    vec4,
+    dvec4,
+};
+
+use mat::{
+    Mat3,
+    Mat4,
+    Mat2x3,
+    Mat2x4,
+    Mat4x3,
+    Matrix4,
+    MatrixMulRHS,
+    MatrixScalarMul,
+};
+
+/**
+ * A 3 x 4 column major matrix, with 3 columns and 4 rows
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the elements of the matrix. Should be a floating point type.
+ *
+ * # Fields
+ *
+ * * `x` - the first column vector of the matrix
+ * * `y` - the second column vector of the matrix
+ * * `z` - the third column vector of the matrix
+ */
+#[deriving_eq]
+pub struct Mat3x4<T> { x: Vec4<T>, y: Vec4<T>, z: Vec4<T> }
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat3x4<T> {
+    /**
+     * Construct a 3 x 4 matrix
+     *
+     * # Arguments
+     *
+     * * `c0r0`, `c0r1`, `c0r2`, `c0r3` - the first column of the matrix
+     * * `c1r0`, `c1r1`, `c1r2`, `c1r3` - the second column of the matrix
+     * * `c2r0`, `c2r1`, `c2r2`, `c2r3` - the third column of the matrix
+     *
+     * ~~~
+     *        c0     c1     c2
+     *     +------+------+------+
+     *  r0 | c0r0 | c1r0 | c2r0 |
+     *     +------+------+------+
+     *  r1 | c0r1 | c1r1 | c2r1 |
+     *     +------+------+------+
+     *  r2 | c0r2 | c1r2 | c2r2 |
+     *     +------+------+------+
+     *  r3 | c0r3 | c1r3 | c2r3 |
+     *     +------+------+------+
+     * ~~~
+     */
+    #[inline(always)]
+    static pure fn new(c0r0: T, c0r1: T, c0r2: T, c0r3: T,
+                       c1r0: T, c1r1: T, c1r2: T, c1r3: T,
+                       c2r0: T, c2r1: T, c2r2: T, c2r3: T) -> Mat3x4<T> {
+        Mat3x4::from_cols(Vector4::new::<T,Vec4<T>>(c0r0, c0r1, c0r2, c0r3),
+                          Vector4::new::<T,Vec4<T>>(c1r0, c1r1, c1r2, c1r3),
+                          Vector4::new::<T,Vec4<T>>(c2r0, c2r1, c2r2, c2r3))
+    }
+    
+    /**
+     * Construct a 3 x 4 matrix from column vectors
+     *
+     * # Arguments
+     *
+     * * `c0` - the first column vector of the matrix
+     * * `c1` - the second column vector of the matrix
+     * * `c2` - the third column vector of the matrix
+     *
+     * ~~~
+     *        c0     c1     c2
+     *     +------+------+------+
+     *  r0 | c0.x | c1.x | c2.x |
+     *     +------+------+------+
+     *  r1 | c0.y | c1.y | c2.y |
+     *     +------+------+------+
+     *  r2 | c0.z | c1.z | c2.z |
+     *     +------+------+------+
+     *  r3 | c0.w | c1.w | c2.w |
+     *     +------+------+------+
+     * ~~~
+     */
+    #[inline(always)]
+    static pure fn from_cols(c0: Vec4<T>,
+                             c1: Vec4<T>,
+                             c2: Vec4<T>) -> Mat3x4<T> {
+        Mat3x4 { x: c0, y: c1, z: c2 }
+    }
+    
+    /**
+     * Construct a 3 x 4 matrix with the major diagonal set to `value` and
+     * all other elements set to zero
+     */
+    #[inline(always)]
+    static pure fn from_value(value: T) -> Mat3x4<T> {
+        Mat3x4::new( value, zero(), zero(), zero(),
+                    zero(),  value, zero(), zero(),
+                    zero(), zero(),  value, zero())
+    }
+    
+    /**
+     * # Return value
+     *
+     * A matrix with ones on the major diagonal and zeros elsewhere
+     */
+    #[inline(always)]
+    static pure fn identity() -> Mat3x4<T> {
+        Mat3x4::from_value(one())
+    }
+    
+    /**
+     * # Return value
+     *
+     * A matrix with all elements set to zero
+     */
+    #[inline(always)]
+    static pure fn zero() -> Mat3x4<T> {
+        Mat3x4::from_value(zero())
+    }
+    
+    /**
+     * # Return value
+     *
+     * The column vector at `i`
+     */
+    #[inline(always)]
+    pure fn col(&self, i: uint) -> Vec4<T> { self[i] }
+    
+    /**
+     * # Return value
+     *
+     * The row vector at `i`
+     */
+    #[inline(always)]
+    pure fn row(&self, i: uint) -> Vec3<T> {
+        Vector3::new(self[0][i],
+                     self[1][i],
+                     self[2][i])
+    }
+    
+    /**
+     * # Return value
+     *
+     * The scalar multiplication of this matrix and `value`
+     */
+    #[inline(always)]
+    pure fn mul_t(&self, value: T) -> Mat3x4<T> {
+        Mat3x4::from_cols(self[0].mul_t(value),
+                          self[1].mul_t(value),
+                          self[2].mul_t(value))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The product of the matrix and the 3-dimensional column vector `vec`,
+     * which is a 4-dimensional vector
+     */
+    #[inline(always)]
+    pure fn mul_v(&self, vec: &Vec3<T>) -> Vec4<T> {
+        Vector4::new(self.row(0).dot(vec),
+                     self.row(1).dot(vec),
+                     self.row(2).dot(vec),
+                     self.row(3).dot(vec))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The matrix addition of the matrix and `other`
+     */
+    #[inline(always)]
+    pure fn add_m(&self, other: &Mat3x4<T>) -> Mat3x4<T> {
+        Mat3x4::from_cols(self[0].add_v(&other[0]),
+                          self[1].add_v(&other[1]),
+                          self[2].add_v(&other[2]))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The difference between the matrix and `other`
+     */
+    #[inline(always)]
+    pure fn sub_m(&self, other: &Mat3x4<T>) -> Mat3x4<T> {
+        Mat3x4::from_cols(self[0].sub_v(&other[0]),
+                          self[1].sub_v(&other[1]),
+                          self[2].sub_v(&other[2]))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The matrix product of the matrix and the 2 x 3 matrix `other`
+     */
+    #[inline(always)]
+    pure fn mul_m2x3(&self, other: &Mat2x3<T>) -> Mat2x4<T> {
+        Mat2x4::from_cols(self.mul_v(&other[0]),
+                          self.mul_v(&other[1]))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The matrix product of the matrix and the 3 x 3 matrix `other`
+     */
+    #[inline(always)]
+    pure fn mul_m3(&self, other: &Mat3<T>) -> Mat3x4<T> {
+        Mat3x4::from_cols(self.mul_v(&other[0]),
+                          self.mul_v(&other[1]),
+                          self.mul_v(&other[2]))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The matrix product of the matrix and the 4 x 3 matrix `other`
+     */
+    #[inline(always)]
+    pure fn mul_m4x3(&self, other: &Mat4x3<T>) -> Mat4<T> {
+        Matrix4::from_cols(self.mul_v(&other[0]),
+                           self.mul_v(&other[1]),
+                           self.mul_v(&other[2]),
+                           self.mul_v(&other[3]))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The transposed matrix, which has 4 columns and 3 rows
+     */
+    #[inline(always)]
+    pure fn transpose(&self) -> Mat4x3<T> {
+        Mat4x3::from_cols(self.row(0),
+                          self.row(1),
+                          self.row(2),
+                          self.row(3))
+    }
+    
+    /**
+     * # Return value
+     *
+     * A pointer to the first element of the matrix
+     */
+    #[inline(always)]
+    pure fn to_ptr(&self) -> *T {
+        unsafe {
+            transmute::<*Mat3x4<T>, *T>(
+                to_unsafe_ptr(self)
+            )
+        }
+    }
+}
+
+pub impl<T:Copy> Mat3x4<T>: Index<uint, Vec4<T>> {
+    #[inline(always)]
+    pure fn index(&self, i: uint) -> Vec4<T> {
+        unsafe { do buf_as_slice(
+            transmute::<*Mat3x4<T>, *Vec4<T>>(
+                to_unsafe_ptr(self)), 3) |slice| { slice[i] }
+        }
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat3x4<T>: Neg<Mat3x4<T>> {
+    #[inline(always)]
+    pure fn neg(&self) -> Mat3x4<T> {
+        Mat3x4::from_cols(-self[0], -self[1], -self[2])
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat3x4<T>: Add<Mat3x4<T>, Mat3x4<T>> {
+    #[inline(always)]
+    pure fn add(&self, rhs: &Mat3x4<T>) -> Mat3x4<T> {
+        self.add_m(rhs)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat3x4<T>: Sub<Mat3x4<T>, Mat3x4<T>> {
+    #[inline(always)]
+    pure fn sub(&self, rhs: &Mat3x4<T>) -> Mat3x4<T> {
+        self.sub_m(rhs)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>, R, RHS:MatrixMulRHS<Mat3x4<T>, R>> Mat3x4<T>: Mul<RHS, R> {
+    #[inline(always)]
+    pure fn mul(&self, rhs: &RHS) -> R {
+        rhs.rmul_m(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat3x4<T>: Div<T, Mat3x4<T>> {
+    #[inline(always)]
+    pure fn div(&self, rhs: &T) -> Mat3x4<T> {
+        self.mul_t(one::<T>() / *rhs)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat3x4<T>: MatrixScalarMul<T> {
+    #[inline(always)]
+    pure fn mul_scalar(&self, value: T) -> Mat3x4<T> {
+        self.mul_t(value)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Vec3<T>: MatrixMulRHS<Mat3x4<T>, Vec4<T>> {
+    #[inline(always)]
+    pure fn rmul_m(&self, lhs: &Mat3x4<T>) -> Vec4<T> {
+        lhs.mul_v(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat2x3<T>: MatrixMulRHS<Mat3x4<T>, Mat2x4<T>> {
+    #[inline(always)]
+    pure fn rmul_m(&self, lhs: &Mat3x4<T>) -> Mat2x4<T> {
+        lhs.mul_m2x3(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat3<T>: MatrixMulRHS<Mat3x4<T>, Mat3x4<T>> {
+    #[inline(always)]
+    pure fn rmul_m(&self, lhs: &Mat3x4<T>) -> Mat3x4<T> {
+        lhs.mul_m3(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat4x3<T>: MatrixMulRHS<Mat3x4<T>, Mat4<T>> {
+    #[inline(always)]
+    pure fn rmul_m(&self, lhs: &Mat3x4<T>) -> Mat4<T> {
+        lhs.mul_m4x3(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat3x4<T>: FuzzyEq<T> {
+    #[inline(always)]
+    pure fn fuzzy_eq(&self, other: &Mat3x4<T>) -> bool {
+        self.fuzzy_eq_eps(other, &Number::from(FUZZY_EPSILON))
+    }
+    
+    #[inline(always)]
+    pure fn fuzzy_eq_eps(&self, other: &Mat3x4<T>, epsilon: &T) -> bool {
+        self[0].fuzzy_eq_eps(&other[0], epsilon) &&
+        self[1].fuzzy_eq_eps(&other[1], epsilon) &&
+        self[2].fuzzy_eq_eps(&other[2], epsilon)
+    }
+}
+
+// GLSL-style type aliases, corresponding to Section 4.1.6 of the [GLSL 4.30.6 specification]
+// (http://www.opengl.org/registry/doc/GLSLangSpec.4.30.6.pdf).
+
+pub type mat3x4 = Mat3x4<f32>;    // a 3×4 single-precision floating-point matrix
+pub type dmat3x4 = Mat3x4<f64>;   // a 3×4 double-precision floating-point matrix
+
+// Static method wrappers for GLSL-style types
+
+pub impl mat3x4 {
+    #[inline(always)] static pure fn new(c0r0: f32, c0r1: f32, c0r2: f32, c0r3: f32, c1r0: f32, c1r1: f32, c1r2: f32, c1r3: f32, c2r0: f32, c2r1: f32, c2r2: f32, c2r3: f32)
+        -> mat3x4 { Mat3x4::new(c0r0, c0r1, c0r2, c0r3, c1r0, c1r1, c1r2, c1r3, c2r0, c2r1, c2r2, c2r3) }
+    #[inline(always)] static pure fn from_cols(c0: vec4, c1: vec4, c2: vec4)
+        -> mat3x4 { Mat3x4::from_cols(move c0, move c1, move c2) }
+    #[inline(always)] static pure fn from_value(v: f32) -> mat3x4 { Mat3x4::from_value(v) }
+    
+    #[inline(always)] static pure fn identity() -> mat3x4 { Mat3x4::identity() }
+    #[inline(always)] static pure fn zero() -> mat3x4 { Mat3x4::zero() }
+    
+    #[inline(always)] static pure fn rows() -> uint { 4 }
+    #[inline(always)] static pure fn cols() -> uint { 3 }
+    #[inline(always)] static pure fn size_of() -> uint { size_of::<mat3x4>() }
+}
+
+pub impl dmat3x4 {
+    #[inline(always)] static pure fn new(c0r0: f64, c0r1: f64, c0r2: f64, c0r3: f64, c1r0: f64, c1r1: f64, c1r2: f64, c1r3: f64, c2r0: f64, c2r1: f64, c2r2: f64, c2r3: f64)
+        -> dmat3x4 { Mat3x4::new(c0r0, c0r1, c0r2, c0r3, c1r0, c1r1, c1r2, c1r3, c2r0, c2r1, c2r2, c2r3) }
+    #[inline(always)] static pure fn from_cols(c0: dvec4, c1: dvec4, c2: dvec4)
+        -> dmat3x4 { Mat3x4::from_cols(move c0, move c1, move c2) }
+    #[inline(always)] static pure fn from_value(v: f64) -> dmat3x4 { Mat3x4::from_value(v) }
+    
+    #[inline(always)] static pure fn identity() -> dmat3x4 { Mat3x4::identity() }
+    #[inline(always)] static pure fn zero() -> dmat3x4 { Mat3x4::zero() }
+    
+    #[inline(always)] static pure fn rows() -> uint { 4 }
+    #[inline(always)] static pure fn cols() -> uint { 3 }
+    #[inline(always)] static pure fn size_of() -> uint { size_of::<dmat3x4>() }
+}
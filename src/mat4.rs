@@ -10,12 +10,21 @@ use numeric::*;
 use numeric::number::Number;
 use numeric::number::Number::{zero,one};
 
+use angle::Angle;
+use quat::Quat;
+
 use vec::{
+    Vec3,
     Vec4,
+    Vector3,
     Vector4,
     MutableVector,
     NumericVector,
+    NumericVector3,
     MutableNumericVector,
+    EuclideanVector,
+    vec3,
+    dvec3,
     vec4,
     dvec4,
 };
@@ -26,6 +35,19 @@ use mat::{
     Matrix3,
     Matrix4,
     MutableMatrix,
+    MatrixMulRHS,
+    MatrixScalarMul,
+    lu_decompose,
+    lu_solve,
+    qr_decompose,
+    lu_is_singular,
+    svd_decompose,
+    svd_decompose_proper,
+    svd_pseudo_inverse,
+    svd_rank,
+    svd_condition_number,
+    polar_decompose,
+    jacobi_rotation,
 };
 
 /**
@@ -192,23 +214,12 @@ pub impl<T:Copy Float FuzzyEq<T>> Mat4<T>: Matrix<T, Vec4<T>> {
     }
 
     pure fn determinant(&self) -> T {
-        let m0: Mat3<T> = Matrix3::new(self[1][1], self[2][1], self[3][1],
-                                       self[1][2], self[2][2], self[3][2],
-                                       self[1][3], self[2][3], self[3][3]);
-        let m1: Mat3<T> = Matrix3::new(self[0][1], self[2][1], self[3][1],
-                                       self[0][2], self[2][2], self[3][2],
-                                       self[0][3], self[2][3], self[3][3]);
-        let m2: Mat3<T> = Matrix3::new(self[0][1], self[1][1], self[3][1],
-                                       self[0][2], self[1][2], self[3][2],
-                                       self[0][3], self[1][3], self[3][3]);
-        let m3: Mat3<T> = Matrix3::new(self[0][1], self[1][1], self[2][1],
-                                       self[0][2], self[1][2], self[2][2],
-                                       self[0][3], self[1][3], self[2][3]);
+        // The determinant of a triangular matrix is the product of its
+        // diagonal, and each row swap in the permutation flips the sign
+        let (_, _, u, odd) = lu_decompose::<T, Vec4<T>, Mat4<T>>(self, 4);
+        let d = u[0][0] * u[1][1] * u[2][2] * u[3][3];
         
-        self[0][0] * m0.determinant() -
-        self[1][0] * m1.determinant() +
-        self[2][0] * m2.determinant() -
-        self[3][0] * m3.determinant()
+        if odd { -d } else { d }
     }
 
     pure fn trace(&self) -> T {
@@ -216,51 +227,71 @@ pub impl<T:Copy Float FuzzyEq<T>> Mat4<T>: Matrix<T, Vec4<T>> {
     }
 
     pure fn inverse(&self) -> Option<Mat4<T>> {
-        let d = self.determinant();
-        if d.fuzzy_eq(&zero()) {
+        let (p, l, u, _) = lu_decompose::<T, Vec4<T>, Mat4<T>>(self, 4);
+        
+        if lu_is_singular::<T, Vec4<T>, Mat4<T>>(&u, 4) {
             None
         } else {
+            // Solve for each column of the inverse in turn, reusing the
+            // decomposition
+            let ident: Mat4<T> = Matrix::identity();
             
-            // Gauss Jordan Elimination with partial pivoting
-            // So take this matrix, A, augmented with the identity
-            // and essentially reduce [A|I]
-            
-            let mut A = *self;
-            let mut I: Mat4<T> = Matrix::identity();
-            
-            for uint::range(0, 4) |j| {
-                // Find largest element in col j
-                let mut i1 = j;
-                for uint::range(j + 1, 4) |i| {
-                    if abs(A[j][i]) > abs(A[j][i1]) {
-                        i1 = i;
-                    }
-                }
-                
-                unsafe {
-                    // Swap columns i1 and j in A and I to
-                    // put pivot on diagonal
-                    A.swap_cols(i1, j);
-                    I.swap_cols(i1, j);
-                    
-                    // Scale col j to have a unit diagonal
-                    I.col_mut(j).div_self_t(&A[j][j]);
-                    A.col_mut(j).div_self_t(&A[j][j]);
-                    
-                    // Eliminate off-diagonal elems in col j of A,
-                    // doing identical ops to I
-                    for uint::range(0, 4) |i| {
-                        if i != j {
-                            I.col_mut(i).sub_self_v(&I[j].mul_t(A[i][j]));
-                            A.col_mut(i).sub_self_v(&A[j].mul_t(A[i][j]));
-                        }
-                    }
-                }
-            }
-            Some(I)
+            Some(Matrix4::from_cols(lu_solve(&p, &l, &u, &ident[0], 4).unwrap(),
+                                    lu_solve(&p, &l, &u, &ident[1], 4).unwrap(),
+                                    lu_solve(&p, &l, &u, &ident[2], 4).unwrap(),
+                                    lu_solve(&p, &l, &u, &ident[3], 4).unwrap()))
+        }
+    }
+    
+    #[inline(always)]
+    pure fn lu(&self) -> (Mat4<T>, Mat4<T>, Mat4<T>) {
+        match lu_decompose::<T, Vec4<T>, Mat4<T>>(self, 4) {
+            (p, l, u, _) => (p, l, u)
+        }
+    }
+    
+    #[inline(always)]
+    pure fn solve(&self, b: &Vec4<T>) -> Option<Vec4<T>> {
+        match self.lu() {
+            (p, l, u) => lu_solve(&p, &l, &u, b, 4)
         }
     }
     
+    #[inline(always)]
+    pure fn qr(&self) -> (Mat4<T>, Mat4<T>) {
+        qr_decompose::<T, Vec4<T>, Mat4<T>>(self, 4)
+    }
+    
+    #[inline(always)]
+    pure fn svd(&self) -> (Mat4<T>, Vec4<T>, Mat4<T>) {
+        svd_decompose::<T, Vec4<T>, Mat4<T>>(self, 4)
+    }
+    
+    #[inline(always)]
+    pure fn proper_svd(&self) -> (Mat4<T>, Vec4<T>, Mat4<T>) {
+        svd_decompose_proper::<T, Vec4<T>, Mat4<T>>(self, 4)
+    }
+    
+    #[inline(always)]
+    pure fn pseudo_inverse(&self) -> Mat4<T> {
+        svd_pseudo_inverse::<T, Vec4<T>, Mat4<T>>(self, 4)
+    }
+    
+    #[inline(always)]
+    pure fn rank(&self, epsilon: T) -> uint {
+        svd_rank::<T, Vec4<T>, Mat4<T>>(self, epsilon, 4)
+    }
+    
+    #[inline(always)]
+    pure fn condition_number(&self) -> T {
+        svd_condition_number::<T, Vec4<T>, Mat4<T>>(self, 4)
+    }
+    
+    #[inline(always)]
+    pure fn polar(&self) -> (Mat4<T>, Mat4<T>) {
+        polar_decompose::<T, Vec4<T>, Mat4<T>>(self, 4)
+    }
+    
     #[inline(always)]
     pure fn transpose(&self) -> Mat4<T> {
         Matrix4::new(self[0][0], self[1][0], self[2][0], self[3][0],
@@ -319,7 +350,9 @@ pub impl<T:Copy Float FuzzyEq<T>> Mat4<T>: Matrix<T, Vec4<T>> {
 
     #[inline(always)]
     pure fn is_invertible(&self) -> bool {
-        !self.determinant().fuzzy_eq(&zero())
+        match self.lu() {
+            (_, _, u) => !lu_is_singular::<T, Vec4<T>, Mat4<T>>(&u, 4)
+        }
     }
     
     #[inline(always)]
@@ -397,6 +430,244 @@ pub impl<T:Copy Float FuzzyEq<T>> Mat4<T>: Matrix4<T, Vec4<T>> {
                              c3: Vec4<T>) -> Mat4<T> {
         Mat4 { x: c0, y: c1, z: c2, w: c3 }
     }
+    
+    /**
+     * Construct a matrix that translates by `v`
+     *
+     * ~~~
+     *       c0   c1   c2   c3
+     *     +----+----+----+-----+
+     *  r0 |  1 |  0 |  0 | v.x |
+     *     +----+----+----+-----+
+     *  r1 |  0 |  1 |  0 | v.y |
+     *     +----+----+----+-----+
+     *  r2 |  0 |  0 |  1 | v.z |
+     *     +----+----+----+-----+
+     *  r3 |  0 |  0 |  0 |   1 |
+     *     +----+----+----+-----+
+     * ~~~
+     */
+    #[inline(always)]
+    static pure fn from_translation(v: &Vec3<T>) -> Mat4<T> {
+        Matrix4::new( one(), zero(), zero(), zero(),
+                     zero(),  one(), zero(), zero(),
+                     zero(), zero(),  one(), zero(),
+                        v.x,    v.y,    v.z,  one())
+    }
+    
+    /**
+     * Construct a matrix that scales each axis by the corresponding
+     * component of `v`
+     *
+     * ~~~
+     *        c0    c1    c2   c3
+     *     +-----+-----+-----+----+
+     *  r0 | v.x |   0 |   0 |  0 |
+     *     +-----+-----+-----+----+
+     *  r1 |   0 | v.y |   0 |  0 |
+     *     +-----+-----+-----+----+
+     *  r2 |   0 |   0 | v.z |  0 |
+     *     +-----+-----+-----+----+
+     *  r3 |   0 |   0 |   0 |  1 |
+     *     +-----+-----+-----+----+
+     * ~~~
+     */
+    #[inline(always)]
+    static pure fn from_scale(v: &Vec3<T>) -> Mat4<T> {
+        Matrix4::new(   v.x, zero(), zero(), zero(),
+                     zero(),    v.y, zero(), zero(),
+                     zero(), zero(),    v.z, zero(),
+                     zero(), zero(), zero(),  one())
+    }
+    
+    #[inline(always)]
+    static pure fn from_uniform_scale(value: T) -> Mat4<T> {
+        Matrix4::from_scale(&Vector3::new(value, value, value))
+    }
+    
+    #[inline(always)]
+    static pure fn from_mat3(m: &Mat3<T>) -> Mat4<T> {
+        m.to_mat4()
+    }
+    
+    #[inline(always)]
+    static pure fn from_quat(q: &Quat<T>) -> Mat4<T> {
+        q.to_mat4()
+    }
+    
+    #[inline(always)]
+    static pure fn from_angle_axis<A:Angle<T>>(theta: A, axis: &Vec3<T>) -> Mat4<T> {
+        let m: Mat3<T> = Matrix3::from_angle_axis(theta, axis); m.to_mat4()
+    }
+    
+    static pure fn look_at(eye: &Vec3<T>, center: &Vec3<T>, up: &Vec3<T>) -> Mat4<T> {
+        let f = center.sub_v(eye).normalize();
+        let s = f.cross(up).normalize();
+        let u = s.cross(&f);
+        
+        Matrix4::new(        s.x,         u.x,       -f.x, zero(),
+                             s.y,         u.y,       -f.y, zero(),
+                             s.z,         u.z,       -f.z, zero(),
+                     -s.dot(eye), -u.dot(eye), f.dot(eye),  one())
+    }
+    
+    #[inline(always)]
+    pure fn translate(&self, v: &Vec3<T>) -> Mat4<T> {
+        self.mul_m(&Matrix4::from_translation(v))
+    }
+    
+    #[inline(always)]
+    pure fn scale(&self, v: &Vec3<T>) -> Mat4<T> {
+        self.mul_m(&Matrix4::from_scale(v))
+    }
+    
+    #[inline(always)]
+    pure fn rotate<A:Angle<T>>(&self, theta: A, axis: &Vec3<T>) -> Mat4<T> {
+        self.mul_m(&Matrix4::from_angle_axis(theta, axis))
+    }
+    
+    /**
+     * Returns the the matrix with the last row and column removed
+     * ~~~
+     *       c0   c1   c2   c3                 c0   c1   c2
+     *     +----+----+----+----+             +----+----+----+
+     *  r0 |  a |  b |  c |  d |          r0 |  a |  b |  c |
+     *     +----+----+----+----+             +----+----+----+
+     *  r1 |  e |  f |  g |  h |    =>    r1 |  e |  f |  g |
+     *     +----+----+----+----+             +----+----+----+
+     *  r2 |  i |  j |  k |  l |          r2 |  i |  j |  k |
+     *     +----+----+----+----+             +----+----+----+
+     *  r3 |  m |  n |  o |  p |
+     *     +----+----+----+----+
+     * ~~~
+     */
+    #[inline(always)]
+    pure fn to_mat3(&self) -> Mat3<T> {
+        Matrix3::new(self[0][0], self[0][1], self[0][2],
+                     self[1][0], self[1][1], self[1][2],
+                     self[2][0], self[2][1], self[2][2])
+    }
+    
+    #[inline(always)]
+    pure fn to_quat(&self) -> Quat<T> {
+        // Dividing each column by its length removes the scale
+        let m = self.to_mat3();
+        let r: Mat3<T> = Matrix3::from_cols(m[0].normalize(), m[1].normalize(), m[2].normalize());
+        r.to_quat()
+    }
+    
+    pure fn decompose(&self) -> Option<Decomposed<T>> {
+        // The bottom row of an affine transformation is `(0, 0, 0, 1)`
+        if !self.row(3).fuzzy_eq(&Vector4::new(zero(), zero(), zero(), one())) {
+            None
+        } else {
+            let m = self.to_mat3();
+            
+            // `m == q * r`, where the diagonal of `r` holds the scale and the
+            // elements above it hold the scale multiplied by the shear
+            match m.qr() {
+                (q, r) => {
+                    let mut q = q;
+                    let mut r = r;
+                    
+                    // Move any reflection out of `q` and into the `z` scale,
+                    // so that `q` is a rotation
+                    if q.determinant() < zero() {
+                        unsafe {
+                            let z = -q[2];
+                            *q.col_mut(2) = z;
+                            *r.col_mut(2).index_mut(2) = -r[2][2];
+                        }
+                    }
+                    
+                    // `r` is triangular, so the same relative test of its
+                    // diagonal applies as for the `u` of an LU decomposition
+                    if lu_is_singular::<T, Vec3<T>, Mat3<T>>(&r, 3) {
+                        None
+                    } else {
+                        Some(Decomposed::new(Vector3::new(self[3][0], self[3][1], self[3][2]),
+                                             q.to_quat(),
+                                             Vector3::new(r[0][0], r[1][1], r[2][2]),
+                                             Vector3::new(r[1][0] / r[1][1],
+                                                          r[2][0] / r[2][2],
+                                                          r[2][1] / r[2][2])))
+                    }
+                }
+            }
+        }
+    }
+    
+    pure fn symmetric_eigen(&self) -> Option<(Vec4<T>, Mat4<T>)> {
+        if !self.is_symmetric() {
+            None
+        } else {
+            // Cyclic Jacobi eigenvalue algorithm, as in `Mat3::symmetric_eigen`
+            
+            let _1: T = Number::from(1);
+            let _2: T = Number::from(2);
+            let _100: T = Number::from(100);
+            
+            let mut a = *self;
+            let mut vecs: Mat4<T> = Matrix::identity();
+            let mut sweep = 0;
+            let mut converged = false;
+            
+            while !converged && sweep < 50 {
+                converged = true;
+                
+                for uint::range(0, 3) |p| {
+                    for uint::range(p + 1, 4) |q| {
+                        let apq = a[q][p];
+                        
+                        // skip the rotation once the off-diagonal element is
+                        // negligible relative to the diagonal elements
+                        let g = abs(apq) * _100;
+                        
+                        if abs(a[p][p]) + g != abs(a[p][p]) ||
+                           abs(a[q][q]) + g != abs(a[q][q]) {
+                            converged = false;
+                            
+                            let theta = (a[q][q] - a[p][p]) / (_2 * apq);
+                            let t = if theta >= zero() {
+                                _1 / (theta + (theta * theta + _1).sqrt())
+                            } else {
+                                -_1 / (-theta + (theta * theta + _1).sqrt())
+                            };
+                            let c = _1 / (t * t + _1).sqrt();
+                            let s = t * c;
+                            
+                            let rot = jacobi_rotation::<T, Vec4<T>, Mat4<T>>(p, q, c, s);
+                            
+                            a = rot.transpose().mul_m(&a).mul_m(&rot);
+                            vecs = vecs.mul_m(&rot);
+                        }
+                    }
+                }
+                
+                sweep += 1;
+            }
+            
+            if !converged {
+                return None;
+            }
+            
+            // Sort the eigenvalues, and their eigenvectors, in ascending order
+            let mut i = [0, 1, 2, 3];
+            
+            for uint::range(1, 4) |k| {
+                let mut j = k;
+                while j > 0 && a[i[j - 1]][i[j - 1]] > a[i[j]][i[j]] {
+                    let tmp = i[j];
+                    i[j] = i[j - 1];
+                    i[j - 1] = tmp;
+                    j -= 1;
+                }
+            }
+            
+            Some((Vector4::new(a[i[0]][i[0]], a[i[1]][i[1]], a[i[2]][i[2]], a[i[3]][i[3]]),
+                  Matrix4::from_cols(vecs[i[0]], vecs[i[1]], vecs[i[2]], vecs[i[3]])))
+        }
+    }
 }
 
 pub impl<T:Copy Float FuzzyEq<T>> Mat4<T>: MutableMatrix<T, Vec4<T>> {
@@ -509,6 +780,55 @@ pub impl<T:Copy> Mat4<T>: Index<uint, Vec4<T>> {
     }
 }
 
+pub impl<T:Copy Float FuzzyEq<T>> Mat4<T>: Add<Mat4<T>, Mat4<T>> {
+    #[inline(always)]
+    pure fn add(&self, rhs: &Mat4<T>) -> Mat4<T> {
+        self.add_m(rhs)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat4<T>: Sub<Mat4<T>, Mat4<T>> {
+    #[inline(always)]
+    pure fn sub(&self, rhs: &Mat4<T>) -> Mat4<T> {
+        self.sub_m(rhs)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>, R, RHS:MatrixMulRHS<Mat4<T>, R>> Mat4<T>: Mul<RHS, R> {
+    #[inline(always)]
+    pure fn mul(&self, rhs: &RHS) -> R {
+        rhs.rmul_m(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat4<T>: Div<T, Mat4<T>> {
+    #[inline(always)]
+    pure fn div(&self, rhs: &T) -> Mat4<T> {
+        self.mul_t(one::<T>() / *rhs)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat4<T>: MatrixScalarMul<T> {
+    #[inline(always)]
+    pure fn mul_scalar(&self, value: T) -> Mat4<T> {
+        self.mul_t(value)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat4<T>: MatrixMulRHS<Mat4<T>, Mat4<T>> {
+    #[inline(always)]
+    pure fn rmul_m(&self, lhs: &Mat4<T>) -> Mat4<T> {
+        lhs.mul_m(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Vec4<T>: MatrixMulRHS<Mat4<T>, Vec4<T>> {
+    #[inline(always)]
+    pure fn rmul_m(&self, lhs: &Mat4<T>) -> Vec4<T> {
+        lhs.mul_v(self)
+    }
+}
+
 pub impl<T:Copy Float FuzzyEq<T>> Mat4<T>: FuzzyEq<T> {
     #[inline(always)]
     pure fn fuzzy_eq(&self, other: &Mat4<T>) -> bool {
@@ -524,6 +844,81 @@ pub impl<T:Copy Float FuzzyEq<T>> Mat4<T>: FuzzyEq<T> {
     }
 }
 
+/**
+ * An affine transformation decomposed into a translation, a rotation, a scale
+ * and a shear, as returned by `Matrix4::decompose`
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the components. Should be a floating point type.
+ *
+ * # Fields
+ *
+ * * `translation` - the translation along each axis
+ * * `rotation` - the rotation, as a unit quaternion
+ * * `scale` - the scale along each axis. A reflection is represented by a
+ *             negative `z` scale.
+ * * `shear` - the shear factors `(xy, xz, yz)`, where `xy` is the shift along
+ *             the `x` axis per unit along the `y` axis, and so on
+ */
+#[deriving_eq]
+pub struct Decomposed<T> {
+    translation: Vec3<T>,
+    rotation:    Quat<T>,
+    scale:       Vec3<T>,
+    shear:       Vec3<T>,
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Decomposed<T> {
+    #[inline(always)]
+    static pure fn new(translation: Vec3<T>, rotation: Quat<T>, scale: Vec3<T>, shear: Vec3<T>) -> Decomposed<T> {
+        Decomposed {
+            translation: translation,
+            rotation:    rotation,
+            scale:       scale,
+            shear:       shear,
+        }
+    }
+    
+    /**
+     * # Return value
+     *
+     * The transformation matrix, which applies the scale, then the shear,
+     * then the rotation, and finally the translation
+     */
+    #[inline(always)]
+    pure fn compose(&self) -> Mat4<T> {
+        let s = &self.scale;
+        let h = &self.shear;
+        let t = &self.translation;
+        
+        let sh: Mat3<T> = Matrix3::new(      s.x,    zero(), zero(),
+                                       h.x * s.y,       s.y, zero(),
+                                       h.y * s.z, h.z * s.z,    s.z);
+        let m = self.rotation.to_mat3().mul_m(&sh);
+        
+        Matrix4::new(m[0][0], m[0][1], m[0][2], zero(),
+                     m[1][0], m[1][1], m[1][2], zero(),
+                     m[2][0], m[2][1], m[2][2], zero(),
+                         t.x,     t.y,     t.z,  one())
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Decomposed<T>: FuzzyEq<T> {
+    #[inline(always)]
+    pure fn fuzzy_eq(&self, other: &Decomposed<T>) -> bool {
+        self.fuzzy_eq_eps(other, &Number::from(FUZZY_EPSILON))
+    }
+    
+    #[inline(always)]
+    pure fn fuzzy_eq_eps(&self, other: &Decomposed<T>, epsilon: &T) -> bool {
+        self.translation.fuzzy_eq_eps(&other.translation, epsilon) &&
+        self.rotation.fuzzy_eq_eps(&other.rotation, epsilon) &&
+        self.scale.fuzzy_eq_eps(&other.scale, epsilon) &&
+        self.shear.fuzzy_eq_eps(&other.shear, epsilon)
+    }
+}
+
 // GLSL-style type aliases, corresponding to Section 4.1.6 of the [GLSL 4.30.6 specification]
 // (http://www.opengl.org/registry/doc/GLSLangSpec.4.30.6.pdf).
 
@@ -542,6 +937,14 @@ pub impl mat4 {
     #[inline(always)] static pure fn identity() -> mat4 { Matrix::identity() }
     #[inline(always)] static pure fn zero() -> mat4 { Matrix::zero() }
     
+    #[inline(always)] static pure fn from_translation(v: &vec3) -> mat4 { Matrix4::from_translation(v) }
+    #[inline(always)] static pure fn from_scale(v: &vec3) -> mat4 { Matrix4::from_scale(v) }
+    #[inline(always)] static pure fn from_uniform_scale(value: f32) -> mat4 { Matrix4::from_uniform_scale(value) }
+    #[inline(always)] static pure fn from_mat3(m: &Mat3<f32>) -> mat4 { Matrix4::from_mat3(m) }
+    #[inline(always)] static pure fn from_quat(q: &Quat<f32>) -> mat4 { Matrix4::from_quat(q) }
+    #[inline(always)] static pure fn from_angle_axis<A:Angle<f32>>(theta: A, axis: &vec3) -> mat4 { Matrix4::from_angle_axis(theta, axis) }
+    #[inline(always)] static pure fn look_at(eye: &vec3, center: &vec3, up: &vec3) -> mat4 { Matrix4::look_at(eye, center, up) }
+    
     #[inline(always)] static pure fn dim() -> uint { 4 }
     #[inline(always)] static pure fn rows() -> uint { 4 }
     #[inline(always)] static pure fn cols() -> uint { 4 }
@@ -558,6 +961,14 @@ pub impl dmat4 {
     #[inline(always)] static pure fn identity() -> dmat4 { Matrix::identity() }
     #[inline(always)] static pure fn zero() -> dmat4 { Matrix::zero() }
     
+    #[inline(always)] static pure fn from_translation(v: &dvec3) -> dmat4 { Matrix4::from_translation(v) }
+    #[inline(always)] static pure fn from_scale(v: &dvec3) -> dmat4 { Matrix4::from_scale(v) }
+    #[inline(always)] static pure fn from_uniform_scale(value: f64) -> dmat4 { Matrix4::from_uniform_scale(value) }
+    #[inline(always)] static pure fn from_mat3(m: &Mat3<f64>) -> dmat4 { Matrix4::from_mat3(m) }
+    #[inline(always)] static pure fn from_quat(q: &Quat<f64>) -> dmat4 { Matrix4::from_quat(q) }
+    #[inline(always)] static pure fn from_angle_axis<A:Angle<f64>>(theta: A, axis: &dvec3) -> dmat4 { Matrix4::from_angle_axis(theta, axis) }
+    #[inline(always)] static pure fn look_at(eye: &dvec3, center: &dvec3, up: &dvec3) -> dmat4 { Matrix4::look_at(eye, center, up) }
+    
     #[inline(always)] static pure fn dim() -> uint { 4 }
     #[inline(always)] static pure fn rows() -> uint { 4 }
     #[inline(always)] static pure fn cols() -> uint { 4 }
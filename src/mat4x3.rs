@@ -0,0 +1,444 @@
+use core::cast::transmute;
+use core::cmp::Eq;
+use core::ptr::to_unsafe_ptr;
+use core::sys::size_of;
+use core::vec::raw::buf_as_slice;
+
+use std::cmp::{FuzzyEq, FUZZY_EPSILON};
+use numeric::*;
+use numeric::number::Number;
+use numeric::number::Number::{zero,one};
+
+use vec::{
+    Vec3,
+    Vec4,
+    Vector3,
+    Vector4,
+    NumericVector,
+    vec3,
+    dvec3,
+    vec4,
+    dvec4,
+};
+
+use mat::{
+    Mat3,
+    Mat4,
+    Mat2x3,
+    Mat2x4,
+    Mat3x4,
+    Matrix3,
+    Matrix4,
+    MatrixMulRHS,
+    MatrixScalarMul,
+};
+
+/**
+ * A 4 x 3 column major matrix, with 4 columns and 3 rows
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the elements of the matrix. Should be a floating point type.
+ *
+ * # Fields
+ *
+ * * `x` - the first column vector of the matrix
+ * * `y` - the second column vector of the matrix
+ * * `z` - the third column vector of the matrix
+ * * `w` - the fourth column vector of the matrix
+ */
+#[deriving_eq]
+pub struct Mat4x3<T> { x: Vec3<T>, y: Vec3<T>, z: Vec3<T>, w: Vec3<T> }
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat4x3<T> {
+    /**
+     * Construct a 4 x 3 matrix
+     *
+     * # Arguments
+     *
+     * * `c0r0`, `c0r1`, `c0r2` - the first column of the matrix
+     * * `c1r0`, `c1r1`, `c1r2` - the second column of the matrix
+     * * `c2r0`, `c2r1`, `c2r2` - the third column of the matrix
+     * * `c3r0`, `c3r1`, `c3r2` - the fourth column of the matrix
+     *
+     * ~~~
+     *        c0     c1     c2     c3
+     *     +------+------+------+------+
+     *  r0 | c0r0 | c1r0 | c2r0 | c3r0 |
+     *     +------+------+------+------+
+     *  r1 | c0r1 | c1r1 | c2r1 | c3r1 |
+     *     +------+------+------+------+
+     *  r2 | c0r2 | c1r2 | c2r2 | c3r2 |
+     *     +------+------+------+------+
+     * ~~~
+     */
+    #[inline(always)]
+    static pure fn new(c0r0: T, c0r1: T, c0r2: T,
+                       c1r0: T, c1r1: T, c1r2: T,
+                       c2r0: T, c2r1: T, c2r2: T,
+                       c3r0: T, c3r1: T, c3r2: T) -> Mat4x3<T> {
+        Mat4x3::from_cols(Vector3::new::<T,Vec3<T>>(c0r0, c0r1, c0r2),
+                          Vector3::new::<T,Vec3<T>>(c1r0, c1r1, c1r2),
+                          Vector3::new::<T,Vec3<T>>(c2r0, c2r1, c2r2),
+                          Vector3::new::<T,Vec3<T>>(c3r0, c3r1, c3r2))
+    }
+    
+    /**
+     * Construct a 4 x 3 matrix from column vectors
+     *
+     * # Arguments
+     *
+     * * `c0` - the first column vector of the matrix
+     * * `c1` - the second column vector of the matrix
+     * * `c2` - the third column vector of the matrix
+     * * `c3` - the fourth column vector of the matrix
+     *
+     * ~~~
+     *        c0     c1     c2     c3
+     *     +------+------+------+------+
+     *  r0 | c0.x | c1.x | c2.x | c3.x |
+     *     +------+------+------+------+
+     *  r1 | c0.y | c1.y | c2.y | c3.y |
+     *     +------+------+------+------+
+     *  r2 | c0.z | c1.z | c2.z | c3.z |
+     *     +------+------+------+------+
+     * ~~~
+     */
+    #[inline(always)]
+    static pure fn from_cols(c0: Vec3<T>,
+                             c1: Vec3<T>,
+                             c2: Vec3<T>,
+                             c3: Vec3<T>) -> Mat4x3<T> {
+        Mat4x3 { x: c0, y: c1, z: c2, w: c3 }
+    }
+    
+    /**
+     * Construct a 4 x 3 matrix with the major diagonal set to `value` and
+     * all other elements set to zero
+     */
+    #[inline(always)]
+    static pure fn from_value(value: T) -> Mat4x3<T> {
+        Mat4x3::new( value, zero(), zero(),
+                    zero(),  value, zero(),
+                    zero(), zero(),  value,
+                    zero(), zero(), zero())
+    }
+    
+    /**
+     * # Return value
+     *
+     * A matrix with ones on the major diagonal and zeros elsewhere
+     */
+    #[inline(always)]
+    static pure fn identity() -> Mat4x3<T> {
+        Mat4x3::from_value(one())
+    }
+    
+    /**
+     * # Return value
+     *
+     * A matrix with all elements set to zero
+     */
+    #[inline(always)]
+    static pure fn zero() -> Mat4x3<T> {
+        Mat4x3::from_value(zero())
+    }
+    
+    /**
+     * Construct the matrix from the first 3 rows of a 4 x 4 affine
+     * transformation matrix, discarding the last row
+     */
+    #[inline(always)]
+    static pure fn from_mat4(m: &Mat4<T>) -> Mat4x3<T> {
+        Mat4x3::from_cols(Vector3::new(m[0][0], m[0][1], m[0][2]),
+                          Vector3::new(m[1][0], m[1][1], m[1][2]),
+                          Vector3::new(m[2][0], m[2][1], m[2][2]),
+                          Vector3::new(m[3][0], m[3][1], m[3][2]))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The column vector at `i`
+     */
+    #[inline(always)]
+    pure fn col(&self, i: uint) -> Vec3<T> { self[i] }
+    
+    /**
+     * # Return value
+     *
+     * The row vector at `i`
+     */
+    #[inline(always)]
+    pure fn row(&self, i: uint) -> Vec4<T> {
+        Vector4::new(self[0][i],
+                     self[1][i],
+                     self[2][i],
+                     self[3][i])
+    }
+    
+    /**
+     * # Return value
+     *
+     * The scalar multiplication of this matrix and `value`
+     */
+    #[inline(always)]
+    pure fn mul_t(&self, value: T) -> Mat4x3<T> {
+        Mat4x3::from_cols(self[0].mul_t(value),
+                          self[1].mul_t(value),
+                          self[2].mul_t(value),
+                          self[3].mul_t(value))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The product of the matrix and the 4-dimensional column vector `vec`,
+     * which is a 3-dimensional vector
+     */
+    #[inline(always)]
+    pure fn mul_v(&self, vec: &Vec4<T>) -> Vec3<T> {
+        Vector3::new(self.row(0).dot(vec),
+                     self.row(1).dot(vec),
+                     self.row(2).dot(vec))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The matrix addition of the matrix and `other`
+     */
+    #[inline(always)]
+    pure fn add_m(&self, other: &Mat4x3<T>) -> Mat4x3<T> {
+        Mat4x3::from_cols(self[0].add_v(&other[0]),
+                          self[1].add_v(&other[1]),
+                          self[2].add_v(&other[2]),
+                          self[3].add_v(&other[3]))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The difference between the matrix and `other`
+     */
+    #[inline(always)]
+    pure fn sub_m(&self, other: &Mat4x3<T>) -> Mat4x3<T> {
+        Mat4x3::from_cols(self[0].sub_v(&other[0]),
+                          self[1].sub_v(&other[1]),
+                          self[2].sub_v(&other[2]),
+                          self[3].sub_v(&other[3]))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The matrix product of the matrix and the 2 x 4 matrix `other`
+     */
+    #[inline(always)]
+    pure fn mul_m2x4(&self, other: &Mat2x4<T>) -> Mat2x3<T> {
+        Mat2x3::from_cols(self.mul_v(&other[0]),
+                          self.mul_v(&other[1]))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The matrix product of the matrix and the 3 x 4 matrix `other`
+     */
+    #[inline(always)]
+    pure fn mul_m3x4(&self, other: &Mat3x4<T>) -> Mat3<T> {
+        Matrix3::from_cols(self.mul_v(&other[0]),
+                           self.mul_v(&other[1]),
+                           self.mul_v(&other[2]))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The matrix product of the matrix and the 4 x 4 matrix `other`
+     */
+    #[inline(always)]
+    pure fn mul_m4(&self, other: &Mat4<T>) -> Mat4x3<T> {
+        Mat4x3::from_cols(self.mul_v(&other[0]),
+                          self.mul_v(&other[1]),
+                          self.mul_v(&other[2]),
+                          self.mul_v(&other[3]))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The transposed matrix, which has 3 columns and 4 rows
+     */
+    #[inline(always)]
+    pure fn transpose(&self) -> Mat3x4<T> {
+        Mat3x4::from_cols(self.row(0),
+                          self.row(1),
+                          self.row(2))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The 4 x 4 affine transformation matrix formed by adding a last row
+     * of `(0, 0, 0, 1)`
+     */
+    #[inline(always)]
+    pure fn to_mat4(&self) -> Mat4<T> {
+        Matrix4::new(self[0][0], self[0][1], self[0][2], zero(),
+                     self[1][0], self[1][1], self[1][2], zero(),
+                     self[2][0], self[2][1], self[2][2], zero(),
+                     self[3][0], self[3][1], self[3][2],  one())
+    }
+    
+    /**
+     * # Return value
+     *
+     * A pointer to the first element of the matrix
+     */
+    #[inline(always)]
+    pure fn to_ptr(&self) -> *T {
+        unsafe {
+            transmute::<*Mat4x3<T>, *T>(
+                to_unsafe_ptr(self)
+            )
+        }
+    }
+}
+
+pub impl<T:Copy> Mat4x3<T>: Index<uint, Vec3<T>> {
+    #[inline(always)]
+    pure fn index(&self, i: uint) -> Vec3<T> {
+        unsafe { do buf_as_slice(
+            transmute::<*Mat4x3<T>, *Vec3<T>>(
+                to_unsafe_ptr(self)), 4) |slice| { slice[i] }
+        }
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat4x3<T>: Neg<Mat4x3<T>> {
+    #[inline(always)]
+    pure fn neg(&self) -> Mat4x3<T> {
+        Mat4x3::from_cols(-self[0], -self[1], -self[2], -self[3])
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat4x3<T>: Add<Mat4x3<T>, Mat4x3<T>> {
+    #[inline(always)]
+    pure fn add(&self, rhs: &Mat4x3<T>) -> Mat4x3<T> {
+        self.add_m(rhs)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat4x3<T>: Sub<Mat4x3<T>, Mat4x3<T>> {
+    #[inline(always)]
+    pure fn sub(&self, rhs: &Mat4x3<T>) -> Mat4x3<T> {
+        self.sub_m(rhs)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>, R, RHS:MatrixMulRHS<Mat4x3<T>, R>> Mat4x3<T>: Mul<RHS, R> {
+    #[inline(always)]
+    pure fn mul(&self, rhs: &RHS) -> R {
+        rhs.rmul_m(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat4x3<T>: Div<T, Mat4x3<T>> {
+    #[inline(always)]
+    pure fn div(&self, rhs: &T) -> Mat4x3<T> {
+        self.mul_t(one::<T>() / *rhs)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat4x3<T>: MatrixScalarMul<T> {
+    #[inline(always)]
+    pure fn mul_scalar(&self, value: T) -> Mat4x3<T> {
+        self.mul_t(value)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Vec4<T>: MatrixMulRHS<Mat4x3<T>, Vec3<T>> {
+    #[inline(always)]
+    pure fn rmul_m(&self, lhs: &Mat4x3<T>) -> Vec3<T> {
+        lhs.mul_v(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat2x4<T>: MatrixMulRHS<Mat4x3<T>, Mat2x3<T>> {
+    #[inline(always)]
+    pure fn rmul_m(&self, lhs: &Mat4x3<T>) -> Mat2x3<T> {
+        lhs.mul_m2x4(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat3x4<T>: MatrixMulRHS<Mat4x3<T>, Mat3<T>> {
+    #[inline(always)]
+    pure fn rmul_m(&self, lhs: &Mat4x3<T>) -> Mat3<T> {
+        lhs.mul_m3x4(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat4<T>: MatrixMulRHS<Mat4x3<T>, Mat4x3<T>> {
+    #[inline(always)]
+    pure fn rmul_m(&self, lhs: &Mat4x3<T>) -> Mat4x3<T> {
+        lhs.mul_m4(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Mat4x3<T>: FuzzyEq<T> {
+    #[inline(always)]
+    pure fn fuzzy_eq(&self, other: &Mat4x3<T>) -> bool {
+        self.fuzzy_eq_eps(other, &Number::from(FUZZY_EPSILON))
+    }
+    
+    #[inline(always)]
+    pure fn fuzzy_eq_eps(&self, other: &Mat4x3<T>, epsilon: &T) -> bool {
+        self[0].fuzzy_eq_eps(&other[0], epsilon) &&
+        self[1].fuzzy_eq_eps(&other[1], epsilon) &&
+        self[2].fuzzy_eq_eps(&other[2], epsilon) &&
+        self[3].fuzzy_eq_eps(&other[3], epsilon)
+    }
+}
+
+// GLSL-style type aliases, corresponding to Section 4.1.6 of the [GLSL 4.30.6 specification]
+// (http://www.opengl.org/registry/doc/GLSLangSpec.4.30.6.pdf).
+
+pub type mat4x3 = Mat4x3<f32>;    // a 4×3 single-precision floating-point matrix
+pub type dmat4x3 = Mat4x3<f64>;   // a 4×3 double-precision floating-point matrix
+
+// Static method wrappers for GLSL-style types
+
+pub impl mat4x3 {
+    #[inline(always)] static pure fn new(c0r0: f32, c0r1: f32, c0r2: f32, c1r0: f32, c1r1: f32, c1r2: f32, c2r0: f32, c2r1: f32, c2r2: f32, c3r0: f32, c3r1: f32, c3r2: f32)
+        -> mat4x3 { Mat4x3::new(c0r0, c0r1, c0r2, c1r0, c1r1, c1r2, c2r0, c2r1, c2r2, c3r0, c3r1, c3r2) }
+    #[inline(always)] static pure fn from_cols(c0: vec3, c1: vec3, c2: vec3, c3: vec3)
+        -> mat4x3 { Mat4x3::from_cols(move c0, move c1, move c2, move c3) }
+    #[inline(always)] static pure fn from_value(v: f32) -> mat4x3 { Mat4x3::from_value(v) }
+    
+    #[inline(always)] static pure fn identity() -> mat4x3 { Mat4x3::identity() }
+    #[inline(always)] static pure fn zero() -> mat4x3 { Mat4x3::zero() }
+    
+    #[inline(always)] static pure fn from_mat4(m: &Mat4<f32>) -> mat4x3 { Mat4x3::from_mat4(m) }
+    
+    #[inline(always)] static pure fn rows() -> uint { 3 }
+    #[inline(always)] static pure fn cols() -> uint { 4 }
+    #[inline(always)] static pure fn size_of() -> uint { size_of::<mat4x3>() }
+}
+
+pub impl dmat4x3 {
+    #[inline(always)] static pure fn new(c0r0: f64, c0r1: f64, c0r2: f64, c1r0: f64, c1r1: f64, c1r2: f64, c2r0: f64, c2r1: f64, c2r2: f64, c3r0: f64, c3r1: f64, c3r2: f64)
+        -> dmat4x3 { Mat4x3::new(c0r0, c0r1, c0r2, c1r0, c1r1, c1r2, c2r0, c2r1, c2r2, c3r0, c3r1, c3r2) }
+    #[inline(always)] static pure fn from_cols(c0: dvec3, c1: dvec3, c2: dvec3, c3: dvec3)
+        -> dmat4x3 { Mat4x3::from_cols(move c0, move c1, move c2, move c3) }
+    #[inline(always)] static pure fn from_value(v: f64) -> dmat4x3 { Mat4x3::from_value(v) }
+    
+    #[inline(always)] static pure fn identity() -> dmat4x3 { Mat4x3::identity() }
+    #[inline(always)] static pure fn zero() -> dmat4x3 { Mat4x3::zero() }
+    
+    #[inline(always)] static pure fn from_mat4(m: &Mat4<f64>) -> dmat4x3 { Mat4x3::from_mat4(m) }
+    
+    #[inline(always)] static pure fn rows() -> uint { 3 }
+    #[inline(always)] static pure fn cols() -> uint { 4 }
+    #[inline(always)] static pure fn size_of() -> uint { size_of::<dmat4x3>() }
+}
@@ -0,0 +1,136 @@
+use core::cmp::Eq;
+
+use std::cmp::{FuzzyEq, FUZZY_EPSILON};
+use numeric::*;
+use numeric::number::Number;
+
+use vec::{
+    Vec3,
+    Vec4,
+    Vector3,
+    NumericVector,
+    EuclideanVector,
+    vec3,
+    dvec3,
+    vec4,
+    dvec4,
+};
+
+/**
+ * A plane formed from the set of all points `p` where `n.dot(p) + d == 0`
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the components. Should be a floating point type.
+ *
+ * # Fields
+ *
+ * * `n` - the normal of the plane
+ * * `d` - the signed distance of the plane from the origin, measured in the
+ *         opposite direction to the normal and in multiples of its length
+ */
+#[deriving_eq]
+pub struct Plane<T> { n: Vec3<T>, d: T }
+
+pub impl<T:Copy Float FuzzyEq<T>> Plane<T> {
+    /**
+     * Construct the plane from a normal and a distance
+     */
+    #[inline(always)]
+    static pure fn new(n: Vec3<T>, d: T) -> Plane<T> {
+        Plane { n: n, d: d }
+    }
+    
+    /**
+     * Construct the plane from the coefficients of the plane equation
+     * `a*x + b*y + c*z + d == 0`
+     */
+    #[inline(always)]
+    static pure fn from_abcd(a: T, b: T, c: T, d: T) -> Plane<T> {
+        Plane::new(Vector3::new(a, b, c), d)
+    }
+    
+    /**
+     * Construct the plane from a vector holding the coefficients of the plane
+     * equation in the form `(a, b, c, d)`
+     */
+    #[inline(always)]
+    static pure fn from_vec4(v: &Vec4<T>) -> Plane<T> {
+        Plane::from_abcd(v.x, v.y, v.z, v.w)
+    }
+    
+    /**
+     * Construct the plane that passes through `point` with the normal `n`
+     */
+    #[inline(always)]
+    static pure fn from_point_normal(point: &Vec3<T>, n: &Vec3<T>) -> Plane<T> {
+        Plane::new(*n, -n.dot(point))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The signed distance from the plane to `point`. This is positive on the
+     * side of the plane that the normal points towards, and is only a true
+     * distance if the plane is normalized.
+     */
+    #[inline(always)]
+    pure fn distance(&self, point: &Vec3<T>) -> T {
+        self.n.dot(point) + self.d
+    }
+    
+    /**
+     * # Return value
+     *
+     * The plane scaled so that its normal has a length of one
+     */
+    #[inline(always)]
+    pure fn normalize(&self) -> Plane<T> {
+        let len = self.n.length();
+        Plane::new(self.n.div_t(len), self.d / len)
+    }
+    
+    /**
+     * # Return value
+     *
+     * The plane facing in the opposite direction
+     */
+    #[inline(always)]
+    pure fn flip(&self) -> Plane<T> {
+        Plane::new(-self.n, -self.d)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Plane<T>: FuzzyEq<T> {
+    #[inline(always)]
+    pure fn fuzzy_eq(&self, other: &Plane<T>) -> bool {
+        self.fuzzy_eq_eps(other, &Number::from(FUZZY_EPSILON))
+    }
+    
+    #[inline(always)]
+    pure fn fuzzy_eq_eps(&self, other: &Plane<T>, epsilon: &T) -> bool {
+        self.n.fuzzy_eq_eps(&other.n, epsilon) &&
+        self.d.fuzzy_eq_eps(&other.d, epsilon)
+    }
+}
+
+// Type aliases following the nomenclature of the GLSL-style types
+
+pub type plane  = Plane<f32>;   /// a single-precision floating-point plane
+pub type dplane = Plane<f64>;   /// a double-precision floating-point plane
+
+// Static method wrappers for GLSL-style types
+
+pub impl plane {
+    #[inline(always)] static pure fn new(n: vec3, d: f32) -> plane { Plane::new(n, d) }
+    #[inline(always)] static pure fn from_abcd(a: f32, b: f32, c: f32, d: f32) -> plane { Plane::from_abcd(a, b, c, d) }
+    #[inline(always)] static pure fn from_vec4(v: &vec4) -> plane { Plane::from_vec4(v) }
+    #[inline(always)] static pure fn from_point_normal(point: &vec3, n: &vec3) -> plane { Plane::from_point_normal(point, n) }
+}
+
+pub impl dplane {
+    #[inline(always)] static pure fn new(n: dvec3, d: f64) -> dplane { Plane::new(n, d) }
+    #[inline(always)] static pure fn from_abcd(a: f64, b: f64, c: f64, d: f64) -> dplane { Plane::from_abcd(a, b, c, d) }
+    #[inline(always)] static pure fn from_vec4(v: &dvec4) -> dplane { Plane::from_vec4(v) }
+    #[inline(always)] static pure fn from_point_normal(point: &dvec3, n: &dvec3) -> dplane { Plane::from_point_normal(point, n) }
+}
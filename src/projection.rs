@@ -1,24 +1,135 @@
 use numeric::*;
 use numeric::number::Number;
+use numeric::number::Number::{zero,one};
 
-use std::cmp::FuzzyEq;
+use std::cmp::{FuzzyEq, FUZZY_EPSILON};
 
-use mat::{Mat4, Matrix4};
+use angle::Angle;
+use mat::{Mat4, Matrix, Matrix4};
+use vec::{Vec3, Vec4, Vector3, Vector4, NumericVector, EuclideanVector};
+
+// The functions in this module produce matrices that follow the OpenGL
+// convention of mapping the near and far planes to a clip space depth range of
+// [-1, 1]. The functions with a `_zo` suffix instead map depth to the [0, 1]
+// range expected by Direct3D, Vulkan and Metal.
 
 /**
  * Create a perspective projection matrix
  *
  * This is the equivalent of the gluPerspective function, the algorithm of which
  * can be found [here](http://www.opengl.org/wiki/GluPerspective_code).
+ *
+ * `fovy` is the full angle between the top and bottom planes of the frustum,
+ * as in `gluPerspective`. Earlier versions of this function took the tangent
+ * of `fovy` itself rather than of half of it, producing a field of view of
+ * twice `fovy`, so callers that compensated for this by passing half of
+ * the intended angle need to pass the full angle instead. The other
+ * perspective functions in this module follow the same convention.
+ *
+ * # Arguments
+ *
+ * * `fovy` - the full vertical field of view, as a `Rad` or `Deg` angle
+ * * `aspectRatio` - the ratio of the width to the height of the viewport
+ * * `near`, `far` - the distances to the near and far clipping planes
  */
 #[inline(always)]
-pub pure fn perspective<T:Copy Float FuzzyEq<T>>(fovy: T, aspectRatio: T, near: T, far: T) -> Mat4<T> {
-    let ymax = near * tan(radians(fovy));
+pub pure fn perspective<T:Copy Float FuzzyEq<T>, A:Angle<T>>(fovy: A, aspectRatio: T, near: T, far: T) -> Mat4<T> {
+    let ymax = near * fovy.div_t(Number::from(2)).tan();
     let xmax = ymax * aspectRatio;
     
     frustum(-xmax, xmax, -ymax, ymax, near, far)
 }
 
+/**
+ * Create a perspective projection matrix that maps depth to the range [0, 1]
+ */
+#[inline(always)]
+pub pure fn perspective_zo<T:Copy Float FuzzyEq<T>, A:Angle<T>>(fovy: A, aspectRatio: T, near: T, far: T) -> Mat4<T> {
+    let ymax = near * fovy.div_t(Number::from(2)).tan();
+    let xmax = ymax * aspectRatio;
+    
+    frustum_zo(-xmax, xmax, -ymax, ymax, near, far)
+}
+
+/**
+ * Create a perspective projection matrix with the far plane at infinity
+ */
+#[inline(always)]
+pub pure fn infinite_perspective<T:Copy Float FuzzyEq<T>, A:Angle<T>>(fovy: A, aspectRatio: T, near: T) -> Mat4<T> {
+    let _0: T = Number::from(0);
+    let _1: T = Number::from(1);
+    let _2: T = Number::from(2);
+    
+    let f = _1 / fovy.div_t(_2).tan();
+    
+    Matrix4::new(f / aspectRatio, _0,             _0,  _0,
+                 _0,              f,              _0,  _0,
+                 _0,              _0,            -_1, -_1,
+                 _0,              _0, -(_2 * near),   _0)
+}
+
+/**
+ * Create a perspective projection matrix with the far plane at infinity
+ * that maps depth to the range [0, 1]
+ */
+#[inline(always)]
+pub pure fn infinite_perspective_zo<T:Copy Float FuzzyEq<T>, A:Angle<T>>(fovy: A, aspectRatio: T, near: T) -> Mat4<T> {
+    let _0: T = Number::from(0);
+    let _1: T = Number::from(1);
+    let _2: T = Number::from(2);
+    
+    let f = _1 / fovy.div_t(_2).tan();
+    
+    Matrix4::new(f / aspectRatio, _0,     _0,  _0,
+                 _0,              f,      _0,  _0,
+                 _0,              _0,    -_1, -_1,
+                 _0,              _0,  -near,  _0)
+}
+
+/**
+ * Create a reversed-z perspective projection matrix, mapping the near plane
+ * to a depth of 1 and the far plane to a depth of 0
+ *
+ * Combined with a floating point depth buffer, this distributes the
+ * precision of the depth buffer far more evenly than the standard mapping.
+ * Remember to clear the depth buffer to 0 and to flip the depth test.
+ */
+#[inline(always)]
+pub pure fn reverse_perspective<T:Copy Float FuzzyEq<T>, A:Angle<T>>(fovy: A, aspectRatio: T, near: T, far: T) -> Mat4<T> {
+    let _0: T = Number::from(0);
+    let _1: T = Number::from(1);
+    let _2: T = Number::from(2);
+    
+    let f = _1 / fovy.div_t(_2).tan();
+    
+    let c2r2 = near / (far - near);
+    let c3r2 = (far * near) / (far - near);
+    
+    Matrix4::new(f / aspectRatio, _0,   _0,  _0,
+                 _0,              f,    _0,  _0,
+                 _0,              _0, c2r2, -_1,
+                 _0,              _0, c3r2,  _0)
+}
+
+/**
+ * Create a reversed-z perspective projection matrix with the far plane at
+ * infinity, mapping the near plane to a depth of 1 and infinity to a depth
+ * of 0
+ */
+#[inline(always)]
+pub pure fn reverse_infinite_perspective<T:Copy Float FuzzyEq<T>, A:Angle<T>>(fovy: A, aspectRatio: T, near: T) -> Mat4<T> {
+    let _0: T = Number::from(0);
+    let _1: T = Number::from(1);
+    let _2: T = Number::from(2);
+    
+    let f = _1 / fovy.div_t(_2).tan();
+    
+    Matrix4::new(f / aspectRatio, _0,   _0,  _0,
+                 _0,              f,    _0,  _0,
+                 _0,              _0,   _0, -_1,
+                 _0,              _0, near,  _0)
+}
+
 /**
  * Define a view frustrum
  *
@@ -55,4 +166,317 @@ pub pure fn frustum<T:Copy Float FuzzyEq<T>>(left: T, right: T, bottom: T, top:
                  c1r0, c1r1, c1r2, c1r3,
                  c2r0, c2r1, c2r2, c2r3,
                  c3r0, c3r1, c3r2, c3r3)
-}
\ No newline at end of file
+}
+
+/**
+ * Define a view frustrum that maps depth to the range [0, 1]
+ */
+#[inline(always)]
+pub pure fn frustum_zo<T:Copy Float FuzzyEq<T>>(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Mat4<T> {
+    let _0: T = Number::from(0);
+    let _1: T = Number::from(1);
+    let _2: T = Number::from(2);
+    
+    let c0r0 = (_2 * near) / (right - left);
+    let c0r1 = _0;
+    let c0r2 = _0;
+    let c0r3 = _0;
+    
+    let c1r0 = _0;
+    let c1r1 = (_2 * near) / (top - bottom);
+    let c1r2 = _0;
+    let c1r3 = _0;
+    
+    let c2r0 = (right + left) / (right - left);
+    let c2r1 = (top + bottom) / (top - bottom);
+    let c2r2 = -far / (far - near);
+    let c2r3 = -_1;
+    
+    let c3r0 = _0;
+    let c3r1 = _0;
+    let c3r2 = -(far * near) / (far - near);
+    let c3r3 = _0;
+    
+    Matrix4::new(c0r0, c0r1, c0r2, c0r3,
+                 c1r0, c1r1, c1r2, c1r3,
+                 c2r0, c2r1, c2r2, c2r3,
+                 c3r0, c3r1, c3r2, c3r3)
+}
+
+/**
+ * Create an orthographic projection matrix
+ *
+ * This is the equivalent of the now deprecated [glOrtho]
+ * (http://www.opengl.org/sdk/docs/man2/xhtml/glOrtho.xml) function.
+ */
+#[inline(always)]
+pub pure fn ortho<T:Copy Float FuzzyEq<T>>(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Mat4<T> {
+    let _0: T = Number::from(0);
+    let _1: T = Number::from(1);
+    let _2: T = Number::from(2);
+    
+    let c0r0 = _2 / (right - left);
+    let c0r1 = _0;
+    let c0r2 = _0;
+    let c0r3 = _0;
+    
+    let c1r0 = _0;
+    let c1r1 = _2 / (top - bottom);
+    let c1r2 = _0;
+    let c1r3 = _0;
+    
+    let c2r0 = _0;
+    let c2r1 = _0;
+    let c2r2 = -_2 / (far - near);
+    let c2r3 = _0;
+    
+    let c3r0 = -(right + left) / (right - left);
+    let c3r1 = -(top + bottom) / (top - bottom);
+    let c3r2 = -(far + near) / (far - near);
+    let c3r3 = _1;
+    
+    Matrix4::new(c0r0, c0r1, c0r2, c0r3,
+                 c1r0, c1r1, c1r2, c1r3,
+                 c2r0, c2r1, c2r2, c2r3,
+                 c3r0, c3r1, c3r2, c3r3)
+}
+
+/**
+ * Create an orthographic projection matrix that maps depth to the range [0, 1]
+ */
+#[inline(always)]
+pub pure fn ortho_zo<T:Copy Float FuzzyEq<T>>(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Mat4<T> {
+    let _0: T = Number::from(0);
+    let _1: T = Number::from(1);
+    let _2: T = Number::from(2);
+    
+    let c0r0 = _2 / (right - left);
+    let c0r1 = _0;
+    let c0r2 = _0;
+    let c0r3 = _0;
+    
+    let c1r0 = _0;
+    let c1r1 = _2 / (top - bottom);
+    let c1r2 = _0;
+    let c1r3 = _0;
+    
+    let c2r0 = _0;
+    let c2r1 = _0;
+    let c2r2 = -_1 / (far - near);
+    let c2r3 = _0;
+    
+    let c3r0 = -(right + left) / (right - left);
+    let c3r1 = -(top + bottom) / (top - bottom);
+    let c3r2 = -near / (far - near);
+    let c3r3 = _1;
+    
+    Matrix4::new(c0r0, c0r1, c0r2, c0r3,
+                 c1r0, c1r1, c1r2, c1r3,
+                 c2r0, c2r1, c2r2, c2r3,
+                 c3r0, c3r1, c3r2, c3r3)
+}
+
+/**
+ * Create a 2D orthographic projection matrix
+ *
+ * This is the equivalent of the [gluOrtho2D]
+ * (http://www.opengl.org/sdk/docs/man2/xhtml/gluOrtho2D.xml) function, and
+ * is the same as calling `ortho` with a `near` of -1 and a `far` of 1.
+ */
+#[inline(always)]
+pub pure fn ortho2d<T:Copy Float FuzzyEq<T>>(left: T, right: T, bottom: T, top: T) -> Mat4<T> {
+    let _1: T = Number::from(1);
+    
+    ortho(left, right, bottom, top, -_1, _1)
+}
+
+/**
+ * Map an object space point to window coordinates
+ *
+ * This is the equivalent of the [gluProject]
+ * (http://www.opengl.org/sdk/docs/man2/xhtml/gluProject.xml) function, and
+ * expects a projection matrix that maps depth to the range [-1, 1]. Use
+ * `project_zo` with the `_zo` and reversed-z projection matrices.
+ *
+ * # Arguments
+ *
+ * * `obj` - the point to project, in object coordinates
+ * * `modelview` - the model-view matrix
+ * * `proj` - the projection matrix
+ * * `viewport` - the viewport, in the form `(x, y, width, height)`
+ *
+ * # Return value
+ *
+ * The window coordinates of the point, with a depth in the range [0, 1], or
+ * `None` if the point lies on the plane of the eye and can't be projected
+ */
+#[inline(always)]
+pub pure fn project<T:Copy Float FuzzyEq<T>>(obj: &Vec3<T>, modelview: &Mat4<T>, proj: &Mat4<T>, viewport: &Vec4<T>) -> Option<Vec3<T>> {
+    project_depth(obj, modelview, proj, viewport, -one::<T>())
+}
+
+/**
+ * Map an object space point to window coordinates, using a projection matrix
+ * that maps depth to the range [0, 1]
+ */
+#[inline(always)]
+pub pure fn project_zo<T:Copy Float FuzzyEq<T>>(obj: &Vec3<T>, modelview: &Mat4<T>, proj: &Mat4<T>, viewport: &Vec4<T>) -> Option<Vec3<T>> {
+    project_depth(obj, modelview, proj, viewport, zero())
+}
+
+/**
+ * Map window coordinates back to object coordinates
+ *
+ * This is the equivalent of the [gluUnProject]
+ * (http://www.opengl.org/sdk/docs/man2/xhtml/gluUnProject.xml) function, and
+ * expects a projection matrix that maps depth to the range [-1, 1]. Use
+ * `unproject_zo` with the `_zo` and reversed-z projection matrices.
+ *
+ * # Arguments
+ *
+ * * `win` - the window coordinates, with a depth in the range [0, 1]
+ * * `modelview` - the model-view matrix
+ * * `proj` - the projection matrix
+ * * `viewport` - the viewport, in the form `(x, y, width, height)`
+ *
+ * # Return value
+ *
+ * The point in object coordinates, or `None` if the combined model-view and
+ * projection matrix is singular, or if the point is at infinity
+ */
+#[inline(always)]
+pub pure fn unproject<T:Copy Float FuzzyEq<T>>(win: &Vec3<T>, modelview: &Mat4<T>, proj: &Mat4<T>, viewport: &Vec4<T>) -> Option<Vec3<T>> {
+    match unproject_depth(win, modelview, proj, viewport, -one::<T>()) {
+        Some(obj) => dehomogenize(&obj),
+        None => None
+    }
+}
+
+/**
+ * Map window coordinates back to object coordinates, using a projection
+ * matrix that maps depth to the range [0, 1]
+ */
+#[inline(always)]
+pub pure fn unproject_zo<T:Copy Float FuzzyEq<T>>(win: &Vec3<T>, modelview: &Mat4<T>, proj: &Mat4<T>, viewport: &Vec4<T>) -> Option<Vec3<T>> {
+    match unproject_depth(win, modelview, proj, viewport, zero()) {
+        Some(obj) => dehomogenize(&obj),
+        None => None
+    }
+}
+
+/**
+ * Construct a picking ray through a position in the viewport
+ *
+ * This expects a projection matrix that maps depth to the range [-1, 1]. Use
+ * `pick_ray_zo` with the `_zo` and reversed-z projection matrices.
+ *
+ * # Arguments
+ *
+ * * `x`, `y` - the window position, with the origin in the bottom left corner
+ *   of the window. Mouse positions measured from the top of the window should
+ *   be flipped first.
+ * * `modelview` - the model-view matrix
+ * * `proj` - the projection matrix
+ * * `viewport` - the viewport, in the form `(x, y, width, height)`
+ *
+ * # Return value
+ *
+ * A tuple containing the origin of the ray on the near plane and its
+ * normalized direction, both in object coordinates, or `None` if the
+ * combined model-view and projection matrix is singular
+ */
+#[inline(always)]
+pub pure fn pick_ray<T:Copy Float FuzzyEq<T>>(x: T, y: T, modelview: &Mat4<T>, proj: &Mat4<T>, viewport: &Vec4<T>) -> Option<(Vec3<T>, Vec3<T>)> {
+    pick_ray_depth(x, y, modelview, proj, viewport, -one::<T>())
+}
+
+/**
+ * Construct a picking ray through a position in the viewport, using a
+ * projection matrix that maps depth to the range [0, 1]
+ */
+#[inline(always)]
+pub pure fn pick_ray_zo<T:Copy Float FuzzyEq<T>>(x: T, y: T, modelview: &Mat4<T>, proj: &Mat4<T>, viewport: &Vec4<T>) -> Option<(Vec3<T>, Vec3<T>)> {
+    pick_ray_depth(x, y, modelview, proj, viewport, zero())
+}
+
+// The helpers below are shared by the depth conventions. `min_depth` is the
+// clip space depth that a window depth of 0 corresponds to, either -1 or 0.
+
+#[inline(always)]
+priv pure fn project_depth<T:Copy Float FuzzyEq<T>>(obj: &Vec3<T>, modelview: &Mat4<T>, proj: &Mat4<T>, viewport: &Vec4<T>, min_depth: T) -> Option<Vec3<T>> {
+    let _1: T = Number::from(1);
+    let _2: T = Number::from(2);
+    
+    let clip = proj.mul_v(&modelview.mul_v(&Vector4::new(obj.x, obj.y, obj.z, _1)));
+    
+    if clip.w == zero() {
+        None
+    } else {
+        let ndc = clip.div_t(clip.w);
+        
+        Some(Vector3::new(viewport.x + viewport.z * (ndc.x + _1) / _2,
+                          viewport.y + viewport.w * (ndc.y + _1) / _2,
+                          (ndc.z - min_depth) / (_1 - min_depth)))
+    }
+}
+
+/**
+ * Map window coordinates back to homogeneous object coordinates, without the
+ * division by `w`, which is zero for a point at infinity
+ */
+#[inline(always)]
+priv pure fn unproject_depth<T:Copy Float FuzzyEq<T>>(win: &Vec3<T>, modelview: &Mat4<T>, proj: &Mat4<T>, viewport: &Vec4<T>, min_depth: T) -> Option<Vec4<T>> {
+    let _1: T = Number::from(1);
+    let _2: T = Number::from(2);
+    
+    match proj.mul_m(modelview).inverse() {
+        Some(inv) => {
+            let ndc: Vec4<T> = Vector4::new(_2 * (win.x - viewport.x) / viewport.z - _1,
+                                            _2 * (win.y - viewport.y) / viewport.w - _1,
+                                            min_depth + win.z * (_1 - min_depth),
+                                            _1);
+            Some(inv.mul_v(&ndc))
+        }
+        None => None
+    }
+}
+
+#[inline(always)]
+priv pure fn dehomogenize<T:Copy Float FuzzyEq<T>>(v: &Vec4<T>) -> Option<Vec3<T>> {
+    if v.w == zero() {
+        None
+    } else {
+        Some(Vector3::new(v.x / v.w, v.y / v.w, v.z / v.w))
+    }
+}
+
+#[inline(always)]
+priv pure fn pick_ray_depth<T:Copy Float FuzzyEq<T>>(x: T, y: T, modelview: &Mat4<T>, proj: &Mat4<T>, viewport: &Vec4<T>, min_depth: T) -> Option<(Vec3<T>, Vec3<T>)> {
+    let a = unproject_depth(&Vector3::new(x, y, zero()), modelview, proj, viewport, min_depth);
+    let b = unproject_depth(&Vector3::new(x, y, one()), modelview, proj, viewport, min_depth);
+    
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            // The homogeneous `w` of an unprojected point is inversely
+            // proportional to its distance from the eye, so the point with
+            // the larger `w` is on the near plane. This is the point at a
+            // depth of 1 for a reversed-z projection. For an orthographic
+            // projection both are the same to within rounding, and the point
+            // at a depth of 0 is kept.
+            let tolerance: T = one::<T>() + Number::from(FUZZY_EPSILON);
+            let (near, far) = if abs(b.w) > abs(a.w) * tolerance { (b, a) } else { (a, b) };
+            
+            // `far / far.w - near / near.w`, scaled by `near.w * far.w` so
+            // that it stays finite when the far point is at infinity
+            let dir = Vector3::new(far.x * near.w - near.x * far.w,
+                                   far.y * near.w - near.y * far.w,
+                                   far.z * near.w - near.z * far.w);
+            
+            match dehomogenize(&near) {
+                Some(origin) => Some((origin, dir.normalize())),
+                None => None
+            }
+        }
+        _ => None
+    }
+}
@@ -18,9 +18,14 @@ use numeric::*;
 use numeric::number::Number;
 use numeric::number::Number::{zero,one};
 
+use angle::{Angle, Rad};
+
 use mat::{
     Mat3,
-    Matrix3
+    Mat4,
+    Matrix,
+    Matrix3,
+    Matrix4,
 };
 
 use vec::{
@@ -99,40 +104,40 @@ pub impl<T:Copy Float FuzzyEq<T>> Quat<T> {
     }
     
     #[inline(always)]
-    static pure fn from_angle_x(radians: T) -> Quat<T> {
-        let _2 = Number::from(2);
-        Quat::new(cos(radians / _2), sin(radians), zero(), zero())
+    static pure fn from_angle_x<A:Angle<T>>(theta: A) -> Quat<T> {
+        let half = theta.div_t(Number::from(2));
+        Quat::new(half.cos(), half.sin(), zero(), zero())
     }
     
     #[inline(always)]
-    static pure fn from_angle_y(radians: T) -> Quat<T> {
-        let _2 = Number::from(2);
-        Quat::new(cos(radians / _2), zero(), sin(radians), zero())
+    static pure fn from_angle_y<A:Angle<T>>(theta: A) -> Quat<T> {
+        let half = theta.div_t(Number::from(2));
+        Quat::new(half.cos(), zero(), half.sin(), zero())
     }
     
     #[inline(always)]
-    static pure fn from_angle_z(radians: T) -> Quat<T> {
-        let _2 = Number::from(2);
-        Quat::new(cos(radians / _2), zero(), zero(), sin(radians))
+    static pure fn from_angle_z<A:Angle<T>>(theta: A) -> Quat<T> {
+        let half = theta.div_t(Number::from(2));
+        Quat::new(half.cos(), zero(), zero(), half.sin())
     }
     
     #[inline(always)]
-    static pure fn from_angle_xyz(radians_x: T, radians_y: T, radians_z: T) -> Quat<T> {
+    static pure fn from_angle_xyz<A:Angle<T>>(theta_x: A, theta_y: A, theta_z: A) -> Quat<T> {
         // http://en.wikipedia.org/wiki/Conversion_between_quaternions_and_Euler_angles#Conversion
         let _2 = Number::from(2);
-        let xdiv2 = radians_x / _2;
-        let ydiv2 = radians_y / _2;
-        let zdiv2 = radians_z / _2;
-        Quat::new(cos(zdiv2) * cos(xdiv2) * cos(ydiv2) + sin(zdiv2) * sin(xdiv2) * sin(ydiv2),
-                  sin(zdiv2) * cos(xdiv2) * cos(ydiv2) - cos(zdiv2) * sin(xdiv2) * sin(ydiv2),
-                  cos(zdiv2) * sin(xdiv2) * cos(ydiv2) + sin(zdiv2) * cos(xdiv2) * sin(ydiv2),
-                  cos(zdiv2) * cos(xdiv2) * sin(ydiv2) - sin(zdiv2) * sin(xdiv2) * cos(ydiv2))
+        let xdiv2 = theta_x.div_t(_2);
+        let ydiv2 = theta_y.div_t(_2);
+        let zdiv2 = theta_z.div_t(_2);
+        Quat::new(xdiv2.cos() * ydiv2.cos() * zdiv2.cos() + xdiv2.sin() * ydiv2.sin() * zdiv2.sin(),
+                  xdiv2.sin() * ydiv2.cos() * zdiv2.cos() - xdiv2.cos() * ydiv2.sin() * zdiv2.sin(),
+                  xdiv2.cos() * ydiv2.sin() * zdiv2.cos() + xdiv2.sin() * ydiv2.cos() * zdiv2.sin(),
+                  xdiv2.cos() * ydiv2.cos() * zdiv2.sin() - xdiv2.sin() * ydiv2.sin() * zdiv2.cos())
     }
     
     #[inline(always)]
-    static pure fn from_angle_axis(radians: T, axis: &Vec3<T>) -> Quat<T> {
-        let half = radians / Number::from(2);
-        Quat::from_sv(cos(half), axis.mul_t(sin(half)))
+    static pure fn from_angle_axis<A:Angle<T>>(theta: A, axis: &Vec3<T>) -> Quat<T> {
+        let half = theta.div_t(Number::from(2));
+        Quat::from_sv(half.cos(), axis.mul_t(half.sin()))
     }
     
     #[inline(always)]
@@ -140,8 +145,31 @@ pub impl<T:Copy Float FuzzyEq<T>> Quat<T> {
         let m: Mat3<T> = Matrix3::from_axes(x, y, z); m.to_quat()
     }
     
-    pure fn get_angle_axis(&self) -> (T, Vec3<T>) {
-        fail!(~"Not yet implemented.")
+    /**
+     * Extract the angle and axis of the rotation represented by the quaternion
+     *
+     * # Return value
+     *
+     * A tuple containing the angle of the rotation, in the range `[0, 2pi]`,
+     * and the normalized axis of rotation. If the angle is zero (or close to
+     * zero) then the axis is undefined, and the `x` axis is returned. The
+     * quaternion does not need to be normalized.
+     */
+    pure fn get_angle_axis(&self) -> (Rad<T>, Vec3<T>) {
+        // `atan2` remains accurate for angles close to both 0 and pi, where
+        // `acos(self.s)` would lose precision or leave its domain due to
+        // rounding errors.
+        //
+        // The zero check is relative to the magnitude, so that a small but
+        // unnormalized quaternion still has a well defined axis.
+        let sin_half = self.v.length();
+        
+        if sin_half <= self.magnitude() * Number::from(FUZZY_EPSILON) {
+            (Rad(zero()), NumericVector3::unit_x())
+        } else {
+            let _2: T = Number::from(2);
+            (Rad(_2 * atan2(sin_half, self.s)), self.v.div_t(sin_half))
+        }
     }
     
     #[inline(always)]
@@ -149,6 +177,50 @@ pub impl<T:Copy Float FuzzyEq<T>> Quat<T> {
         let m: Mat3<T> = Matrix3::look_at(dir, up); m.to_quat()
     }
     
+    /**
+     * Construct the shortest rotation that takes the direction of `a` to the
+     * direction of `b`
+     *
+     * The vectors do not need to be normalized. If they point in the same
+     * direction the identity is returned, and if they point in opposite
+     * directions the result is a half turn around an arbitrary axis that is
+     * perpendicular to `a`.
+     */
+    static pure fn from_two_vectors(a: &Vec3<T>, b: &Vec3<T>) -> Quat<T> {
+        let a_ = a.normalize();
+        let b_ = b.normalize();
+        let cos_theta = a_.dot(&b_);
+        let _1: T = one();
+        let _2: T = Number::from(2);
+        let _16: T = Number::from(16);
+        
+        // For vectors in opposite directions, rounding leaves `1 + cos_theta`
+        // within a few units in the last place of `1` of zero, possibly
+        // negative, and the direction of `a x b` is noise. Adding it to `16`
+        // rounds it away exactly when it is below 8 of those units.
+        if _16 + (_1 + cos_theta) <= _16 {
+            // the cross product vanishes, so the axis is found by crossing
+            // `a` with the coordinate axis that it is least aligned with
+            let x = abs(a_.x);
+            let y = abs(a_.y);
+            let z = abs(a_.z);
+            
+            let unit: Vec3<T> = if x <= y && x <= z { NumericVector3::unit_x() }
+                                else if y <= z     { NumericVector3::unit_y() }
+                                else               { NumericVector3::unit_z() };
+            
+            Quat::from_sv(zero(), a_.cross(&unit).normalize())
+        } else {
+            // `a x b` has a length of `sin(theta)`, and
+            // `sqrt(2 * (1 + cos(theta)))` is `2 * cos(theta / 2)`, so this
+            // avoids calculating the angle itself. Close to a half turn
+            // `1 + cos_theta` loses most of its precision, which scales the
+            // quaternion without changing its direction much, so normalize.
+            let s = sqrt(_2 * (_1 + cos_theta));
+            Quat::from_sv(s / _2, a_.cross(&b_).div_t(s)).normalize()
+        }
+    }
+    
     /**
      * # Return value
      *
@@ -293,6 +365,18 @@ pub impl<T:Copy Float FuzzyEq<T>> Quat<T> {
         self.mul_t(one::<T>()/self.magnitude())
     }
     
+    /**
+     * # Return value
+     *
+     * Either `other` or `-other`, whichever is closest to the quaternion.
+     * Both represent the same rotation, so interpolating towards the result
+     * rotates the shortest way around instead of the long way.
+     */
+    #[inline(always)]
+    pure fn nearest(&self, other: &Quat<T>) -> Quat<T> {
+        if self.dot(other) < zero() { -*other } else { *other }
+    }
+    
     /**
      * Normalised linear interpolation
      *
@@ -347,6 +431,200 @@ pub impl<T:Copy Float FuzzyEq<T>> Quat<T> {
         }
     }
     
+    /**
+     * # Return value
+     *
+     * The exponential of the quaternion. The exponential of a quaternion with
+     * a scalar part of zero and a vector part of `axis * theta/2` is the
+     * rotation of `theta` radians around `axis`.
+     */
+    pure fn exp(&self) -> Quat<T> {
+        let theta = self.v.length();
+        let e = exp(self.s);
+        
+        if theta.fuzzy_eq(&zero()) {
+            // `sin(theta) / theta` approaches `1`
+            Quat::from_sv(e, self.v.mul_t(e))
+        } else {
+            Quat::from_sv(e * cos(theta), self.v.mul_t(e * sin(theta) / theta))
+        }
+    }
+    
+    /**
+     * # Return value
+     *
+     * The natural logarithm of the quaternion, such that `q.ln().exp() == q`.
+     * The vector part of the logarithm of a normalized quaternion is its
+     * axis of rotation multiplied by half of its angle of rotation. If the
+     * quaternion is a negative real number the axis is undefined, and the `x`
+     * axis is used.
+     */
+    pure fn ln(&self) -> Quat<T> {
+        let len = self.v.length();
+        let ln_m = ln(self.magnitude());
+        
+        if self.s > zero() && (len / self.s).fuzzy_eq(&zero()) {
+            // `atan2(len, s) / len` approaches `1 / s`
+            Quat::from_sv(ln_m, self.v.div_t(self.s))
+        } else if len == zero() {
+            let unit_x: Vec3<T> = NumericVector3::unit_x();
+            Quat::from_sv(ln_m, unit_x.mul_t(Float::pi()))
+        } else {
+            Quat::from_sv(ln_m, self.v.mul_t(atan2(len, self.s) / len))
+        }
+    }
+    
+    /**
+     * # Return value
+     *
+     * The quaternion raised to the power of `n`. For a normalized quaternion
+     * this scales the angle of rotation by `n`, so `q.pow(t)` interpolates
+     * between the identity and `q` as `t` goes from `0` to `1`.
+     */
+    #[inline(always)]
+    pure fn pow(&self, n: T) -> Quat<T> {
+        self.ln().mul_t(n).exp()
+    }
+    
+    /**
+     * Spherical cubic interpolation
+     *
+     * Interpolate between the quaternion and `other` along a smooth curve
+     * that is shaped by the control points `a` and `b`. When each segment of
+     * a keyframe sequence is interpolated using the control points calculated
+     * by `squad_control`, the angular velocity is continuous across the
+     * keyframes. All of the quaternions should be normalized.
+     *
+     * # Arguments
+     *
+     * * `a` - the control point of the quaternion
+     * * `b` - the control point of `other`
+     * * `other` - the quaternion to interpolate towards
+     * * `amount` - the position along the curve, from `0` to `1`
+     *
+     * # Return value
+     *
+     * The interpolated quaternion
+     *
+     * - [Ken Shoemake, Animating Rotation with Quaternion Curves]
+     *   (http://www.cs.cmu.edu/~kiranb/animation/p245-shoemake.pdf)
+     */
+    #[inline(always)]
+    pure fn squad(&self, a: &Quat<T>, b: &Quat<T>, other: &Quat<T>, amount: T) -> Quat<T> {
+        let _1: T = one();
+        let _2: T = Number::from(2);
+        self.slerp(other, amount).slerp(&a.slerp(b, amount), _2 * amount * (_1 - amount))
+    }
+    
+    /**
+     * Calculate the control point of a keyframe for use with `squad`
+     *
+     * # Arguments
+     *
+     * * `prev` - the previous keyframe in the sequence
+     * * `next` - the next keyframe in the sequence
+     *
+     * # Return value
+     *
+     * The control point of the quaternion, chosen so that the curves coming
+     * from `prev` and going to `next` have the same tangent at the quaternion
+     */
+    #[inline(always)]
+    pure fn squad_control(&self, prev: &Quat<T>, next: &Quat<T>) -> Quat<T> {
+        let inv = self.inverse();
+        let t = inv.mul_q(next).ln().add_q(&inv.mul_q(prev).ln());
+        self.mul_q(&t.div_t(Number::from(-4)).exp())
+    }
+    
+    /**
+     * Decompose the rotation into a twist around `axis`, followed by a swing
+     * around an axis perpendicular to `axis`
+     *
+     * # Arguments
+     *
+     * * `axis` - the axis of the twist. This does not need to be normalized.
+     *
+     * # Return value
+     *
+     * A tuple containing the swing and the twist, such that
+     * `swing.mul_q(&twist)` is the quaternion. If the axis of rotation is
+     * perpendicular to `axis` then the rotation is entirely a swing, and the
+     * twist is the identity. The quaternion should be normalized.
+     */
+    pure fn swing_twist(&self, axis: &Vec3<T>) -> (Quat<T>, Quat<T>) {
+        let axis_ = axis.normalize();
+        
+        // the twist is the projection of the rotation onto the axis
+        let twist = Quat::from_sv(self.s, axis_.mul_t(self.v.dot(&axis_)));
+        
+        if twist.magnitude2().fuzzy_eq(&zero()) {
+            (*self, Quat::identity())
+        } else {
+            let twist = twist.normalize();
+            (self.mul_q(&twist.conjugate()), twist)
+        }
+    }
+    
+    /**
+     * Limit the twist of the rotation around `axis` to the range
+     * `[min, max]`, leaving the swing unchanged
+     *
+     * # Arguments
+     *
+     * * `axis` - the axis of the twist. This does not need to be normalized.
+     * * `min` - the minimum angle of the twist, no less than `-half_turn`
+     * * `max` - the maximum angle of the twist, no more than `half_turn`
+     *
+     * # Return value
+     *
+     * The clamped rotation. The quaternion should be normalized.
+     */
+    pure fn clamp_twist<A:Angle<T>>(&self, axis: &Vec3<T>, min: A, max: A) -> Quat<T> {
+        let axis_ = axis.normalize();
+        let (swing, twist) = self.swing_twist(&axis_);
+        
+        // take the twist with a non-negative scalar part, so that the angle
+        // is in the range `[-pi, pi]`
+        let twist = if twist.s < zero() { -twist } else { twist };
+        let _2: T = Number::from(2);
+        let theta = _2 * atan2(twist.v.dot(&axis_), twist.s);
+        let clamped = theta.clamp(*min.to_rad(), *max.to_rad());
+        
+        if clamped == theta {
+            *self
+        } else {
+            swing.mul_q(&Quat::from_angle_axis(Rad(clamped), &axis_))
+        }
+    }
+    
+    /**
+     * Limit the swing of the rotation away from `axis` to a cone with a half
+     * angle of `max`, leaving the twist unchanged
+     *
+     * # Arguments
+     *
+     * * `axis` - the axis of the twist. This does not need to be normalized.
+     * * `max` - the largest angle that the swing can rotate `axis` by
+     *
+     * # Return value
+     *
+     * The clamped rotation. The quaternion should be normalized.
+     */
+    pure fn clamp_swing<A:Angle<T>>(&self, axis: &Vec3<T>, max: A) -> Quat<T> {
+        let (swing, twist) = self.swing_twist(axis);
+        
+        let swing = if swing.s < zero() { -swing } else { swing };
+        let sin_half = swing.v.length();
+        let _2: T = Number::from(2);
+        let theta = _2 * atan2(sin_half, swing.s);
+        
+        if theta <= *max.to_rad() {
+            *self
+        } else {
+            Quat::from_angle_axis(max, &swing.v.div_t(sin_half)).mul_q(&twist)
+        }
+    }
+    
     /**
      * # Return value
      *
@@ -388,6 +666,36 @@ pub impl<T:Copy Float FuzzyEq<T>> Quat<T> {
                           xy2 - sz2, _1 - xx2 - zz2,      yz2 + sx2,
                           xz2 + sy2,      yz2 - sx2, _1 - xx2 - yy2)
     }
+    
+    /**
+     * Convert the quaternion to a 4 x 4 rotation matrix
+     */
+    #[inline(always)]
+    pure fn to_mat4(&self) -> Mat4<T> {
+        let x2 = self.v.x + self.v.x;
+        let y2 = self.v.y + self.v.y;
+        let z2 = self.v.z + self.v.z;
+        
+        let xx2 = x2 * self.v.x;
+        let xy2 = x2 * self.v.y;
+        let xz2 = x2 * self.v.z;
+        
+        let yy2 = y2 * self.v.y;
+        let yz2 = y2 * self.v.z;
+        let zz2 = z2 * self.v.z;
+        
+        let sy2 = y2 * self.s;
+        let sz2 = z2 * self.s;
+        let sx2 = x2 * self.s;
+        
+        let _0: T = zero();
+        let _1: T = one();
+        
+        Matrix4::new(_1 - yy2 - zz2,      xy2 + sz2,      xz2 - sy2, _0,
+                          xy2 - sz2, _1 - xx2 - zz2,      yz2 + sx2, _0,
+                          xz2 + sy2,      yz2 - sx2, _1 - xx2 - yy2, _0,
+                                 _0,             _0,             _0, _1)
+    }
 }
 
 pub impl<T:Copy> Quat<T>: Index<uint, T> {
@@ -407,6 +715,83 @@ pub impl<T:Copy Float FuzzyEq<T>> Quat<T>: Neg<Quat<T>> {
     }
 }
 
+/**
+ * A type that can appear on the right hand side of the `*` operator when a
+ * quaternion is on the left hand side. This is implemented for scalars,
+ * quaternions (the Hamilton product) and 3-dimensional vectors (rotation of
+ * the vector by the quaternion). See `VectorMulRHS` for why a scalar can't be
+ * on the left hand side.
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the components of the quaternion
+ * * `Result` - The type of the product
+ */
+pub trait QuatMulRHS<T, Result> {
+    /**
+     * # Return value
+     *
+     * The result of `lhs * self`
+     */
+    pure fn rmul_q(&self, lhs: &Quat<T>) -> Result;
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Quat<T>: Add<Quat<T>, Quat<T>> {
+    #[inline(always)]
+    pure fn add(&self, rhs: &Quat<T>) -> Quat<T> {
+        self.add_q(rhs)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Quat<T>: Sub<Quat<T>, Quat<T>> {
+    #[inline(always)]
+    pure fn sub(&self, rhs: &Quat<T>) -> Quat<T> {
+        self.sub_q(rhs)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>, R, RHS:QuatMulRHS<T, R>> Quat<T>: Mul<RHS, R> {
+    #[inline(always)]
+    pure fn mul(&self, rhs: &RHS) -> R {
+        rhs.rmul_q(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Quat<T>: Div<T, Quat<T>> {
+    #[inline(always)]
+    pure fn div(&self, rhs: &T) -> Quat<T> {
+        self.div_t(*rhs)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Quat<T>: QuatMulRHS<T, Quat<T>> {
+    #[inline(always)]
+    pure fn rmul_q(&self, lhs: &Quat<T>) -> Quat<T> {
+        lhs.mul_q(self)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Vec3<T>: QuatMulRHS<T, Vec3<T>> {
+    #[inline(always)]
+    pure fn rmul_q(&self, lhs: &Quat<T>) -> Vec3<T> {
+        lhs.mul_v(self)
+    }
+}
+
+// Scalar multiplication, eg. `q * 2.0`
+
+pub impl float: QuatMulRHS<float, Quat<float>> {
+    #[inline(always)] pure fn rmul_q(&self, lhs: &Quat<float>) -> Quat<float> { lhs.mul_t(*self) }
+}
+
+pub impl f32: QuatMulRHS<f32, Quat<f32>> {
+    #[inline(always)] pure fn rmul_q(&self, lhs: &Quat<f32>) -> Quat<f32> { lhs.mul_t(*self) }
+}
+
+pub impl f64: QuatMulRHS<f64, Quat<f64>> {
+    #[inline(always)] pure fn rmul_q(&self, lhs: &Quat<f64>) -> Quat<f64> { lhs.mul_t(*self) }
+}
+
 pub impl<T:Copy Float FuzzyEq<T>> Quat<T>: FuzzyEq<T> {
     #[inline(always)]
     pure fn fuzzy_eq(&self, other: &Quat<T>) -> bool {
@@ -422,6 +807,130 @@ pub impl<T:Copy Float FuzzyEq<T>> Quat<T>: FuzzyEq<T> {
     }
 }
 
+/**
+ * Calculate the control points of a sequence of keyframes for use with
+ * `Quat::squad`
+ *
+ * The keyframes should be normalized, and each keyframe should have a
+ * non-negative dot product with the next so that the curve takes the
+ * shortest path between them. The segment between `keys[i]` and `keys[i+1]`
+ * is then interpolated with
+ * `keys[i].squad(&controls[i], &controls[i+1], &keys[i+1], amount)`.
+ *
+ * # Return value
+ *
+ * The control point of each keyframe. The first and last keyframes are
+ * their own control points.
+ */
+pub pure fn squad_controls<T:Copy Float FuzzyEq<T>>(keys: &[Quat<T>]) -> ~[Quat<T>] {
+    let mut controls = ~[];
+    
+    for keys.eachi |i, key| {
+        let control = if i == 0 || i == keys.len() - 1 {
+            *key
+        } else {
+            key.squad_control(&keys[i - 1], &keys[i + 1])
+        };
+        
+        unsafe { controls.push(control); }
+    }
+    
+    controls
+}
+
+/**
+ * Average a set of weighted rotations
+ *
+ * The average is the rotation that minimizes the weighted sum of the squared
+ * distances to each rotation. It is found using the method of Markley et al.,
+ * as the eigenvector with the largest eigenvalue of the symmetric 4 x 4
+ * matrix `sum(weight * q * q.transpose())`. The quaternions `q` and `-q`
+ * contribute equally to this matrix, so the sign of each quaternion does not
+ * affect the result.
+ *
+ * # Arguments
+ *
+ * * `quats` - the rotations to average. These should be normalized.
+ * * `weights` - the non-negative weight of each rotation
+ *
+ * # Return value
+ *
+ * * `Some(q)` - the normalized average, with the sign that is closest to the
+ *               first rotation
+ * * `None` - if there are no rotations, or if all of the weights are zero
+ *
+ * - [Markley et al., Averaging Quaternions]
+ *   (http://ntrs.nasa.gov/archive/nasa/casi.ntrs.nasa.gov/20070017872.pdf)
+ */
+pub pure fn average_quats<T:Copy Float FuzzyEq<T>>(quats: &[Quat<T>], weights: &[T]) -> Option<Quat<T>> {
+    assert quats.len() == weights.len();
+    
+    let mut m: Mat4<T> = Matrix::zero();
+    let mut total: T = zero();
+    
+    for quats.eachi |i, q| {
+        total += weights[i];
+        
+        let (a, b, c, d) = (q.s, q.v.x, q.v.y, q.v.z);
+        let (wa, wb, wc, wd) = (weights[i] * a, weights[i] * b, weights[i] * c, weights[i] * d);
+        
+        // each product is computed once, so that `m` is exactly symmetric
+        let (ab, ac, ad, bc, bd, cd) = (wa * b, wa * c, wa * d, wb * c, wb * d, wc * d);
+        
+        m = m.add_m(&Matrix4::new(wa * a, ab,     ac,     ad,
+                                  ab,     wb * b, bc,     bd,
+                                  ac,     bc,     wc * c, cd,
+                                  ad,     bd,     cd,     wd * d));
+    }
+    
+    if total <= zero() {
+        return None;
+    }
+    
+    // fall back to the weighted sum if the eigen-decomposition fails to
+    // converge
+    match m.symmetric_eigen() {
+        Some((_, vectors)) => {
+            let v = vectors[3];
+            let avg = Quat::new(v.x, v.y, v.z, v.w);
+            
+            Some(if avg.dot(&quats[0]) < zero() { -avg } else { avg })
+        }
+        None => average_quats_fast(quats, weights),
+    }
+}
+
+/**
+ * Average a set of weighted rotations by normalizing their weighted sum
+ *
+ * Each quaternion is negated if necessary to lie in the same hemisphere as
+ * the first, so that `q` and `-q` are treated as the same rotation. This is
+ * cheaper than `average_quats`, and close to it when the rotations are
+ * close together, but it drifts from the true average as they spread apart.
+ *
+ * # Arguments
+ *
+ * * `quats` - the rotations to average. These should be normalized.
+ * * `weights` - the non-negative weight of each rotation
+ *
+ * # Return value
+ *
+ * * `Some(q)` - the normalized average
+ * * `None` - if there are no rotations, or if the weighted sum is zero
+ */
+pub pure fn average_quats_fast<T:Copy Float FuzzyEq<T>>(quats: &[Quat<T>], weights: &[T]) -> Option<Quat<T>> {
+    assert quats.len() == weights.len();
+    
+    let mut sum = Quat::zero();
+    
+    for quats.eachi |i, q| {
+        let weight = if q.dot(&quats[0]) < zero() { -weights[i] } else { weights[i] };
+        sum = sum.add_q(&q.mul_t(weight));
+    }
+    
+    if sum.magnitude2() == zero() { None } else { Some(sum.normalize()) }
+}
+
 // GLSL-style type aliases for quaternions. These are not present in the GLSL
 // specification, but they roughly follow the same nomenclature.
 
@@ -436,14 +945,15 @@ pub impl quat {
     #[inline(always)] static pure fn identity() -> quat { Quat::identity() }
     #[inline(always)] static pure fn zero() -> quat { Quat::zero() }
     
-    #[inline(always)] static pure fn from_angle_x(radians: f32) -> quat { Quat::from_angle_x(radians) }
-    #[inline(always)] static pure fn from_angle_y(radians: f32) -> quat { Quat::from_angle_y(radians) }
-    #[inline(always)] static pure fn from_angle_z(radians: f32) -> quat { Quat::from_angle_z(radians) }
-    #[inline(always)] static pure fn from_angle_xyz(radians_x: f32, radians_y: f32, radians_z: f32)
-        -> quat { Quat::from_angle_xyz(radians_x, radians_y, radians_z) }
-    #[inline(always)] static pure fn from_angle_axis(radians: f32, axis: &vec3) -> quat { Quat::from_angle_axis(radians, axis) }
+    #[inline(always)] static pure fn from_angle_x<A:Angle<f32>>(theta: A) -> quat { Quat::from_angle_x(theta) }
+    #[inline(always)] static pure fn from_angle_y<A:Angle<f32>>(theta: A) -> quat { Quat::from_angle_y(theta) }
+    #[inline(always)] static pure fn from_angle_z<A:Angle<f32>>(theta: A) -> quat { Quat::from_angle_z(theta) }
+    #[inline(always)] static pure fn from_angle_xyz<A:Angle<f32>>(theta_x: A, theta_y: A, theta_z: A)
+        -> quat { Quat::from_angle_xyz(theta_x, theta_y, theta_z) }
+    #[inline(always)] static pure fn from_angle_axis<A:Angle<f32>>(theta: A, axis: &vec3) -> quat { Quat::from_angle_axis(theta, axis) }
     #[inline(always)] static pure fn from_axes(x: vec3, y: vec3, z: vec3) -> quat { Quat::from_axes(x, y, z) }
     #[inline(always)] static pure fn look_at(dir: &vec3, up: &vec3) -> quat { Quat::look_at(dir, up) }
+    #[inline(always)] static pure fn from_two_vectors(a: &vec3, b: &vec3) -> quat { Quat::from_two_vectors(a, b) }
 }
 
 pub impl dquat {
@@ -452,12 +962,13 @@ pub impl dquat {
     #[inline(always)] static pure fn identity() -> dquat { Quat::identity() }
     #[inline(always)] static pure fn zero() -> dquat { Quat::zero() }
     
-    #[inline(always)] static pure fn from_angle_x(radians: f64) -> dquat { Quat::from_angle_x(radians) }
-    #[inline(always)] static pure fn from_angle_y(radians: f64) -> dquat { Quat::from_angle_y(radians) }
-    #[inline(always)] static pure fn from_angle_z(radians: f64) -> dquat { Quat::from_angle_z(radians) }
-    #[inline(always)] static pure fn from_angle_xyz(radians_x: f64, radians_y: f64, radians_z: f64)
-        -> dquat { Quat::from_angle_xyz(radians_x, radians_y, radians_z) }
-    #[inline(always)] static pure fn from_angle_axis(radians: f64, axis: &dvec3) -> dquat { Quat::from_angle_axis(radians, axis) }
+    #[inline(always)] static pure fn from_angle_x<A:Angle<f64>>(theta: A) -> dquat { Quat::from_angle_x(theta) }
+    #[inline(always)] static pure fn from_angle_y<A:Angle<f64>>(theta: A) -> dquat { Quat::from_angle_y(theta) }
+    #[inline(always)] static pure fn from_angle_z<A:Angle<f64>>(theta: A) -> dquat { Quat::from_angle_z(theta) }
+    #[inline(always)] static pure fn from_angle_xyz<A:Angle<f64>>(theta_x: A, theta_y: A, theta_z: A)
+        -> dquat { Quat::from_angle_xyz(theta_x, theta_y, theta_z) }
+    #[inline(always)] static pure fn from_angle_axis<A:Angle<f64>>(theta: A, axis: &dvec3) -> dquat { Quat::from_angle_axis(theta, axis) }
     #[inline(always)] static pure fn from_axes(x: dvec3, y: dvec3, z: dvec3) -> dquat { Quat::from_axes(x, y, z) }
     #[inline(always)] static pure fn look_at(dir: &dvec3, up: &dvec3) -> dquat { Quat::look_at(dir, up) }
+    #[inline(always)] static pure fn from_two_vectors(a: &dvec3, b: &dvec3) -> dquat { Quat::from_two_vectors(a, b) }
 }
\ No newline at end of file
@@ -0,0 +1,151 @@
+use core::cmp::Eq;
+
+use std::cmp::{FuzzyEq, FUZZY_EPSILON};
+use numeric::*;
+use numeric::number::Number;
+use numeric::number::Number::{zero,one};
+
+use angle::{Angle, Rad};
+
+use mat::{
+    Mat3,
+    Matrix3,
+};
+
+use quat::Quat;
+
+use vec::{
+    Vec3,
+    EuclideanVector,
+    NumericVector3,
+    vec3,
+    dvec3,
+};
+
+/**
+ * A rotation of `angle` around `axis`
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the components. Should be a floating point type.
+ *
+ * # Fields
+ *
+ * * `axis` - the axis of rotation. This should be normalized.
+ * * `angle` - the angle of rotation around the axis
+ */
+#[deriving_eq]
+pub struct AxisAngle<T> { axis: Vec3<T>, angle: Rad<T> }
+
+pub impl<T:Copy Float FuzzyEq<T>> AxisAngle<T> {
+    /**
+     * Construct the rotation from an axis and an angle
+     *
+     * # Arguments
+     *
+     * * `axis` - the axis of rotation. This should be normalized.
+     * * `angle` - the angle of rotation around the axis, as a `Rad` or `Deg`
+     *             angle
+     */
+    #[inline(always)]
+    static pure fn new<A:Angle<T>>(axis: Vec3<T>, angle: A) -> AxisAngle<T> {
+        AxisAngle { axis: axis, angle: angle.to_rad() }
+    }
+    
+    /**
+     * # Return value
+     *
+     * A rotation of zero radians around the `x` axis
+     */
+    #[inline(always)]
+    static pure fn identity() -> AxisAngle<T> {
+        AxisAngle::new(NumericVector3::unit_x(), Rad(zero()))
+    }
+    
+    /**
+     * Construct the rotation from a quaternion
+     */
+    #[inline(always)]
+    static pure fn from_quat(quat: &Quat<T>) -> AxisAngle<T> {
+        let (angle, axis) = quat.get_angle_axis();
+        AxisAngle::new(axis, angle)
+    }
+    
+    /**
+     * Construct the rotation from a 3 x 3 rotation matrix
+     */
+    #[inline(always)]
+    static pure fn from_mat3(mat: &Mat3<T>) -> AxisAngle<T> {
+        AxisAngle::from_quat(&mat.to_quat())
+    }
+    
+    /**
+     * # Return value
+     *
+     * The rotation in the opposite direction
+     */
+    #[inline(always)]
+    pure fn inverse(&self) -> AxisAngle<T> {
+        AxisAngle::new(self.axis, -self.angle)
+    }
+    
+    /**
+     * # Return value
+     *
+     * The rotation with its axis normalized
+     */
+    #[inline(always)]
+    pure fn normalize(&self) -> AxisAngle<T> {
+        AxisAngle::new(self.axis.normalize(), self.angle)
+    }
+    
+    /**
+     * Convert the rotation to a quaternion
+     */
+    #[inline(always)]
+    pure fn to_quat(&self) -> Quat<T> {
+        Quat::from_angle_axis(self.angle, &self.axis)
+    }
+    
+    /**
+     * Convert the rotation to a 3 x 3 rotation matrix
+     */
+    #[inline(always)]
+    pure fn to_mat3(&self) -> Mat3<T> {
+        Matrix3::from_angle_axis(self.angle, &self.axis)
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> AxisAngle<T>: FuzzyEq<T> {
+    #[inline(always)]
+    pure fn fuzzy_eq(&self, other: &AxisAngle<T>) -> bool {
+        self.fuzzy_eq_eps(other, &Number::from(FUZZY_EPSILON))
+    }
+    
+    #[inline(always)]
+    pure fn fuzzy_eq_eps(&self, other: &AxisAngle<T>, epsilon: &T) -> bool {
+        self.axis.fuzzy_eq_eps(&other.axis, epsilon) &&
+        self.angle.fuzzy_eq_eps(&other.angle, epsilon)
+    }
+}
+
+// Type aliases following the nomenclature of the GLSL-style types
+
+pub type axis_angle  = AxisAngle<f32>;  /// a single-precision floating-point axis-angle rotation
+pub type daxis_angle = AxisAngle<f64>;  /// a double-precision floating-point axis-angle rotation
+
+// Static method wrappers for GLSL-style types
+
+pub impl axis_angle {
+    #[inline(always)] static pure fn new<A:Angle<f32>>(axis: vec3, angle: A) -> axis_angle { AxisAngle::new(axis, angle) }
+    #[inline(always)] static pure fn identity() -> axis_angle { AxisAngle::identity() }
+    #[inline(always)] static pure fn from_quat(quat: &Quat<f32>) -> axis_angle { AxisAngle::from_quat(quat) }
+    #[inline(always)] static pure fn from_mat3(mat: &Mat3<f32>) -> axis_angle { AxisAngle::from_mat3(mat) }
+}
+
+pub impl daxis_angle {
+    #[inline(always)] static pure fn new<A:Angle<f64>>(axis: dvec3, angle: A) -> daxis_angle { AxisAngle::new(axis, angle) }
+    #[inline(always)] static pure fn identity() -> daxis_angle { AxisAngle::identity() }
+    #[inline(always)] static pure fn from_quat(quat: &Quat<f64>) -> daxis_angle { AxisAngle::from_quat(quat) }
+    #[inline(always)] static pure fn from_mat3(mat: &Mat3<f64>) -> daxis_angle { AxisAngle::from_mat3(mat) }
+}
@@ -0,0 +1,1717 @@
+use vec::{
+    Vec2,
+    Vec3,
+    Vec4,
+    Vector2,
+    Vector3,
+    Vector4,
+};
+
+// GLSL-style swizzles, corresponding to Section 5.5 of the [GLSL 4.30.6 specification]
+// (http://www.opengl.org/registry/doc/GLSLangSpec.4.30.6.pdf).
+//
+// The `x, y, z, w`, `r, g, b, a` and `s, t, p, q` component names all refer to
+// the same components of the vector, and can't be mixed in a single swizzle.
+// Any combination of components can be read, but a swizzle can only be
+// written if none of its components are repeated.
+
+pub impl<T:Copy> Vec2<T> {
+    #[inline(always)] pure fn xx(&self) -> Vec2<T> { Vector2::new(self.x, self.x) }
+    #[inline(always)] pure fn xy(&self) -> Vec2<T> { Vector2::new(self.x, self.y) }
+    #[inline(always)] pure fn yx(&self) -> Vec2<T> { Vector2::new(self.y, self.x) }
+    #[inline(always)] pure fn yy(&self) -> Vec2<T> { Vector2::new(self.y, self.y) }
+    #[inline(always)] pure fn xxx(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.x) }
+    #[inline(always)] pure fn xxy(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.y) }
+    #[inline(always)] pure fn xyx(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.x) }
+    #[inline(always)] pure fn xyy(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.y) }
+    #[inline(always)] pure fn yxx(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.x) }
+    #[inline(always)] pure fn yxy(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.y) }
+    #[inline(always)] pure fn yyx(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.x) }
+    #[inline(always)] pure fn yyy(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.y) }
+    #[inline(always)] pure fn xxxx(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.x) }
+    #[inline(always)] pure fn xxxy(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.y) }
+    #[inline(always)] pure fn xxyx(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.x) }
+    #[inline(always)] pure fn xxyy(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.y) }
+    #[inline(always)] pure fn xyxx(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.x) }
+    #[inline(always)] pure fn xyxy(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.y) }
+    #[inline(always)] pure fn xyyx(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.x) }
+    #[inline(always)] pure fn xyyy(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.y) }
+    #[inline(always)] pure fn yxxx(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.x) }
+    #[inline(always)] pure fn yxxy(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.y) }
+    #[inline(always)] pure fn yxyx(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.x) }
+    #[inline(always)] pure fn yxyy(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.y) }
+    #[inline(always)] pure fn yyxx(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.x) }
+    #[inline(always)] pure fn yyxy(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.y) }
+    #[inline(always)] pure fn yyyx(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.x) }
+    #[inline(always)] pure fn yyyy(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.y) }
+    
+    #[inline(always)] fn set_xy(&mut self, v: &Vec2<T>) { self.x = v.x; self.y = v.y; }
+    #[inline(always)] fn set_yx(&mut self, v: &Vec2<T>) { self.y = v.x; self.x = v.y; }
+}
+
+pub impl<T:Copy> Vec2<T> {
+    #[inline(always)] pure fn rr(&self) -> Vec2<T> { Vector2::new(self.x, self.x) }
+    #[inline(always)] pure fn rg(&self) -> Vec2<T> { Vector2::new(self.x, self.y) }
+    #[inline(always)] pure fn gr(&self) -> Vec2<T> { Vector2::new(self.y, self.x) }
+    #[inline(always)] pure fn gg(&self) -> Vec2<T> { Vector2::new(self.y, self.y) }
+    #[inline(always)] pure fn rrr(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.x) }
+    #[inline(always)] pure fn rrg(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.y) }
+    #[inline(always)] pure fn rgr(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.x) }
+    #[inline(always)] pure fn rgg(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.y) }
+    #[inline(always)] pure fn grr(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.x) }
+    #[inline(always)] pure fn grg(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.y) }
+    #[inline(always)] pure fn ggr(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.x) }
+    #[inline(always)] pure fn ggg(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.y) }
+    #[inline(always)] pure fn rrrr(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.x) }
+    #[inline(always)] pure fn rrrg(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.y) }
+    #[inline(always)] pure fn rrgr(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.x) }
+    #[inline(always)] pure fn rrgg(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.y) }
+    #[inline(always)] pure fn rgrr(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.x) }
+    #[inline(always)] pure fn rgrg(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.y) }
+    #[inline(always)] pure fn rggr(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.x) }
+    #[inline(always)] pure fn rggg(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.y) }
+    #[inline(always)] pure fn grrr(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.x) }
+    #[inline(always)] pure fn grrg(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.y) }
+    #[inline(always)] pure fn grgr(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.x) }
+    #[inline(always)] pure fn grgg(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.y) }
+    #[inline(always)] pure fn ggrr(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.x) }
+    #[inline(always)] pure fn ggrg(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.y) }
+    #[inline(always)] pure fn gggr(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.x) }
+    #[inline(always)] pure fn gggg(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.y) }
+    
+    #[inline(always)] fn set_rg(&mut self, v: &Vec2<T>) { self.x = v.x; self.y = v.y; }
+    #[inline(always)] fn set_gr(&mut self, v: &Vec2<T>) { self.y = v.x; self.x = v.y; }
+}
+
+pub impl<T:Copy> Vec2<T> {
+    #[inline(always)] pure fn ss(&self) -> Vec2<T> { Vector2::new(self.x, self.x) }
+    #[inline(always)] pure fn st(&self) -> Vec2<T> { Vector2::new(self.x, self.y) }
+    #[inline(always)] pure fn ts(&self) -> Vec2<T> { Vector2::new(self.y, self.x) }
+    #[inline(always)] pure fn tt(&self) -> Vec2<T> { Vector2::new(self.y, self.y) }
+    #[inline(always)] pure fn sss(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.x) }
+    #[inline(always)] pure fn sst(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.y) }
+    #[inline(always)] pure fn sts(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.x) }
+    #[inline(always)] pure fn stt(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.y) }
+    #[inline(always)] pure fn tss(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.x) }
+    #[inline(always)] pure fn tst(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.y) }
+    #[inline(always)] pure fn tts(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.x) }
+    #[inline(always)] pure fn ttt(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.y) }
+    #[inline(always)] pure fn ssss(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.x) }
+    #[inline(always)] pure fn ssst(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.y) }
+    #[inline(always)] pure fn ssts(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.x) }
+    #[inline(always)] pure fn sstt(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.y) }
+    #[inline(always)] pure fn stss(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.x) }
+    #[inline(always)] pure fn stst(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.y) }
+    #[inline(always)] pure fn stts(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.x) }
+    #[inline(always)] pure fn sttt(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.y) }
+    #[inline(always)] pure fn tsss(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.x) }
+    #[inline(always)] pure fn tsst(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.y) }
+    #[inline(always)] pure fn tsts(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.x) }
+    #[inline(always)] pure fn tstt(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.y) }
+    #[inline(always)] pure fn ttss(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.x) }
+    #[inline(always)] pure fn ttst(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.y) }
+    #[inline(always)] pure fn ttts(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.x) }
+    #[inline(always)] pure fn tttt(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.y) }
+    
+    #[inline(always)] fn set_st(&mut self, v: &Vec2<T>) { self.x = v.x; self.y = v.y; }
+    #[inline(always)] fn set_ts(&mut self, v: &Vec2<T>) { self.y = v.x; self.x = v.y; }
+}
+
+pub impl<T:Copy> Vec3<T> {
+    #[inline(always)] pure fn xx(&self) -> Vec2<T> { Vector2::new(self.x, self.x) }
+    #[inline(always)] pure fn xy(&self) -> Vec2<T> { Vector2::new(self.x, self.y) }
+    #[inline(always)] pure fn xz(&self) -> Vec2<T> { Vector2::new(self.x, self.z) }
+    #[inline(always)] pure fn yx(&self) -> Vec2<T> { Vector2::new(self.y, self.x) }
+    #[inline(always)] pure fn yy(&self) -> Vec2<T> { Vector2::new(self.y, self.y) }
+    #[inline(always)] pure fn yz(&self) -> Vec2<T> { Vector2::new(self.y, self.z) }
+    #[inline(always)] pure fn zx(&self) -> Vec2<T> { Vector2::new(self.z, self.x) }
+    #[inline(always)] pure fn zy(&self) -> Vec2<T> { Vector2::new(self.z, self.y) }
+    #[inline(always)] pure fn zz(&self) -> Vec2<T> { Vector2::new(self.z, self.z) }
+    #[inline(always)] pure fn xxx(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.x) }
+    #[inline(always)] pure fn xxy(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.y) }
+    #[inline(always)] pure fn xxz(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.z) }
+    #[inline(always)] pure fn xyx(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.x) }
+    #[inline(always)] pure fn xyy(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.y) }
+    #[inline(always)] pure fn xyz(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.z) }
+    #[inline(always)] pure fn xzx(&self) -> Vec3<T> { Vector3::new(self.x, self.z, self.x) }
+    #[inline(always)] pure fn xzy(&self) -> Vec3<T> { Vector3::new(self.x, self.z, self.y) }
+    #[inline(always)] pure fn xzz(&self) -> Vec3<T> { Vector3::new(self.x, self.z, self.z) }
+    #[inline(always)] pure fn yxx(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.x) }
+    #[inline(always)] pure fn yxy(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.y) }
+    #[inline(always)] pure fn yxz(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.z) }
+    #[inline(always)] pure fn yyx(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.x) }
+    #[inline(always)] pure fn yyy(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.y) }
+    #[inline(always)] pure fn yyz(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.z) }
+    #[inline(always)] pure fn yzx(&self) -> Vec3<T> { Vector3::new(self.y, self.z, self.x) }
+    #[inline(always)] pure fn yzy(&self) -> Vec3<T> { Vector3::new(self.y, self.z, self.y) }
+    #[inline(always)] pure fn yzz(&self) -> Vec3<T> { Vector3::new(self.y, self.z, self.z) }
+    #[inline(always)] pure fn zxx(&self) -> Vec3<T> { Vector3::new(self.z, self.x, self.x) }
+    #[inline(always)] pure fn zxy(&self) -> Vec3<T> { Vector3::new(self.z, self.x, self.y) }
+    #[inline(always)] pure fn zxz(&self) -> Vec3<T> { Vector3::new(self.z, self.x, self.z) }
+    #[inline(always)] pure fn zyx(&self) -> Vec3<T> { Vector3::new(self.z, self.y, self.x) }
+    #[inline(always)] pure fn zyy(&self) -> Vec3<T> { Vector3::new(self.z, self.y, self.y) }
+    #[inline(always)] pure fn zyz(&self) -> Vec3<T> { Vector3::new(self.z, self.y, self.z) }
+    #[inline(always)] pure fn zzx(&self) -> Vec3<T> { Vector3::new(self.z, self.z, self.x) }
+    #[inline(always)] pure fn zzy(&self) -> Vec3<T> { Vector3::new(self.z, self.z, self.y) }
+    #[inline(always)] pure fn zzz(&self) -> Vec3<T> { Vector3::new(self.z, self.z, self.z) }
+    #[inline(always)] pure fn xxxx(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.x) }
+    #[inline(always)] pure fn xxxy(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.y) }
+    #[inline(always)] pure fn xxxz(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.z) }
+    #[inline(always)] pure fn xxyx(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.x) }
+    #[inline(always)] pure fn xxyy(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.y) }
+    #[inline(always)] pure fn xxyz(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.z) }
+    #[inline(always)] pure fn xxzx(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.z, self.x) }
+    #[inline(always)] pure fn xxzy(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.z, self.y) }
+    #[inline(always)] pure fn xxzz(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.z, self.z) }
+    #[inline(always)] pure fn xyxx(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.x) }
+    #[inline(always)] pure fn xyxy(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.y) }
+    #[inline(always)] pure fn xyxz(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.z) }
+    #[inline(always)] pure fn xyyx(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.x) }
+    #[inline(always)] pure fn xyyy(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.y) }
+    #[inline(always)] pure fn xyyz(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.z) }
+    #[inline(always)] pure fn xyzx(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.z, self.x) }
+    #[inline(always)] pure fn xyzy(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.z, self.y) }
+    #[inline(always)] pure fn xyzz(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.z, self.z) }
+    #[inline(always)] pure fn xzxx(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.x, self.x) }
+    #[inline(always)] pure fn xzxy(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.x, self.y) }
+    #[inline(always)] pure fn xzxz(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.x, self.z) }
+    #[inline(always)] pure fn xzyx(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.y, self.x) }
+    #[inline(always)] pure fn xzyy(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.y, self.y) }
+    #[inline(always)] pure fn xzyz(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.y, self.z) }
+    #[inline(always)] pure fn xzzx(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.z, self.x) }
+    #[inline(always)] pure fn xzzy(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.z, self.y) }
+    #[inline(always)] pure fn xzzz(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.z, self.z) }
+    #[inline(always)] pure fn yxxx(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.x) }
+    #[inline(always)] pure fn yxxy(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.y) }
+    #[inline(always)] pure fn yxxz(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.z) }
+    #[inline(always)] pure fn yxyx(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.x) }
+    #[inline(always)] pure fn yxyy(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.y) }
+    #[inline(always)] pure fn yxyz(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.z) }
+    #[inline(always)] pure fn yxzx(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.z, self.x) }
+    #[inline(always)] pure fn yxzy(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.z, self.y) }
+    #[inline(always)] pure fn yxzz(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.z, self.z) }
+    #[inline(always)] pure fn yyxx(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.x) }
+    #[inline(always)] pure fn yyxy(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.y) }
+    #[inline(always)] pure fn yyxz(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.z) }
+    #[inline(always)] pure fn yyyx(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.x) }
+    #[inline(always)] pure fn yyyy(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.y) }
+    #[inline(always)] pure fn yyyz(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.z) }
+    #[inline(always)] pure fn yyzx(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.z, self.x) }
+    #[inline(always)] pure fn yyzy(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.z, self.y) }
+    #[inline(always)] pure fn yyzz(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.z, self.z) }
+    #[inline(always)] pure fn yzxx(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.x, self.x) }
+    #[inline(always)] pure fn yzxy(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.x, self.y) }
+    #[inline(always)] pure fn yzxz(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.x, self.z) }
+    #[inline(always)] pure fn yzyx(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.y, self.x) }
+    #[inline(always)] pure fn yzyy(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.y, self.y) }
+    #[inline(always)] pure fn yzyz(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.y, self.z) }
+    #[inline(always)] pure fn yzzx(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.z, self.x) }
+    #[inline(always)] pure fn yzzy(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.z, self.y) }
+    #[inline(always)] pure fn yzzz(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.z, self.z) }
+    #[inline(always)] pure fn zxxx(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.x, self.x) }
+    #[inline(always)] pure fn zxxy(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.x, self.y) }
+    #[inline(always)] pure fn zxxz(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.x, self.z) }
+    #[inline(always)] pure fn zxyx(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.y, self.x) }
+    #[inline(always)] pure fn zxyy(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.y, self.y) }
+    #[inline(always)] pure fn zxyz(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.y, self.z) }
+    #[inline(always)] pure fn zxzx(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.z, self.x) }
+    #[inline(always)] pure fn zxzy(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.z, self.y) }
+    #[inline(always)] pure fn zxzz(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.z, self.z) }
+    #[inline(always)] pure fn zyxx(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.x, self.x) }
+    #[inline(always)] pure fn zyxy(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.x, self.y) }
+    #[inline(always)] pure fn zyxz(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.x, self.z) }
+    #[inline(always)] pure fn zyyx(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.y, self.x) }
+    #[inline(always)] pure fn zyyy(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.y, self.y) }
+    #[inline(always)] pure fn zyyz(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.y, self.z) }
+    #[inline(always)] pure fn zyzx(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.z, self.x) }
+    #[inline(always)] pure fn zyzy(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.z, self.y) }
+    #[inline(always)] pure fn zyzz(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.z, self.z) }
+    #[inline(always)] pure fn zzxx(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.x, self.x) }
+    #[inline(always)] pure fn zzxy(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.x, self.y) }
+    #[inline(always)] pure fn zzxz(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.x, self.z) }
+    #[inline(always)] pure fn zzyx(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.y, self.x) }
+    #[inline(always)] pure fn zzyy(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.y, self.y) }
+    #[inline(always)] pure fn zzyz(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.y, self.z) }
+    #[inline(always)] pure fn zzzx(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.z, self.x) }
+    #[inline(always)] pure fn zzzy(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.z, self.y) }
+    #[inline(always)] pure fn zzzz(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.z, self.z) }
+    
+    #[inline(always)] fn set_xy(&mut self, v: &Vec2<T>) { self.x = v.x; self.y = v.y; }
+    #[inline(always)] fn set_xz(&mut self, v: &Vec2<T>) { self.x = v.x; self.z = v.y; }
+    #[inline(always)] fn set_yx(&mut self, v: &Vec2<T>) { self.y = v.x; self.x = v.y; }
+    #[inline(always)] fn set_yz(&mut self, v: &Vec2<T>) { self.y = v.x; self.z = v.y; }
+    #[inline(always)] fn set_zx(&mut self, v: &Vec2<T>) { self.z = v.x; self.x = v.y; }
+    #[inline(always)] fn set_zy(&mut self, v: &Vec2<T>) { self.z = v.x; self.y = v.y; }
+    #[inline(always)] fn set_xyz(&mut self, v: &Vec3<T>) { self.x = v.x; self.y = v.y; self.z = v.z; }
+    #[inline(always)] fn set_xzy(&mut self, v: &Vec3<T>) { self.x = v.x; self.z = v.y; self.y = v.z; }
+    #[inline(always)] fn set_yxz(&mut self, v: &Vec3<T>) { self.y = v.x; self.x = v.y; self.z = v.z; }
+    #[inline(always)] fn set_yzx(&mut self, v: &Vec3<T>) { self.y = v.x; self.z = v.y; self.x = v.z; }
+    #[inline(always)] fn set_zxy(&mut self, v: &Vec3<T>) { self.z = v.x; self.x = v.y; self.y = v.z; }
+    #[inline(always)] fn set_zyx(&mut self, v: &Vec3<T>) { self.z = v.x; self.y = v.y; self.x = v.z; }
+}
+
+pub impl<T:Copy> Vec3<T> {
+    #[inline(always)] pure fn rr(&self) -> Vec2<T> { Vector2::new(self.x, self.x) }
+    #[inline(always)] pure fn rg(&self) -> Vec2<T> { Vector2::new(self.x, self.y) }
+    #[inline(always)] pure fn rb(&self) -> Vec2<T> { Vector2::new(self.x, self.z) }
+    #[inline(always)] pure fn gr(&self) -> Vec2<T> { Vector2::new(self.y, self.x) }
+    #[inline(always)] pure fn gg(&self) -> Vec2<T> { Vector2::new(self.y, self.y) }
+    #[inline(always)] pure fn gb(&self) -> Vec2<T> { Vector2::new(self.y, self.z) }
+    #[inline(always)] pure fn br(&self) -> Vec2<T> { Vector2::new(self.z, self.x) }
+    #[inline(always)] pure fn bg(&self) -> Vec2<T> { Vector2::new(self.z, self.y) }
+    #[inline(always)] pure fn bb(&self) -> Vec2<T> { Vector2::new(self.z, self.z) }
+    #[inline(always)] pure fn rrr(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.x) }
+    #[inline(always)] pure fn rrg(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.y) }
+    #[inline(always)] pure fn rrb(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.z) }
+    #[inline(always)] pure fn rgr(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.x) }
+    #[inline(always)] pure fn rgg(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.y) }
+    #[inline(always)] pure fn rgb(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.z) }
+    #[inline(always)] pure fn rbr(&self) -> Vec3<T> { Vector3::new(self.x, self.z, self.x) }
+    #[inline(always)] pure fn rbg(&self) -> Vec3<T> { Vector3::new(self.x, self.z, self.y) }
+    #[inline(always)] pure fn rbb(&self) -> Vec3<T> { Vector3::new(self.x, self.z, self.z) }
+    #[inline(always)] pure fn grr(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.x) }
+    #[inline(always)] pure fn grg(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.y) }
+    #[inline(always)] pure fn grb(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.z) }
+    #[inline(always)] pure fn ggr(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.x) }
+    #[inline(always)] pure fn ggg(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.y) }
+    #[inline(always)] pure fn ggb(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.z) }
+    #[inline(always)] pure fn gbr(&self) -> Vec3<T> { Vector3::new(self.y, self.z, self.x) }
+    #[inline(always)] pure fn gbg(&self) -> Vec3<T> { Vector3::new(self.y, self.z, self.y) }
+    #[inline(always)] pure fn gbb(&self) -> Vec3<T> { Vector3::new(self.y, self.z, self.z) }
+    #[inline(always)] pure fn brr(&self) -> Vec3<T> { Vector3::new(self.z, self.x, self.x) }
+    #[inline(always)] pure fn brg(&self) -> Vec3<T> { Vector3::new(self.z, self.x, self.y) }
+    #[inline(always)] pure fn brb(&self) -> Vec3<T> { Vector3::new(self.z, self.x, self.z) }
+    #[inline(always)] pure fn bgr(&self) -> Vec3<T> { Vector3::new(self.z, self.y, self.x) }
+    #[inline(always)] pure fn bgg(&self) -> Vec3<T> { Vector3::new(self.z, self.y, self.y) }
+    #[inline(always)] pure fn bgb(&self) -> Vec3<T> { Vector3::new(self.z, self.y, self.z) }
+    #[inline(always)] pure fn bbr(&self) -> Vec3<T> { Vector3::new(self.z, self.z, self.x) }
+    #[inline(always)] pure fn bbg(&self) -> Vec3<T> { Vector3::new(self.z, self.z, self.y) }
+    #[inline(always)] pure fn bbb(&self) -> Vec3<T> { Vector3::new(self.z, self.z, self.z) }
+    #[inline(always)] pure fn rrrr(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.x) }
+    #[inline(always)] pure fn rrrg(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.y) }
+    #[inline(always)] pure fn rrrb(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.z) }
+    #[inline(always)] pure fn rrgr(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.x) }
+    #[inline(always)] pure fn rrgg(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.y) }
+    #[inline(always)] pure fn rrgb(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.z) }
+    #[inline(always)] pure fn rrbr(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.z, self.x) }
+    #[inline(always)] pure fn rrbg(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.z, self.y) }
+    #[inline(always)] pure fn rrbb(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.z, self.z) }
+    #[inline(always)] pure fn rgrr(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.x) }
+    #[inline(always)] pure fn rgrg(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.y) }
+    #[inline(always)] pure fn rgrb(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.z) }
+    #[inline(always)] pure fn rggr(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.x) }
+    #[inline(always)] pure fn rggg(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.y) }
+    #[inline(always)] pure fn rggb(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.z) }
+    #[inline(always)] pure fn rgbr(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.z, self.x) }
+    #[inline(always)] pure fn rgbg(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.z, self.y) }
+    #[inline(always)] pure fn rgbb(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.z, self.z) }
+    #[inline(always)] pure fn rbrr(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.x, self.x) }
+    #[inline(always)] pure fn rbrg(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.x, self.y) }
+    #[inline(always)] pure fn rbrb(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.x, self.z) }
+    #[inline(always)] pure fn rbgr(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.y, self.x) }
+    #[inline(always)] pure fn rbgg(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.y, self.y) }
+    #[inline(always)] pure fn rbgb(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.y, self.z) }
+    #[inline(always)] pure fn rbbr(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.z, self.x) }
+    #[inline(always)] pure fn rbbg(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.z, self.y) }
+    #[inline(always)] pure fn rbbb(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.z, self.z) }
+    #[inline(always)] pure fn grrr(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.x) }
+    #[inline(always)] pure fn grrg(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.y) }
+    #[inline(always)] pure fn grrb(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.z) }
+    #[inline(always)] pure fn grgr(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.x) }
+    #[inline(always)] pure fn grgg(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.y) }
+    #[inline(always)] pure fn grgb(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.z) }
+    #[inline(always)] pure fn grbr(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.z, self.x) }
+    #[inline(always)] pure fn grbg(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.z, self.y) }
+    #[inline(always)] pure fn grbb(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.z, self.z) }
+    #[inline(always)] pure fn ggrr(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.x) }
+    #[inline(always)] pure fn ggrg(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.y) }
+    #[inline(always)] pure fn ggrb(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.z) }
+    #[inline(always)] pure fn gggr(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.x) }
+    #[inline(always)] pure fn gggg(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.y) }
+    #[inline(always)] pure fn gggb(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.z) }
+    #[inline(always)] pure fn ggbr(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.z, self.x) }
+    #[inline(always)] pure fn ggbg(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.z, self.y) }
+    #[inline(always)] pure fn ggbb(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.z, self.z) }
+    #[inline(always)] pure fn gbrr(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.x, self.x) }
+    #[inline(always)] pure fn gbrg(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.x, self.y) }
+    #[inline(always)] pure fn gbrb(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.x, self.z) }
+    #[inline(always)] pure fn gbgr(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.y, self.x) }
+    #[inline(always)] pure fn gbgg(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.y, self.y) }
+    #[inline(always)] pure fn gbgb(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.y, self.z) }
+    #[inline(always)] pure fn gbbr(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.z, self.x) }
+    #[inline(always)] pure fn gbbg(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.z, self.y) }
+    #[inline(always)] pure fn gbbb(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.z, self.z) }
+    #[inline(always)] pure fn brrr(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.x, self.x) }
+    #[inline(always)] pure fn brrg(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.x, self.y) }
+    #[inline(always)] pure fn brrb(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.x, self.z) }
+    #[inline(always)] pure fn brgr(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.y, self.x) }
+    #[inline(always)] pure fn brgg(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.y, self.y) }
+    #[inline(always)] pure fn brgb(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.y, self.z) }
+    #[inline(always)] pure fn brbr(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.z, self.x) }
+    #[inline(always)] pure fn brbg(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.z, self.y) }
+    #[inline(always)] pure fn brbb(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.z, self.z) }
+    #[inline(always)] pure fn bgrr(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.x, self.x) }
+    #[inline(always)] pure fn bgrg(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.x, self.y) }
+    #[inline(always)] pure fn bgrb(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.x, self.z) }
+    #[inline(always)] pure fn bggr(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.y, self.x) }
+    #[inline(always)] pure fn bggg(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.y, self.y) }
+    #[inline(always)] pure fn bggb(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.y, self.z) }
+    #[inline(always)] pure fn bgbr(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.z, self.x) }
+    #[inline(always)] pure fn bgbg(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.z, self.y) }
+    #[inline(always)] pure fn bgbb(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.z, self.z) }
+    #[inline(always)] pure fn bbrr(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.x, self.x) }
+    #[inline(always)] pure fn bbrg(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.x, self.y) }
+    #[inline(always)] pure fn bbrb(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.x, self.z) }
+    #[inline(always)] pure fn bbgr(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.y, self.x) }
+    #[inline(always)] pure fn bbgg(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.y, self.y) }
+    #[inline(always)] pure fn bbgb(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.y, self.z) }
+    #[inline(always)] pure fn bbbr(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.z, self.x) }
+    #[inline(always)] pure fn bbbg(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.z, self.y) }
+    #[inline(always)] pure fn bbbb(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.z, self.z) }
+    
+    #[inline(always)] fn set_rg(&mut self, v: &Vec2<T>) { self.x = v.x; self.y = v.y; }
+    #[inline(always)] fn set_rb(&mut self, v: &Vec2<T>) { self.x = v.x; self.z = v.y; }
+    #[inline(always)] fn set_gr(&mut self, v: &Vec2<T>) { self.y = v.x; self.x = v.y; }
+    #[inline(always)] fn set_gb(&mut self, v: &Vec2<T>) { self.y = v.x; self.z = v.y; }
+    #[inline(always)] fn set_br(&mut self, v: &Vec2<T>) { self.z = v.x; self.x = v.y; }
+    #[inline(always)] fn set_bg(&mut self, v: &Vec2<T>) { self.z = v.x; self.y = v.y; }
+    #[inline(always)] fn set_rgb(&mut self, v: &Vec3<T>) { self.x = v.x; self.y = v.y; self.z = v.z; }
+    #[inline(always)] fn set_rbg(&mut self, v: &Vec3<T>) { self.x = v.x; self.z = v.y; self.y = v.z; }
+    #[inline(always)] fn set_grb(&mut self, v: &Vec3<T>) { self.y = v.x; self.x = v.y; self.z = v.z; }
+    #[inline(always)] fn set_gbr(&mut self, v: &Vec3<T>) { self.y = v.x; self.z = v.y; self.x = v.z; }
+    #[inline(always)] fn set_brg(&mut self, v: &Vec3<T>) { self.z = v.x; self.x = v.y; self.y = v.z; }
+    #[inline(always)] fn set_bgr(&mut self, v: &Vec3<T>) { self.z = v.x; self.y = v.y; self.x = v.z; }
+}
+
+pub impl<T:Copy> Vec3<T> {
+    #[inline(always)] pure fn ss(&self) -> Vec2<T> { Vector2::new(self.x, self.x) }
+    #[inline(always)] pure fn st(&self) -> Vec2<T> { Vector2::new(self.x, self.y) }
+    #[inline(always)] pure fn sp(&self) -> Vec2<T> { Vector2::new(self.x, self.z) }
+    #[inline(always)] pure fn ts(&self) -> Vec2<T> { Vector2::new(self.y, self.x) }
+    #[inline(always)] pure fn tt(&self) -> Vec2<T> { Vector2::new(self.y, self.y) }
+    #[inline(always)] pure fn tp(&self) -> Vec2<T> { Vector2::new(self.y, self.z) }
+    #[inline(always)] pure fn ps(&self) -> Vec2<T> { Vector2::new(self.z, self.x) }
+    #[inline(always)] pure fn pt(&self) -> Vec2<T> { Vector2::new(self.z, self.y) }
+    #[inline(always)] pure fn pp(&self) -> Vec2<T> { Vector2::new(self.z, self.z) }
+    #[inline(always)] pure fn sss(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.x) }
+    #[inline(always)] pure fn sst(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.y) }
+    #[inline(always)] pure fn ssp(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.z) }
+    #[inline(always)] pure fn sts(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.x) }
+    #[inline(always)] pure fn stt(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.y) }
+    #[inline(always)] pure fn stp(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.z) }
+    #[inline(always)] pure fn sps(&self) -> Vec3<T> { Vector3::new(self.x, self.z, self.x) }
+    #[inline(always)] pure fn spt(&self) -> Vec3<T> { Vector3::new(self.x, self.z, self.y) }
+    #[inline(always)] pure fn spp(&self) -> Vec3<T> { Vector3::new(self.x, self.z, self.z) }
+    #[inline(always)] pure fn tss(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.x) }
+    #[inline(always)] pure fn tst(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.y) }
+    #[inline(always)] pure fn tsp(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.z) }
+    #[inline(always)] pure fn tts(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.x) }
+    #[inline(always)] pure fn ttt(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.y) }
+    #[inline(always)] pure fn ttp(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.z) }
+    #[inline(always)] pure fn tps(&self) -> Vec3<T> { Vector3::new(self.y, self.z, self.x) }
+    #[inline(always)] pure fn tpt(&self) -> Vec3<T> { Vector3::new(self.y, self.z, self.y) }
+    #[inline(always)] pure fn tpp(&self) -> Vec3<T> { Vector3::new(self.y, self.z, self.z) }
+    #[inline(always)] pure fn pss(&self) -> Vec3<T> { Vector3::new(self.z, self.x, self.x) }
+    #[inline(always)] pure fn pst(&self) -> Vec3<T> { Vector3::new(self.z, self.x, self.y) }
+    #[inline(always)] pure fn psp(&self) -> Vec3<T> { Vector3::new(self.z, self.x, self.z) }
+    #[inline(always)] pure fn pts(&self) -> Vec3<T> { Vector3::new(self.z, self.y, self.x) }
+    #[inline(always)] pure fn ptt(&self) -> Vec3<T> { Vector3::new(self.z, self.y, self.y) }
+    #[inline(always)] pure fn ptp(&self) -> Vec3<T> { Vector3::new(self.z, self.y, self.z) }
+    #[inline(always)] pure fn pps(&self) -> Vec3<T> { Vector3::new(self.z, self.z, self.x) }
+    #[inline(always)] pure fn ppt(&self) -> Vec3<T> { Vector3::new(self.z, self.z, self.y) }
+    #[inline(always)] pure fn ppp(&self) -> Vec3<T> { Vector3::new(self.z, self.z, self.z) }
+    #[inline(always)] pure fn ssss(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.x) }
+    #[inline(always)] pure fn ssst(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.y) }
+    #[inline(always)] pure fn sssp(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.z) }
+    #[inline(always)] pure fn ssts(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.x) }
+    #[inline(always)] pure fn sstt(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.y) }
+    #[inline(always)] pure fn sstp(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.z) }
+    #[inline(always)] pure fn ssps(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.z, self.x) }
+    #[inline(always)] pure fn sspt(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.z, self.y) }
+    #[inline(always)] pure fn sspp(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.z, self.z) }
+    #[inline(always)] pure fn stss(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.x) }
+    #[inline(always)] pure fn stst(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.y) }
+    #[inline(always)] pure fn stsp(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.z) }
+    #[inline(always)] pure fn stts(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.x) }
+    #[inline(always)] pure fn sttt(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.y) }
+    #[inline(always)] pure fn sttp(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.z) }
+    #[inline(always)] pure fn stps(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.z, self.x) }
+    #[inline(always)] pure fn stpt(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.z, self.y) }
+    #[inline(always)] pure fn stpp(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.z, self.z) }
+    #[inline(always)] pure fn spss(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.x, self.x) }
+    #[inline(always)] pure fn spst(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.x, self.y) }
+    #[inline(always)] pure fn spsp(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.x, self.z) }
+    #[inline(always)] pure fn spts(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.y, self.x) }
+    #[inline(always)] pure fn sptt(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.y, self.y) }
+    #[inline(always)] pure fn sptp(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.y, self.z) }
+    #[inline(always)] pure fn spps(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.z, self.x) }
+    #[inline(always)] pure fn sppt(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.z, self.y) }
+    #[inline(always)] pure fn sppp(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.z, self.z) }
+    #[inline(always)] pure fn tsss(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.x) }
+    #[inline(always)] pure fn tsst(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.y) }
+    #[inline(always)] pure fn tssp(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.z) }
+    #[inline(always)] pure fn tsts(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.x) }
+    #[inline(always)] pure fn tstt(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.y) }
+    #[inline(always)] pure fn tstp(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.z) }
+    #[inline(always)] pure fn tsps(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.z, self.x) }
+    #[inline(always)] pure fn tspt(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.z, self.y) }
+    #[inline(always)] pure fn tspp(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.z, self.z) }
+    #[inline(always)] pure fn ttss(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.x) }
+    #[inline(always)] pure fn ttst(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.y) }
+    #[inline(always)] pure fn ttsp(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.z) }
+    #[inline(always)] pure fn ttts(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.x) }
+    #[inline(always)] pure fn tttt(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.y) }
+    #[inline(always)] pure fn tttp(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.z) }
+    #[inline(always)] pure fn ttps(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.z, self.x) }
+    #[inline(always)] pure fn ttpt(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.z, self.y) }
+    #[inline(always)] pure fn ttpp(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.z, self.z) }
+    #[inline(always)] pure fn tpss(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.x, self.x) }
+    #[inline(always)] pure fn tpst(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.x, self.y) }
+    #[inline(always)] pure fn tpsp(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.x, self.z) }
+    #[inline(always)] pure fn tpts(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.y, self.x) }
+    #[inline(always)] pure fn tptt(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.y, self.y) }
+    #[inline(always)] pure fn tptp(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.y, self.z) }
+    #[inline(always)] pure fn tpps(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.z, self.x) }
+    #[inline(always)] pure fn tppt(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.z, self.y) }
+    #[inline(always)] pure fn tppp(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.z, self.z) }
+    #[inline(always)] pure fn psss(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.x, self.x) }
+    #[inline(always)] pure fn psst(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.x, self.y) }
+    #[inline(always)] pure fn pssp(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.x, self.z) }
+    #[inline(always)] pure fn psts(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.y, self.x) }
+    #[inline(always)] pure fn pstt(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.y, self.y) }
+    #[inline(always)] pure fn pstp(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.y, self.z) }
+    #[inline(always)] pure fn psps(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.z, self.x) }
+    #[inline(always)] pure fn pspt(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.z, self.y) }
+    #[inline(always)] pure fn pspp(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.z, self.z) }
+    #[inline(always)] pure fn ptss(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.x, self.x) }
+    #[inline(always)] pure fn ptst(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.x, self.y) }
+    #[inline(always)] pure fn ptsp(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.x, self.z) }
+    #[inline(always)] pure fn ptts(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.y, self.x) }
+    #[inline(always)] pure fn pttt(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.y, self.y) }
+    #[inline(always)] pure fn pttp(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.y, self.z) }
+    #[inline(always)] pure fn ptps(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.z, self.x) }
+    #[inline(always)] pure fn ptpt(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.z, self.y) }
+    #[inline(always)] pure fn ptpp(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.z, self.z) }
+    #[inline(always)] pure fn ppss(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.x, self.x) }
+    #[inline(always)] pure fn ppst(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.x, self.y) }
+    #[inline(always)] pure fn ppsp(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.x, self.z) }
+    #[inline(always)] pure fn ppts(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.y, self.x) }
+    #[inline(always)] pure fn pptt(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.y, self.y) }
+    #[inline(always)] pure fn pptp(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.y, self.z) }
+    #[inline(always)] pure fn ppps(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.z, self.x) }
+    #[inline(always)] pure fn pppt(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.z, self.y) }
+    #[inline(always)] pure fn pppp(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.z, self.z) }
+    
+    #[inline(always)] fn set_st(&mut self, v: &Vec2<T>) { self.x = v.x; self.y = v.y; }
+    #[inline(always)] fn set_sp(&mut self, v: &Vec2<T>) { self.x = v.x; self.z = v.y; }
+    #[inline(always)] fn set_ts(&mut self, v: &Vec2<T>) { self.y = v.x; self.x = v.y; }
+    #[inline(always)] fn set_tp(&mut self, v: &Vec2<T>) { self.y = v.x; self.z = v.y; }
+    #[inline(always)] fn set_ps(&mut self, v: &Vec2<T>) { self.z = v.x; self.x = v.y; }
+    #[inline(always)] fn set_pt(&mut self, v: &Vec2<T>) { self.z = v.x; self.y = v.y; }
+    #[inline(always)] fn set_stp(&mut self, v: &Vec3<T>) { self.x = v.x; self.y = v.y; self.z = v.z; }
+    #[inline(always)] fn set_spt(&mut self, v: &Vec3<T>) { self.x = v.x; self.z = v.y; self.y = v.z; }
+    #[inline(always)] fn set_tsp(&mut self, v: &Vec3<T>) { self.y = v.x; self.x = v.y; self.z = v.z; }
+    #[inline(always)] fn set_tps(&mut self, v: &Vec3<T>) { self.y = v.x; self.z = v.y; self.x = v.z; }
+    #[inline(always)] fn set_pst(&mut self, v: &Vec3<T>) { self.z = v.x; self.x = v.y; self.y = v.z; }
+    #[inline(always)] fn set_pts(&mut self, v: &Vec3<T>) { self.z = v.x; self.y = v.y; self.x = v.z; }
+}
+
+pub impl<T:Copy> Vec4<T> {
+    #[inline(always)] pure fn xx(&self) -> Vec2<T> { Vector2::new(self.x, self.x) }
+    #[inline(always)] pure fn xy(&self) -> Vec2<T> { Vector2::new(self.x, self.y) }
+    #[inline(always)] pure fn xz(&self) -> Vec2<T> { Vector2::new(self.x, self.z) }
+    #[inline(always)] pure fn xw(&self) -> Vec2<T> { Vector2::new(self.x, self.w) }
+    #[inline(always)] pure fn yx(&self) -> Vec2<T> { Vector2::new(self.y, self.x) }
+    #[inline(always)] pure fn yy(&self) -> Vec2<T> { Vector2::new(self.y, self.y) }
+    #[inline(always)] pure fn yz(&self) -> Vec2<T> { Vector2::new(self.y, self.z) }
+    #[inline(always)] pure fn yw(&self) -> Vec2<T> { Vector2::new(self.y, self.w) }
+    #[inline(always)] pure fn zx(&self) -> Vec2<T> { Vector2::new(self.z, self.x) }
+    #[inline(always)] pure fn zy(&self) -> Vec2<T> { Vector2::new(self.z, self.y) }
+    #[inline(always)] pure fn zz(&self) -> Vec2<T> { Vector2::new(self.z, self.z) }
+    #[inline(always)] pure fn zw(&self) -> Vec2<T> { Vector2::new(self.z, self.w) }
+    #[inline(always)] pure fn wx(&self) -> Vec2<T> { Vector2::new(self.w, self.x) }
+    #[inline(always)] pure fn wy(&self) -> Vec2<T> { Vector2::new(self.w, self.y) }
+    #[inline(always)] pure fn wz(&self) -> Vec2<T> { Vector2::new(self.w, self.z) }
+    #[inline(always)] pure fn ww(&self) -> Vec2<T> { Vector2::new(self.w, self.w) }
+    #[inline(always)] pure fn xxx(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.x) }
+    #[inline(always)] pure fn xxy(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.y) }
+    #[inline(always)] pure fn xxz(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.z) }
+    #[inline(always)] pure fn xxw(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.w) }
+    #[inline(always)] pure fn xyx(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.x) }
+    #[inline(always)] pure fn xyy(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.y) }
+    #[inline(always)] pure fn xyz(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.z) }
+    #[inline(always)] pure fn xyw(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.w) }
+    #[inline(always)] pure fn xzx(&self) -> Vec3<T> { Vector3::new(self.x, self.z, self.x) }
+    #[inline(always)] pure fn xzy(&self) -> Vec3<T> { Vector3::new(self.x, self.z, self.y) }
+    #[inline(always)] pure fn xzz(&self) -> Vec3<T> { Vector3::new(self.x, self.z, self.z) }
+    #[inline(always)] pure fn xzw(&self) -> Vec3<T> { Vector3::new(self.x, self.z, self.w) }
+    #[inline(always)] pure fn xwx(&self) -> Vec3<T> { Vector3::new(self.x, self.w, self.x) }
+    #[inline(always)] pure fn xwy(&self) -> Vec3<T> { Vector3::new(self.x, self.w, self.y) }
+    #[inline(always)] pure fn xwz(&self) -> Vec3<T> { Vector3::new(self.x, self.w, self.z) }
+    #[inline(always)] pure fn xww(&self) -> Vec3<T> { Vector3::new(self.x, self.w, self.w) }
+    #[inline(always)] pure fn yxx(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.x) }
+    #[inline(always)] pure fn yxy(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.y) }
+    #[inline(always)] pure fn yxz(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.z) }
+    #[inline(always)] pure fn yxw(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.w) }
+    #[inline(always)] pure fn yyx(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.x) }
+    #[inline(always)] pure fn yyy(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.y) }
+    #[inline(always)] pure fn yyz(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.z) }
+    #[inline(always)] pure fn yyw(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.w) }
+    #[inline(always)] pure fn yzx(&self) -> Vec3<T> { Vector3::new(self.y, self.z, self.x) }
+    #[inline(always)] pure fn yzy(&self) -> Vec3<T> { Vector3::new(self.y, self.z, self.y) }
+    #[inline(always)] pure fn yzz(&self) -> Vec3<T> { Vector3::new(self.y, self.z, self.z) }
+    #[inline(always)] pure fn yzw(&self) -> Vec3<T> { Vector3::new(self.y, self.z, self.w) }
+    #[inline(always)] pure fn ywx(&self) -> Vec3<T> { Vector3::new(self.y, self.w, self.x) }
+    #[inline(always)] pure fn ywy(&self) -> Vec3<T> { Vector3::new(self.y, self.w, self.y) }
+    #[inline(always)] pure fn ywz(&self) -> Vec3<T> { Vector3::new(self.y, self.w, self.z) }
+    #[inline(always)] pure fn yww(&self) -> Vec3<T> { Vector3::new(self.y, self.w, self.w) }
+    #[inline(always)] pure fn zxx(&self) -> Vec3<T> { Vector3::new(self.z, self.x, self.x) }
+    #[inline(always)] pure fn zxy(&self) -> Vec3<T> { Vector3::new(self.z, self.x, self.y) }
+    #[inline(always)] pure fn zxz(&self) -> Vec3<T> { Vector3::new(self.z, self.x, self.z) }
+    #[inline(always)] pure fn zxw(&self) -> Vec3<T> { Vector3::new(self.z, self.x, self.w) }
+    #[inline(always)] pure fn zyx(&self) -> Vec3<T> { Vector3::new(self.z, self.y, self.x) }
+    #[inline(always)] pure fn zyy(&self) -> Vec3<T> { Vector3::new(self.z, self.y, self.y) }
+    #[inline(always)] pure fn zyz(&self) -> Vec3<T> { Vector3::new(self.z, self.y, self.z) }
+    #[inline(always)] pure fn zyw(&self) -> Vec3<T> { Vector3::new(self.z, self.y, self.w) }
+    #[inline(always)] pure fn zzx(&self) -> Vec3<T> { Vector3::new(self.z, self.z, self.x) }
+    #[inline(always)] pure fn zzy(&self) -> Vec3<T> { Vector3::new(self.z, self.z, self.y) }
+    #[inline(always)] pure fn zzz(&self) -> Vec3<T> { Vector3::new(self.z, self.z, self.z) }
+    #[inline(always)] pure fn zzw(&self) -> Vec3<T> { Vector3::new(self.z, self.z, self.w) }
+    #[inline(always)] pure fn zwx(&self) -> Vec3<T> { Vector3::new(self.z, self.w, self.x) }
+    #[inline(always)] pure fn zwy(&self) -> Vec3<T> { Vector3::new(self.z, self.w, self.y) }
+    #[inline(always)] pure fn zwz(&self) -> Vec3<T> { Vector3::new(self.z, self.w, self.z) }
+    #[inline(always)] pure fn zww(&self) -> Vec3<T> { Vector3::new(self.z, self.w, self.w) }
+    #[inline(always)] pure fn wxx(&self) -> Vec3<T> { Vector3::new(self.w, self.x, self.x) }
+    #[inline(always)] pure fn wxy(&self) -> Vec3<T> { Vector3::new(self.w, self.x, self.y) }
+    #[inline(always)] pure fn wxz(&self) -> Vec3<T> { Vector3::new(self.w, self.x, self.z) }
+    #[inline(always)] pure fn wxw(&self) -> Vec3<T> { Vector3::new(self.w, self.x, self.w) }
+    #[inline(always)] pure fn wyx(&self) -> Vec3<T> { Vector3::new(self.w, self.y, self.x) }
+    #[inline(always)] pure fn wyy(&self) -> Vec3<T> { Vector3::new(self.w, self.y, self.y) }
+    #[inline(always)] pure fn wyz(&self) -> Vec3<T> { Vector3::new(self.w, self.y, self.z) }
+    #[inline(always)] pure fn wyw(&self) -> Vec3<T> { Vector3::new(self.w, self.y, self.w) }
+    #[inline(always)] pure fn wzx(&self) -> Vec3<T> { Vector3::new(self.w, self.z, self.x) }
+    #[inline(always)] pure fn wzy(&self) -> Vec3<T> { Vector3::new(self.w, self.z, self.y) }
+    #[inline(always)] pure fn wzz(&self) -> Vec3<T> { Vector3::new(self.w, self.z, self.z) }
+    #[inline(always)] pure fn wzw(&self) -> Vec3<T> { Vector3::new(self.w, self.z, self.w) }
+    #[inline(always)] pure fn wwx(&self) -> Vec3<T> { Vector3::new(self.w, self.w, self.x) }
+    #[inline(always)] pure fn wwy(&self) -> Vec3<T> { Vector3::new(self.w, self.w, self.y) }
+    #[inline(always)] pure fn wwz(&self) -> Vec3<T> { Vector3::new(self.w, self.w, self.z) }
+    #[inline(always)] pure fn www(&self) -> Vec3<T> { Vector3::new(self.w, self.w, self.w) }
+    #[inline(always)] pure fn xxxx(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.x) }
+    #[inline(always)] pure fn xxxy(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.y) }
+    #[inline(always)] pure fn xxxz(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.z) }
+    #[inline(always)] pure fn xxxw(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.w) }
+    #[inline(always)] pure fn xxyx(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.x) }
+    #[inline(always)] pure fn xxyy(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.y) }
+    #[inline(always)] pure fn xxyz(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.z) }
+    #[inline(always)] pure fn xxyw(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.w) }
+    #[inline(always)] pure fn xxzx(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.z, self.x) }
+    #[inline(always)] pure fn xxzy(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.z, self.y) }
+    #[inline(always)] pure fn xxzz(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.z, self.z) }
+    #[inline(always)] pure fn xxzw(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.z, self.w) }
+    #[inline(always)] pure fn xxwx(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.w, self.x) }
+    #[inline(always)] pure fn xxwy(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.w, self.y) }
+    #[inline(always)] pure fn xxwz(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.w, self.z) }
+    #[inline(always)] pure fn xxww(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.w, self.w) }
+    #[inline(always)] pure fn xyxx(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.x) }
+    #[inline(always)] pure fn xyxy(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.y) }
+    #[inline(always)] pure fn xyxz(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.z) }
+    #[inline(always)] pure fn xyxw(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.w) }
+    #[inline(always)] pure fn xyyx(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.x) }
+    #[inline(always)] pure fn xyyy(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.y) }
+    #[inline(always)] pure fn xyyz(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.z) }
+    #[inline(always)] pure fn xyyw(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.w) }
+    #[inline(always)] pure fn xyzx(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.z, self.x) }
+    #[inline(always)] pure fn xyzy(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.z, self.y) }
+    #[inline(always)] pure fn xyzz(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.z, self.z) }
+    #[inline(always)] pure fn xyzw(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.z, self.w) }
+    #[inline(always)] pure fn xywx(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.w, self.x) }
+    #[inline(always)] pure fn xywy(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.w, self.y) }
+    #[inline(always)] pure fn xywz(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.w, self.z) }
+    #[inline(always)] pure fn xyww(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.w, self.w) }
+    #[inline(always)] pure fn xzxx(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.x, self.x) }
+    #[inline(always)] pure fn xzxy(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.x, self.y) }
+    #[inline(always)] pure fn xzxz(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.x, self.z) }
+    #[inline(always)] pure fn xzxw(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.x, self.w) }
+    #[inline(always)] pure fn xzyx(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.y, self.x) }
+    #[inline(always)] pure fn xzyy(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.y, self.y) }
+    #[inline(always)] pure fn xzyz(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.y, self.z) }
+    #[inline(always)] pure fn xzyw(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.y, self.w) }
+    #[inline(always)] pure fn xzzx(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.z, self.x) }
+    #[inline(always)] pure fn xzzy(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.z, self.y) }
+    #[inline(always)] pure fn xzzz(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.z, self.z) }
+    #[inline(always)] pure fn xzzw(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.z, self.w) }
+    #[inline(always)] pure fn xzwx(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.w, self.x) }
+    #[inline(always)] pure fn xzwy(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.w, self.y) }
+    #[inline(always)] pure fn xzwz(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.w, self.z) }
+    #[inline(always)] pure fn xzww(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.w, self.w) }
+    #[inline(always)] pure fn xwxx(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.x, self.x) }
+    #[inline(always)] pure fn xwxy(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.x, self.y) }
+    #[inline(always)] pure fn xwxz(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.x, self.z) }
+    #[inline(always)] pure fn xwxw(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.x, self.w) }
+    #[inline(always)] pure fn xwyx(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.y, self.x) }
+    #[inline(always)] pure fn xwyy(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.y, self.y) }
+    #[inline(always)] pure fn xwyz(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.y, self.z) }
+    #[inline(always)] pure fn xwyw(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.y, self.w) }
+    #[inline(always)] pure fn xwzx(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.z, self.x) }
+    #[inline(always)] pure fn xwzy(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.z, self.y) }
+    #[inline(always)] pure fn xwzz(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.z, self.z) }
+    #[inline(always)] pure fn xwzw(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.z, self.w) }
+    #[inline(always)] pure fn xwwx(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.w, self.x) }
+    #[inline(always)] pure fn xwwy(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.w, self.y) }
+    #[inline(always)] pure fn xwwz(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.w, self.z) }
+    #[inline(always)] pure fn xwww(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.w, self.w) }
+    #[inline(always)] pure fn yxxx(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.x) }
+    #[inline(always)] pure fn yxxy(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.y) }
+    #[inline(always)] pure fn yxxz(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.z) }
+    #[inline(always)] pure fn yxxw(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.w) }
+    #[inline(always)] pure fn yxyx(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.x) }
+    #[inline(always)] pure fn yxyy(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.y) }
+    #[inline(always)] pure fn yxyz(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.z) }
+    #[inline(always)] pure fn yxyw(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.w) }
+    #[inline(always)] pure fn yxzx(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.z, self.x) }
+    #[inline(always)] pure fn yxzy(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.z, self.y) }
+    #[inline(always)] pure fn yxzz(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.z, self.z) }
+    #[inline(always)] pure fn yxzw(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.z, self.w) }
+    #[inline(always)] pure fn yxwx(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.w, self.x) }
+    #[inline(always)] pure fn yxwy(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.w, self.y) }
+    #[inline(always)] pure fn yxwz(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.w, self.z) }
+    #[inline(always)] pure fn yxww(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.w, self.w) }
+    #[inline(always)] pure fn yyxx(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.x) }
+    #[inline(always)] pure fn yyxy(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.y) }
+    #[inline(always)] pure fn yyxz(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.z) }
+    #[inline(always)] pure fn yyxw(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.w) }
+    #[inline(always)] pure fn yyyx(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.x) }
+    #[inline(always)] pure fn yyyy(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.y) }
+    #[inline(always)] pure fn yyyz(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.z) }
+    #[inline(always)] pure fn yyyw(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.w) }
+    #[inline(always)] pure fn yyzx(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.z, self.x) }
+    #[inline(always)] pure fn yyzy(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.z, self.y) }
+    #[inline(always)] pure fn yyzz(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.z, self.z) }
+    #[inline(always)] pure fn yyzw(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.z, self.w) }
+    #[inline(always)] pure fn yywx(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.w, self.x) }
+    #[inline(always)] pure fn yywy(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.w, self.y) }
+    #[inline(always)] pure fn yywz(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.w, self.z) }
+    #[inline(always)] pure fn yyww(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.w, self.w) }
+    #[inline(always)] pure fn yzxx(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.x, self.x) }
+    #[inline(always)] pure fn yzxy(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.x, self.y) }
+    #[inline(always)] pure fn yzxz(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.x, self.z) }
+    #[inline(always)] pure fn yzxw(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.x, self.w) }
+    #[inline(always)] pure fn yzyx(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.y, self.x) }
+    #[inline(always)] pure fn yzyy(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.y, self.y) }
+    #[inline(always)] pure fn yzyz(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.y, self.z) }
+    #[inline(always)] pure fn yzyw(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.y, self.w) }
+    #[inline(always)] pure fn yzzx(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.z, self.x) }
+    #[inline(always)] pure fn yzzy(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.z, self.y) }
+    #[inline(always)] pure fn yzzz(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.z, self.z) }
+    #[inline(always)] pure fn yzzw(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.z, self.w) }
+    #[inline(always)] pure fn yzwx(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.w, self.x) }
+    #[inline(always)] pure fn yzwy(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.w, self.y) }
+    #[inline(always)] pure fn yzwz(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.w, self.z) }
+    #[inline(always)] pure fn yzww(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.w, self.w) }
+    #[inline(always)] pure fn ywxx(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.x, self.x) }
+    #[inline(always)] pure fn ywxy(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.x, self.y) }
+    #[inline(always)] pure fn ywxz(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.x, self.z) }
+    #[inline(always)] pure fn ywxw(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.x, self.w) }
+    #[inline(always)] pure fn ywyx(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.y, self.x) }
+    #[inline(always)] pure fn ywyy(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.y, self.y) }
+    #[inline(always)] pure fn ywyz(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.y, self.z) }
+    #[inline(always)] pure fn ywyw(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.y, self.w) }
+    #[inline(always)] pure fn ywzx(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.z, self.x) }
+    #[inline(always)] pure fn ywzy(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.z, self.y) }
+    #[inline(always)] pure fn ywzz(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.z, self.z) }
+    #[inline(always)] pure fn ywzw(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.z, self.w) }
+    #[inline(always)] pure fn ywwx(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.w, self.x) }
+    #[inline(always)] pure fn ywwy(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.w, self.y) }
+    #[inline(always)] pure fn ywwz(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.w, self.z) }
+    #[inline(always)] pure fn ywww(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.w, self.w) }
+    #[inline(always)] pure fn zxxx(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.x, self.x) }
+    #[inline(always)] pure fn zxxy(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.x, self.y) }
+    #[inline(always)] pure fn zxxz(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.x, self.z) }
+    #[inline(always)] pure fn zxxw(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.x, self.w) }
+    #[inline(always)] pure fn zxyx(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.y, self.x) }
+    #[inline(always)] pure fn zxyy(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.y, self.y) }
+    #[inline(always)] pure fn zxyz(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.y, self.z) }
+    #[inline(always)] pure fn zxyw(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.y, self.w) }
+    #[inline(always)] pure fn zxzx(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.z, self.x) }
+    #[inline(always)] pure fn zxzy(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.z, self.y) }
+    #[inline(always)] pure fn zxzz(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.z, self.z) }
+    #[inline(always)] pure fn zxzw(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.z, self.w) }
+    #[inline(always)] pure fn zxwx(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.w, self.x) }
+    #[inline(always)] pure fn zxwy(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.w, self.y) }
+    #[inline(always)] pure fn zxwz(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.w, self.z) }
+    #[inline(always)] pure fn zxww(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.w, self.w) }
+    #[inline(always)] pure fn zyxx(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.x, self.x) }
+    #[inline(always)] pure fn zyxy(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.x, self.y) }
+    #[inline(always)] pure fn zyxz(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.x, self.z) }
+    #[inline(always)] pure fn zyxw(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.x, self.w) }
+    #[inline(always)] pure fn zyyx(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.y, self.x) }
+    #[inline(always)] pure fn zyyy(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.y, self.y) }
+    #[inline(always)] pure fn zyyz(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.y, self.z) }
+    #[inline(always)] pure fn zyyw(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.y, self.w) }
+    #[inline(always)] pure fn zyzx(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.z, self.x) }
+    #[inline(always)] pure fn zyzy(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.z, self.y) }
+    #[inline(always)] pure fn zyzz(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.z, self.z) }
+    #[inline(always)] pure fn zyzw(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.z, self.w) }
+    #[inline(always)] pure fn zywx(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.w, self.x) }
+    #[inline(always)] pure fn zywy(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.w, self.y) }
+    #[inline(always)] pure fn zywz(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.w, self.z) }
+    #[inline(always)] pure fn zyww(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.w, self.w) }
+    #[inline(always)] pure fn zzxx(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.x, self.x) }
+    #[inline(always)] pure fn zzxy(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.x, self.y) }
+    #[inline(always)] pure fn zzxz(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.x, self.z) }
+    #[inline(always)] pure fn zzxw(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.x, self.w) }
+    #[inline(always)] pure fn zzyx(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.y, self.x) }
+    #[inline(always)] pure fn zzyy(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.y, self.y) }
+    #[inline(always)] pure fn zzyz(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.y, self.z) }
+    #[inline(always)] pure fn zzyw(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.y, self.w) }
+    #[inline(always)] pure fn zzzx(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.z, self.x) }
+    #[inline(always)] pure fn zzzy(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.z, self.y) }
+    #[inline(always)] pure fn zzzz(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.z, self.z) }
+    #[inline(always)] pure fn zzzw(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.z, self.w) }
+    #[inline(always)] pure fn zzwx(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.w, self.x) }
+    #[inline(always)] pure fn zzwy(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.w, self.y) }
+    #[inline(always)] pure fn zzwz(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.w, self.z) }
+    #[inline(always)] pure fn zzww(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.w, self.w) }
+    #[inline(always)] pure fn zwxx(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.x, self.x) }
+    #[inline(always)] pure fn zwxy(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.x, self.y) }
+    #[inline(always)] pure fn zwxz(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.x, self.z) }
+    #[inline(always)] pure fn zwxw(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.x, self.w) }
+    #[inline(always)] pure fn zwyx(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.y, self.x) }
+    #[inline(always)] pure fn zwyy(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.y, self.y) }
+    #[inline(always)] pure fn zwyz(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.y, self.z) }
+    #[inline(always)] pure fn zwyw(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.y, self.w) }
+    #[inline(always)] pure fn zwzx(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.z, self.x) }
+    #[inline(always)] pure fn zwzy(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.z, self.y) }
+    #[inline(always)] pure fn zwzz(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.z, self.z) }
+    #[inline(always)] pure fn zwzw(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.z, self.w) }
+    #[inline(always)] pure fn zwwx(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.w, self.x) }
+    #[inline(always)] pure fn zwwy(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.w, self.y) }
+    #[inline(always)] pure fn zwwz(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.w, self.z) }
+    #[inline(always)] pure fn zwww(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.w, self.w) }
+    #[inline(always)] pure fn wxxx(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.x, self.x) }
+    #[inline(always)] pure fn wxxy(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.x, self.y) }
+    #[inline(always)] pure fn wxxz(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.x, self.z) }
+    #[inline(always)] pure fn wxxw(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.x, self.w) }
+    #[inline(always)] pure fn wxyx(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.y, self.x) }
+    #[inline(always)] pure fn wxyy(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.y, self.y) }
+    #[inline(always)] pure fn wxyz(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.y, self.z) }
+    #[inline(always)] pure fn wxyw(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.y, self.w) }
+    #[inline(always)] pure fn wxzx(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.z, self.x) }
+    #[inline(always)] pure fn wxzy(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.z, self.y) }
+    #[inline(always)] pure fn wxzz(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.z, self.z) }
+    #[inline(always)] pure fn wxzw(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.z, self.w) }
+    #[inline(always)] pure fn wxwx(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.w, self.x) }
+    #[inline(always)] pure fn wxwy(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.w, self.y) }
+    #[inline(always)] pure fn wxwz(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.w, self.z) }
+    #[inline(always)] pure fn wxww(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.w, self.w) }
+    #[inline(always)] pure fn wyxx(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.x, self.x) }
+    #[inline(always)] pure fn wyxy(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.x, self.y) }
+    #[inline(always)] pure fn wyxz(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.x, self.z) }
+    #[inline(always)] pure fn wyxw(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.x, self.w) }
+    #[inline(always)] pure fn wyyx(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.y, self.x) }
+    #[inline(always)] pure fn wyyy(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.y, self.y) }
+    #[inline(always)] pure fn wyyz(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.y, self.z) }
+    #[inline(always)] pure fn wyyw(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.y, self.w) }
+    #[inline(always)] pure fn wyzx(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.z, self.x) }
+    #[inline(always)] pure fn wyzy(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.z, self.y) }
+    #[inline(always)] pure fn wyzz(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.z, self.z) }
+    #[inline(always)] pure fn wyzw(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.z, self.w) }
+    #[inline(always)] pure fn wywx(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.w, self.x) }
+    #[inline(always)] pure fn wywy(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.w, self.y) }
+    #[inline(always)] pure fn wywz(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.w, self.z) }
+    #[inline(always)] pure fn wyww(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.w, self.w) }
+    #[inline(always)] pure fn wzxx(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.x, self.x) }
+    #[inline(always)] pure fn wzxy(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.x, self.y) }
+    #[inline(always)] pure fn wzxz(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.x, self.z) }
+    #[inline(always)] pure fn wzxw(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.x, self.w) }
+    #[inline(always)] pure fn wzyx(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.y, self.x) }
+    #[inline(always)] pure fn wzyy(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.y, self.y) }
+    #[inline(always)] pure fn wzyz(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.y, self.z) }
+    #[inline(always)] pure fn wzyw(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.y, self.w) }
+    #[inline(always)] pure fn wzzx(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.z, self.x) }
+    #[inline(always)] pure fn wzzy(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.z, self.y) }
+    #[inline(always)] pure fn wzzz(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.z, self.z) }
+    #[inline(always)] pure fn wzzw(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.z, self.w) }
+    #[inline(always)] pure fn wzwx(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.w, self.x) }
+    #[inline(always)] pure fn wzwy(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.w, self.y) }
+    #[inline(always)] pure fn wzwz(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.w, self.z) }
+    #[inline(always)] pure fn wzww(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.w, self.w) }
+    #[inline(always)] pure fn wwxx(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.x, self.x) }
+    #[inline(always)] pure fn wwxy(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.x, self.y) }
+    #[inline(always)] pure fn wwxz(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.x, self.z) }
+    #[inline(always)] pure fn wwxw(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.x, self.w) }
+    #[inline(always)] pure fn wwyx(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.y, self.x) }
+    #[inline(always)] pure fn wwyy(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.y, self.y) }
+    #[inline(always)] pure fn wwyz(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.y, self.z) }
+    #[inline(always)] pure fn wwyw(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.y, self.w) }
+    #[inline(always)] pure fn wwzx(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.z, self.x) }
+    #[inline(always)] pure fn wwzy(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.z, self.y) }
+    #[inline(always)] pure fn wwzz(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.z, self.z) }
+    #[inline(always)] pure fn wwzw(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.z, self.w) }
+    #[inline(always)] pure fn wwwx(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.w, self.x) }
+    #[inline(always)] pure fn wwwy(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.w, self.y) }
+    #[inline(always)] pure fn wwwz(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.w, self.z) }
+    #[inline(always)] pure fn wwww(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.w, self.w) }
+    
+    #[inline(always)] fn set_xy(&mut self, v: &Vec2<T>) { self.x = v.x; self.y = v.y; }
+    #[inline(always)] fn set_xz(&mut self, v: &Vec2<T>) { self.x = v.x; self.z = v.y; }
+    #[inline(always)] fn set_xw(&mut self, v: &Vec2<T>) { self.x = v.x; self.w = v.y; }
+    #[inline(always)] fn set_yx(&mut self, v: &Vec2<T>) { self.y = v.x; self.x = v.y; }
+    #[inline(always)] fn set_yz(&mut self, v: &Vec2<T>) { self.y = v.x; self.z = v.y; }
+    #[inline(always)] fn set_yw(&mut self, v: &Vec2<T>) { self.y = v.x; self.w = v.y; }
+    #[inline(always)] fn set_zx(&mut self, v: &Vec2<T>) { self.z = v.x; self.x = v.y; }
+    #[inline(always)] fn set_zy(&mut self, v: &Vec2<T>) { self.z = v.x; self.y = v.y; }
+    #[inline(always)] fn set_zw(&mut self, v: &Vec2<T>) { self.z = v.x; self.w = v.y; }
+    #[inline(always)] fn set_wx(&mut self, v: &Vec2<T>) { self.w = v.x; self.x = v.y; }
+    #[inline(always)] fn set_wy(&mut self, v: &Vec2<T>) { self.w = v.x; self.y = v.y; }
+    #[inline(always)] fn set_wz(&mut self, v: &Vec2<T>) { self.w = v.x; self.z = v.y; }
+    #[inline(always)] fn set_xyz(&mut self, v: &Vec3<T>) { self.x = v.x; self.y = v.y; self.z = v.z; }
+    #[inline(always)] fn set_xyw(&mut self, v: &Vec3<T>) { self.x = v.x; self.y = v.y; self.w = v.z; }
+    #[inline(always)] fn set_xzy(&mut self, v: &Vec3<T>) { self.x = v.x; self.z = v.y; self.y = v.z; }
+    #[inline(always)] fn set_xzw(&mut self, v: &Vec3<T>) { self.x = v.x; self.z = v.y; self.w = v.z; }
+    #[inline(always)] fn set_xwy(&mut self, v: &Vec3<T>) { self.x = v.x; self.w = v.y; self.y = v.z; }
+    #[inline(always)] fn set_xwz(&mut self, v: &Vec3<T>) { self.x = v.x; self.w = v.y; self.z = v.z; }
+    #[inline(always)] fn set_yxz(&mut self, v: &Vec3<T>) { self.y = v.x; self.x = v.y; self.z = v.z; }
+    #[inline(always)] fn set_yxw(&mut self, v: &Vec3<T>) { self.y = v.x; self.x = v.y; self.w = v.z; }
+    #[inline(always)] fn set_yzx(&mut self, v: &Vec3<T>) { self.y = v.x; self.z = v.y; self.x = v.z; }
+    #[inline(always)] fn set_yzw(&mut self, v: &Vec3<T>) { self.y = v.x; self.z = v.y; self.w = v.z; }
+    #[inline(always)] fn set_ywx(&mut self, v: &Vec3<T>) { self.y = v.x; self.w = v.y; self.x = v.z; }
+    #[inline(always)] fn set_ywz(&mut self, v: &Vec3<T>) { self.y = v.x; self.w = v.y; self.z = v.z; }
+    #[inline(always)] fn set_zxy(&mut self, v: &Vec3<T>) { self.z = v.x; self.x = v.y; self.y = v.z; }
+    #[inline(always)] fn set_zxw(&mut self, v: &Vec3<T>) { self.z = v.x; self.x = v.y; self.w = v.z; }
+    #[inline(always)] fn set_zyx(&mut self, v: &Vec3<T>) { self.z = v.x; self.y = v.y; self.x = v.z; }
+    #[inline(always)] fn set_zyw(&mut self, v: &Vec3<T>) { self.z = v.x; self.y = v.y; self.w = v.z; }
+    #[inline(always)] fn set_zwx(&mut self, v: &Vec3<T>) { self.z = v.x; self.w = v.y; self.x = v.z; }
+    #[inline(always)] fn set_zwy(&mut self, v: &Vec3<T>) { self.z = v.x; self.w = v.y; self.y = v.z; }
+    #[inline(always)] fn set_wxy(&mut self, v: &Vec3<T>) { self.w = v.x; self.x = v.y; self.y = v.z; }
+    #[inline(always)] fn set_wxz(&mut self, v: &Vec3<T>) { self.w = v.x; self.x = v.y; self.z = v.z; }
+    #[inline(always)] fn set_wyx(&mut self, v: &Vec3<T>) { self.w = v.x; self.y = v.y; self.x = v.z; }
+    #[inline(always)] fn set_wyz(&mut self, v: &Vec3<T>) { self.w = v.x; self.y = v.y; self.z = v.z; }
+    #[inline(always)] fn set_wzx(&mut self, v: &Vec3<T>) { self.w = v.x; self.z = v.y; self.x = v.z; }
+    #[inline(always)] fn set_wzy(&mut self, v: &Vec3<T>) { self.w = v.x; self.z = v.y; self.y = v.z; }
+    #[inline(always)] fn set_xyzw(&mut self, v: &Vec4<T>) { self.x = v.x; self.y = v.y; self.z = v.z; self.w = v.w; }
+    #[inline(always)] fn set_xywz(&mut self, v: &Vec4<T>) { self.x = v.x; self.y = v.y; self.w = v.z; self.z = v.w; }
+    #[inline(always)] fn set_xzyw(&mut self, v: &Vec4<T>) { self.x = v.x; self.z = v.y; self.y = v.z; self.w = v.w; }
+    #[inline(always)] fn set_xzwy(&mut self, v: &Vec4<T>) { self.x = v.x; self.z = v.y; self.w = v.z; self.y = v.w; }
+    #[inline(always)] fn set_xwyz(&mut self, v: &Vec4<T>) { self.x = v.x; self.w = v.y; self.y = v.z; self.z = v.w; }
+    #[inline(always)] fn set_xwzy(&mut self, v: &Vec4<T>) { self.x = v.x; self.w = v.y; self.z = v.z; self.y = v.w; }
+    #[inline(always)] fn set_yxzw(&mut self, v: &Vec4<T>) { self.y = v.x; self.x = v.y; self.z = v.z; self.w = v.w; }
+    #[inline(always)] fn set_yxwz(&mut self, v: &Vec4<T>) { self.y = v.x; self.x = v.y; self.w = v.z; self.z = v.w; }
+    #[inline(always)] fn set_yzxw(&mut self, v: &Vec4<T>) { self.y = v.x; self.z = v.y; self.x = v.z; self.w = v.w; }
+    #[inline(always)] fn set_yzwx(&mut self, v: &Vec4<T>) { self.y = v.x; self.z = v.y; self.w = v.z; self.x = v.w; }
+    #[inline(always)] fn set_ywxz(&mut self, v: &Vec4<T>) { self.y = v.x; self.w = v.y; self.x = v.z; self.z = v.w; }
+    #[inline(always)] fn set_ywzx(&mut self, v: &Vec4<T>) { self.y = v.x; self.w = v.y; self.z = v.z; self.x = v.w; }
+    #[inline(always)] fn set_zxyw(&mut self, v: &Vec4<T>) { self.z = v.x; self.x = v.y; self.y = v.z; self.w = v.w; }
+    #[inline(always)] fn set_zxwy(&mut self, v: &Vec4<T>) { self.z = v.x; self.x = v.y; self.w = v.z; self.y = v.w; }
+    #[inline(always)] fn set_zyxw(&mut self, v: &Vec4<T>) { self.z = v.x; self.y = v.y; self.x = v.z; self.w = v.w; }
+    #[inline(always)] fn set_zywx(&mut self, v: &Vec4<T>) { self.z = v.x; self.y = v.y; self.w = v.z; self.x = v.w; }
+    #[inline(always)] fn set_zwxy(&mut self, v: &Vec4<T>) { self.z = v.x; self.w = v.y; self.x = v.z; self.y = v.w; }
+    #[inline(always)] fn set_zwyx(&mut self, v: &Vec4<T>) { self.z = v.x; self.w = v.y; self.y = v.z; self.x = v.w; }
+    #[inline(always)] fn set_wxyz(&mut self, v: &Vec4<T>) { self.w = v.x; self.x = v.y; self.y = v.z; self.z = v.w; }
+    #[inline(always)] fn set_wxzy(&mut self, v: &Vec4<T>) { self.w = v.x; self.x = v.y; self.z = v.z; self.y = v.w; }
+    #[inline(always)] fn set_wyxz(&mut self, v: &Vec4<T>) { self.w = v.x; self.y = v.y; self.x = v.z; self.z = v.w; }
+    #[inline(always)] fn set_wyzx(&mut self, v: &Vec4<T>) { self.w = v.x; self.y = v.y; self.z = v.z; self.x = v.w; }
+    #[inline(always)] fn set_wzxy(&mut self, v: &Vec4<T>) { self.w = v.x; self.z = v.y; self.x = v.z; self.y = v.w; }
+    #[inline(always)] fn set_wzyx(&mut self, v: &Vec4<T>) { self.w = v.x; self.z = v.y; self.y = v.z; self.x = v.w; }
+}
+
+pub impl<T:Copy> Vec4<T> {
+    #[inline(always)] pure fn rr(&self) -> Vec2<T> { Vector2::new(self.x, self.x) }
+    #[inline(always)] pure fn rg(&self) -> Vec2<T> { Vector2::new(self.x, self.y) }
+    #[inline(always)] pure fn rb(&self) -> Vec2<T> { Vector2::new(self.x, self.z) }
+    #[inline(always)] pure fn ra(&self) -> Vec2<T> { Vector2::new(self.x, self.w) }
+    #[inline(always)] pure fn gr(&self) -> Vec2<T> { Vector2::new(self.y, self.x) }
+    #[inline(always)] pure fn gg(&self) -> Vec2<T> { Vector2::new(self.y, self.y) }
+    #[inline(always)] pure fn gb(&self) -> Vec2<T> { Vector2::new(self.y, self.z) }
+    #[inline(always)] pure fn ga(&self) -> Vec2<T> { Vector2::new(self.y, self.w) }
+    #[inline(always)] pure fn br(&self) -> Vec2<T> { Vector2::new(self.z, self.x) }
+    #[inline(always)] pure fn bg(&self) -> Vec2<T> { Vector2::new(self.z, self.y) }
+    #[inline(always)] pure fn bb(&self) -> Vec2<T> { Vector2::new(self.z, self.z) }
+    #[inline(always)] pure fn ba(&self) -> Vec2<T> { Vector2::new(self.z, self.w) }
+    #[inline(always)] pure fn ar(&self) -> Vec2<T> { Vector2::new(self.w, self.x) }
+    #[inline(always)] pure fn ag(&self) -> Vec2<T> { Vector2::new(self.w, self.y) }
+    #[inline(always)] pure fn ab(&self) -> Vec2<T> { Vector2::new(self.w, self.z) }
+    #[inline(always)] pure fn aa(&self) -> Vec2<T> { Vector2::new(self.w, self.w) }
+    #[inline(always)] pure fn rrr(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.x) }
+    #[inline(always)] pure fn rrg(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.y) }
+    #[inline(always)] pure fn rrb(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.z) }
+    #[inline(always)] pure fn rra(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.w) }
+    #[inline(always)] pure fn rgr(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.x) }
+    #[inline(always)] pure fn rgg(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.y) }
+    #[inline(always)] pure fn rgb(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.z) }
+    #[inline(always)] pure fn rga(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.w) }
+    #[inline(always)] pure fn rbr(&self) -> Vec3<T> { Vector3::new(self.x, self.z, self.x) }
+    #[inline(always)] pure fn rbg(&self) -> Vec3<T> { Vector3::new(self.x, self.z, self.y) }
+    #[inline(always)] pure fn rbb(&self) -> Vec3<T> { Vector3::new(self.x, self.z, self.z) }
+    #[inline(always)] pure fn rba(&self) -> Vec3<T> { Vector3::new(self.x, self.z, self.w) }
+    #[inline(always)] pure fn rar(&self) -> Vec3<T> { Vector3::new(self.x, self.w, self.x) }
+    #[inline(always)] pure fn rag(&self) -> Vec3<T> { Vector3::new(self.x, self.w, self.y) }
+    #[inline(always)] pure fn rab(&self) -> Vec3<T> { Vector3::new(self.x, self.w, self.z) }
+    #[inline(always)] pure fn raa(&self) -> Vec3<T> { Vector3::new(self.x, self.w, self.w) }
+    #[inline(always)] pure fn grr(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.x) }
+    #[inline(always)] pure fn grg(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.y) }
+    #[inline(always)] pure fn grb(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.z) }
+    #[inline(always)] pure fn gra(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.w) }
+    #[inline(always)] pure fn ggr(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.x) }
+    #[inline(always)] pure fn ggg(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.y) }
+    #[inline(always)] pure fn ggb(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.z) }
+    #[inline(always)] pure fn gga(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.w) }
+    #[inline(always)] pure fn gbr(&self) -> Vec3<T> { Vector3::new(self.y, self.z, self.x) }
+    #[inline(always)] pure fn gbg(&self) -> Vec3<T> { Vector3::new(self.y, self.z, self.y) }
+    #[inline(always)] pure fn gbb(&self) -> Vec3<T> { Vector3::new(self.y, self.z, self.z) }
+    #[inline(always)] pure fn gba(&self) -> Vec3<T> { Vector3::new(self.y, self.z, self.w) }
+    #[inline(always)] pure fn gar(&self) -> Vec3<T> { Vector3::new(self.y, self.w, self.x) }
+    #[inline(always)] pure fn gag(&self) -> Vec3<T> { Vector3::new(self.y, self.w, self.y) }
+    #[inline(always)] pure fn gab(&self) -> Vec3<T> { Vector3::new(self.y, self.w, self.z) }
+    #[inline(always)] pure fn gaa(&self) -> Vec3<T> { Vector3::new(self.y, self.w, self.w) }
+    #[inline(always)] pure fn brr(&self) -> Vec3<T> { Vector3::new(self.z, self.x, self.x) }
+    #[inline(always)] pure fn brg(&self) -> Vec3<T> { Vector3::new(self.z, self.x, self.y) }
+    #[inline(always)] pure fn brb(&self) -> Vec3<T> { Vector3::new(self.z, self.x, self.z) }
+    #[inline(always)] pure fn bra(&self) -> Vec3<T> { Vector3::new(self.z, self.x, self.w) }
+    #[inline(always)] pure fn bgr(&self) -> Vec3<T> { Vector3::new(self.z, self.y, self.x) }
+    #[inline(always)] pure fn bgg(&self) -> Vec3<T> { Vector3::new(self.z, self.y, self.y) }
+    #[inline(always)] pure fn bgb(&self) -> Vec3<T> { Vector3::new(self.z, self.y, self.z) }
+    #[inline(always)] pure fn bga(&self) -> Vec3<T> { Vector3::new(self.z, self.y, self.w) }
+    #[inline(always)] pure fn bbr(&self) -> Vec3<T> { Vector3::new(self.z, self.z, self.x) }
+    #[inline(always)] pure fn bbg(&self) -> Vec3<T> { Vector3::new(self.z, self.z, self.y) }
+    #[inline(always)] pure fn bbb(&self) -> Vec3<T> { Vector3::new(self.z, self.z, self.z) }
+    #[inline(always)] pure fn bba(&self) -> Vec3<T> { Vector3::new(self.z, self.z, self.w) }
+    #[inline(always)] pure fn bar(&self) -> Vec3<T> { Vector3::new(self.z, self.w, self.x) }
+    #[inline(always)] pure fn bag(&self) -> Vec3<T> { Vector3::new(self.z, self.w, self.y) }
+    #[inline(always)] pure fn bab(&self) -> Vec3<T> { Vector3::new(self.z, self.w, self.z) }
+    #[inline(always)] pure fn baa(&self) -> Vec3<T> { Vector3::new(self.z, self.w, self.w) }
+    #[inline(always)] pure fn arr(&self) -> Vec3<T> { Vector3::new(self.w, self.x, self.x) }
+    #[inline(always)] pure fn arg(&self) -> Vec3<T> { Vector3::new(self.w, self.x, self.y) }
+    #[inline(always)] pure fn arb(&self) -> Vec3<T> { Vector3::new(self.w, self.x, self.z) }
+    #[inline(always)] pure fn ara(&self) -> Vec3<T> { Vector3::new(self.w, self.x, self.w) }
+    #[inline(always)] pure fn agr(&self) -> Vec3<T> { Vector3::new(self.w, self.y, self.x) }
+    #[inline(always)] pure fn agg(&self) -> Vec3<T> { Vector3::new(self.w, self.y, self.y) }
+    #[inline(always)] pure fn agb(&self) -> Vec3<T> { Vector3::new(self.w, self.y, self.z) }
+    #[inline(always)] pure fn aga(&self) -> Vec3<T> { Vector3::new(self.w, self.y, self.w) }
+    #[inline(always)] pure fn abr(&self) -> Vec3<T> { Vector3::new(self.w, self.z, self.x) }
+    #[inline(always)] pure fn abg(&self) -> Vec3<T> { Vector3::new(self.w, self.z, self.y) }
+    #[inline(always)] pure fn abb(&self) -> Vec3<T> { Vector3::new(self.w, self.z, self.z) }
+    #[inline(always)] pure fn aba(&self) -> Vec3<T> { Vector3::new(self.w, self.z, self.w) }
+    #[inline(always)] pure fn aar(&self) -> Vec3<T> { Vector3::new(self.w, self.w, self.x) }
+    #[inline(always)] pure fn aag(&self) -> Vec3<T> { Vector3::new(self.w, self.w, self.y) }
+    #[inline(always)] pure fn aab(&self) -> Vec3<T> { Vector3::new(self.w, self.w, self.z) }
+    #[inline(always)] pure fn aaa(&self) -> Vec3<T> { Vector3::new(self.w, self.w, self.w) }
+    #[inline(always)] pure fn rrrr(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.x) }
+    #[inline(always)] pure fn rrrg(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.y) }
+    #[inline(always)] pure fn rrrb(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.z) }
+    #[inline(always)] pure fn rrra(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.w) }
+    #[inline(always)] pure fn rrgr(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.x) }
+    #[inline(always)] pure fn rrgg(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.y) }
+    #[inline(always)] pure fn rrgb(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.z) }
+    #[inline(always)] pure fn rrga(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.w) }
+    #[inline(always)] pure fn rrbr(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.z, self.x) }
+    #[inline(always)] pure fn rrbg(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.z, self.y) }
+    #[inline(always)] pure fn rrbb(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.z, self.z) }
+    #[inline(always)] pure fn rrba(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.z, self.w) }
+    #[inline(always)] pure fn rrar(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.w, self.x) }
+    #[inline(always)] pure fn rrag(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.w, self.y) }
+    #[inline(always)] pure fn rrab(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.w, self.z) }
+    #[inline(always)] pure fn rraa(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.w, self.w) }
+    #[inline(always)] pure fn rgrr(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.x) }
+    #[inline(always)] pure fn rgrg(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.y) }
+    #[inline(always)] pure fn rgrb(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.z) }
+    #[inline(always)] pure fn rgra(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.w) }
+    #[inline(always)] pure fn rggr(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.x) }
+    #[inline(always)] pure fn rggg(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.y) }
+    #[inline(always)] pure fn rggb(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.z) }
+    #[inline(always)] pure fn rgga(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.w) }
+    #[inline(always)] pure fn rgbr(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.z, self.x) }
+    #[inline(always)] pure fn rgbg(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.z, self.y) }
+    #[inline(always)] pure fn rgbb(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.z, self.z) }
+    #[inline(always)] pure fn rgba(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.z, self.w) }
+    #[inline(always)] pure fn rgar(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.w, self.x) }
+    #[inline(always)] pure fn rgag(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.w, self.y) }
+    #[inline(always)] pure fn rgab(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.w, self.z) }
+    #[inline(always)] pure fn rgaa(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.w, self.w) }
+    #[inline(always)] pure fn rbrr(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.x, self.x) }
+    #[inline(always)] pure fn rbrg(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.x, self.y) }
+    #[inline(always)] pure fn rbrb(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.x, self.z) }
+    #[inline(always)] pure fn rbra(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.x, self.w) }
+    #[inline(always)] pure fn rbgr(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.y, self.x) }
+    #[inline(always)] pure fn rbgg(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.y, self.y) }
+    #[inline(always)] pure fn rbgb(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.y, self.z) }
+    #[inline(always)] pure fn rbga(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.y, self.w) }
+    #[inline(always)] pure fn rbbr(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.z, self.x) }
+    #[inline(always)] pure fn rbbg(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.z, self.y) }
+    #[inline(always)] pure fn rbbb(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.z, self.z) }
+    #[inline(always)] pure fn rbba(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.z, self.w) }
+    #[inline(always)] pure fn rbar(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.w, self.x) }
+    #[inline(always)] pure fn rbag(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.w, self.y) }
+    #[inline(always)] pure fn rbab(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.w, self.z) }
+    #[inline(always)] pure fn rbaa(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.w, self.w) }
+    #[inline(always)] pure fn rarr(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.x, self.x) }
+    #[inline(always)] pure fn rarg(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.x, self.y) }
+    #[inline(always)] pure fn rarb(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.x, self.z) }
+    #[inline(always)] pure fn rara(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.x, self.w) }
+    #[inline(always)] pure fn ragr(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.y, self.x) }
+    #[inline(always)] pure fn ragg(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.y, self.y) }
+    #[inline(always)] pure fn ragb(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.y, self.z) }
+    #[inline(always)] pure fn raga(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.y, self.w) }
+    #[inline(always)] pure fn rabr(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.z, self.x) }
+    #[inline(always)] pure fn rabg(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.z, self.y) }
+    #[inline(always)] pure fn rabb(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.z, self.z) }
+    #[inline(always)] pure fn raba(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.z, self.w) }
+    #[inline(always)] pure fn raar(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.w, self.x) }
+    #[inline(always)] pure fn raag(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.w, self.y) }
+    #[inline(always)] pure fn raab(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.w, self.z) }
+    #[inline(always)] pure fn raaa(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.w, self.w) }
+    #[inline(always)] pure fn grrr(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.x) }
+    #[inline(always)] pure fn grrg(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.y) }
+    #[inline(always)] pure fn grrb(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.z) }
+    #[inline(always)] pure fn grra(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.w) }
+    #[inline(always)] pure fn grgr(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.x) }
+    #[inline(always)] pure fn grgg(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.y) }
+    #[inline(always)] pure fn grgb(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.z) }
+    #[inline(always)] pure fn grga(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.w) }
+    #[inline(always)] pure fn grbr(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.z, self.x) }
+    #[inline(always)] pure fn grbg(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.z, self.y) }
+    #[inline(always)] pure fn grbb(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.z, self.z) }
+    #[inline(always)] pure fn grba(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.z, self.w) }
+    #[inline(always)] pure fn grar(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.w, self.x) }
+    #[inline(always)] pure fn grag(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.w, self.y) }
+    #[inline(always)] pure fn grab(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.w, self.z) }
+    #[inline(always)] pure fn graa(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.w, self.w) }
+    #[inline(always)] pure fn ggrr(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.x) }
+    #[inline(always)] pure fn ggrg(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.y) }
+    #[inline(always)] pure fn ggrb(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.z) }
+    #[inline(always)] pure fn ggra(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.w) }
+    #[inline(always)] pure fn gggr(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.x) }
+    #[inline(always)] pure fn gggg(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.y) }
+    #[inline(always)] pure fn gggb(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.z) }
+    #[inline(always)] pure fn ggga(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.w) }
+    #[inline(always)] pure fn ggbr(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.z, self.x) }
+    #[inline(always)] pure fn ggbg(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.z, self.y) }
+    #[inline(always)] pure fn ggbb(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.z, self.z) }
+    #[inline(always)] pure fn ggba(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.z, self.w) }
+    #[inline(always)] pure fn ggar(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.w, self.x) }
+    #[inline(always)] pure fn ggag(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.w, self.y) }
+    #[inline(always)] pure fn ggab(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.w, self.z) }
+    #[inline(always)] pure fn ggaa(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.w, self.w) }
+    #[inline(always)] pure fn gbrr(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.x, self.x) }
+    #[inline(always)] pure fn gbrg(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.x, self.y) }
+    #[inline(always)] pure fn gbrb(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.x, self.z) }
+    #[inline(always)] pure fn gbra(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.x, self.w) }
+    #[inline(always)] pure fn gbgr(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.y, self.x) }
+    #[inline(always)] pure fn gbgg(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.y, self.y) }
+    #[inline(always)] pure fn gbgb(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.y, self.z) }
+    #[inline(always)] pure fn gbga(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.y, self.w) }
+    #[inline(always)] pure fn gbbr(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.z, self.x) }
+    #[inline(always)] pure fn gbbg(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.z, self.y) }
+    #[inline(always)] pure fn gbbb(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.z, self.z) }
+    #[inline(always)] pure fn gbba(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.z, self.w) }
+    #[inline(always)] pure fn gbar(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.w, self.x) }
+    #[inline(always)] pure fn gbag(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.w, self.y) }
+    #[inline(always)] pure fn gbab(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.w, self.z) }
+    #[inline(always)] pure fn gbaa(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.w, self.w) }
+    #[inline(always)] pure fn garr(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.x, self.x) }
+    #[inline(always)] pure fn garg(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.x, self.y) }
+    #[inline(always)] pure fn garb(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.x, self.z) }
+    #[inline(always)] pure fn gara(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.x, self.w) }
+    #[inline(always)] pure fn gagr(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.y, self.x) }
+    #[inline(always)] pure fn gagg(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.y, self.y) }
+    #[inline(always)] pure fn gagb(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.y, self.z) }
+    #[inline(always)] pure fn gaga(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.y, self.w) }
+    #[inline(always)] pure fn gabr(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.z, self.x) }
+    #[inline(always)] pure fn gabg(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.z, self.y) }
+    #[inline(always)] pure fn gabb(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.z, self.z) }
+    #[inline(always)] pure fn gaba(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.z, self.w) }
+    #[inline(always)] pure fn gaar(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.w, self.x) }
+    #[inline(always)] pure fn gaag(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.w, self.y) }
+    #[inline(always)] pure fn gaab(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.w, self.z) }
+    #[inline(always)] pure fn gaaa(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.w, self.w) }
+    #[inline(always)] pure fn brrr(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.x, self.x) }
+    #[inline(always)] pure fn brrg(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.x, self.y) }
+    #[inline(always)] pure fn brrb(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.x, self.z) }
+    #[inline(always)] pure fn brra(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.x, self.w) }
+    #[inline(always)] pure fn brgr(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.y, self.x) }
+    #[inline(always)] pure fn brgg(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.y, self.y) }
+    #[inline(always)] pure fn brgb(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.y, self.z) }
+    #[inline(always)] pure fn brga(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.y, self.w) }
+    #[inline(always)] pure fn brbr(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.z, self.x) }
+    #[inline(always)] pure fn brbg(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.z, self.y) }
+    #[inline(always)] pure fn brbb(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.z, self.z) }
+    #[inline(always)] pure fn brba(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.z, self.w) }
+    #[inline(always)] pure fn brar(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.w, self.x) }
+    #[inline(always)] pure fn brag(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.w, self.y) }
+    #[inline(always)] pure fn brab(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.w, self.z) }
+    #[inline(always)] pure fn braa(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.w, self.w) }
+    #[inline(always)] pure fn bgrr(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.x, self.x) }
+    #[inline(always)] pure fn bgrg(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.x, self.y) }
+    #[inline(always)] pure fn bgrb(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.x, self.z) }
+    #[inline(always)] pure fn bgra(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.x, self.w) }
+    #[inline(always)] pure fn bggr(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.y, self.x) }
+    #[inline(always)] pure fn bggg(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.y, self.y) }
+    #[inline(always)] pure fn bggb(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.y, self.z) }
+    #[inline(always)] pure fn bgga(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.y, self.w) }
+    #[inline(always)] pure fn bgbr(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.z, self.x) }
+    #[inline(always)] pure fn bgbg(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.z, self.y) }
+    #[inline(always)] pure fn bgbb(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.z, self.z) }
+    #[inline(always)] pure fn bgba(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.z, self.w) }
+    #[inline(always)] pure fn bgar(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.w, self.x) }
+    #[inline(always)] pure fn bgag(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.w, self.y) }
+    #[inline(always)] pure fn bgab(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.w, self.z) }
+    #[inline(always)] pure fn bgaa(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.w, self.w) }
+    #[inline(always)] pure fn bbrr(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.x, self.x) }
+    #[inline(always)] pure fn bbrg(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.x, self.y) }
+    #[inline(always)] pure fn bbrb(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.x, self.z) }
+    #[inline(always)] pure fn bbra(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.x, self.w) }
+    #[inline(always)] pure fn bbgr(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.y, self.x) }
+    #[inline(always)] pure fn bbgg(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.y, self.y) }
+    #[inline(always)] pure fn bbgb(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.y, self.z) }
+    #[inline(always)] pure fn bbga(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.y, self.w) }
+    #[inline(always)] pure fn bbbr(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.z, self.x) }
+    #[inline(always)] pure fn bbbg(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.z, self.y) }
+    #[inline(always)] pure fn bbbb(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.z, self.z) }
+    #[inline(always)] pure fn bbba(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.z, self.w) }
+    #[inline(always)] pure fn bbar(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.w, self.x) }
+    #[inline(always)] pure fn bbag(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.w, self.y) }
+    #[inline(always)] pure fn bbab(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.w, self.z) }
+    #[inline(always)] pure fn bbaa(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.w, self.w) }
+    #[inline(always)] pure fn barr(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.x, self.x) }
+    #[inline(always)] pure fn barg(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.x, self.y) }
+    #[inline(always)] pure fn barb(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.x, self.z) }
+    #[inline(always)] pure fn bara(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.x, self.w) }
+    #[inline(always)] pure fn bagr(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.y, self.x) }
+    #[inline(always)] pure fn bagg(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.y, self.y) }
+    #[inline(always)] pure fn bagb(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.y, self.z) }
+    #[inline(always)] pure fn baga(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.y, self.w) }
+    #[inline(always)] pure fn babr(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.z, self.x) }
+    #[inline(always)] pure fn babg(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.z, self.y) }
+    #[inline(always)] pure fn babb(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.z, self.z) }
+    #[inline(always)] pure fn baba(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.z, self.w) }
+    #[inline(always)] pure fn baar(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.w, self.x) }
+    #[inline(always)] pure fn baag(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.w, self.y) }
+    #[inline(always)] pure fn baab(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.w, self.z) }
+    #[inline(always)] pure fn baaa(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.w, self.w) }
+    #[inline(always)] pure fn arrr(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.x, self.x) }
+    #[inline(always)] pure fn arrg(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.x, self.y) }
+    #[inline(always)] pure fn arrb(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.x, self.z) }
+    #[inline(always)] pure fn arra(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.x, self.w) }
+    #[inline(always)] pure fn argr(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.y, self.x) }
+    #[inline(always)] pure fn argg(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.y, self.y) }
+    #[inline(always)] pure fn argb(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.y, self.z) }
+    #[inline(always)] pure fn arga(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.y, self.w) }
+    #[inline(always)] pure fn arbr(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.z, self.x) }
+    #[inline(always)] pure fn arbg(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.z, self.y) }
+    #[inline(always)] pure fn arbb(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.z, self.z) }
+    #[inline(always)] pure fn arba(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.z, self.w) }
+    #[inline(always)] pure fn arar(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.w, self.x) }
+    #[inline(always)] pure fn arag(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.w, self.y) }
+    #[inline(always)] pure fn arab(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.w, self.z) }
+    #[inline(always)] pure fn araa(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.w, self.w) }
+    #[inline(always)] pure fn agrr(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.x, self.x) }
+    #[inline(always)] pure fn agrg(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.x, self.y) }
+    #[inline(always)] pure fn agrb(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.x, self.z) }
+    #[inline(always)] pure fn agra(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.x, self.w) }
+    #[inline(always)] pure fn aggr(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.y, self.x) }
+    #[inline(always)] pure fn aggg(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.y, self.y) }
+    #[inline(always)] pure fn aggb(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.y, self.z) }
+    #[inline(always)] pure fn agga(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.y, self.w) }
+    #[inline(always)] pure fn agbr(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.z, self.x) }
+    #[inline(always)] pure fn agbg(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.z, self.y) }
+    #[inline(always)] pure fn agbb(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.z, self.z) }
+    #[inline(always)] pure fn agba(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.z, self.w) }
+    #[inline(always)] pure fn agar(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.w, self.x) }
+    #[inline(always)] pure fn agag(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.w, self.y) }
+    #[inline(always)] pure fn agab(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.w, self.z) }
+    #[inline(always)] pure fn agaa(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.w, self.w) }
+    #[inline(always)] pure fn abrr(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.x, self.x) }
+    #[inline(always)] pure fn abrg(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.x, self.y) }
+    #[inline(always)] pure fn abrb(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.x, self.z) }
+    #[inline(always)] pure fn abra(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.x, self.w) }
+    #[inline(always)] pure fn abgr(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.y, self.x) }
+    #[inline(always)] pure fn abgg(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.y, self.y) }
+    #[inline(always)] pure fn abgb(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.y, self.z) }
+    #[inline(always)] pure fn abga(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.y, self.w) }
+    #[inline(always)] pure fn abbr(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.z, self.x) }
+    #[inline(always)] pure fn abbg(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.z, self.y) }
+    #[inline(always)] pure fn abbb(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.z, self.z) }
+    #[inline(always)] pure fn abba(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.z, self.w) }
+    #[inline(always)] pure fn abar(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.w, self.x) }
+    #[inline(always)] pure fn abag(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.w, self.y) }
+    #[inline(always)] pure fn abab(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.w, self.z) }
+    #[inline(always)] pure fn abaa(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.w, self.w) }
+    #[inline(always)] pure fn aarr(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.x, self.x) }
+    #[inline(always)] pure fn aarg(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.x, self.y) }
+    #[inline(always)] pure fn aarb(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.x, self.z) }
+    #[inline(always)] pure fn aara(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.x, self.w) }
+    #[inline(always)] pure fn aagr(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.y, self.x) }
+    #[inline(always)] pure fn aagg(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.y, self.y) }
+    #[inline(always)] pure fn aagb(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.y, self.z) }
+    #[inline(always)] pure fn aaga(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.y, self.w) }
+    #[inline(always)] pure fn aabr(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.z, self.x) }
+    #[inline(always)] pure fn aabg(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.z, self.y) }
+    #[inline(always)] pure fn aabb(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.z, self.z) }
+    #[inline(always)] pure fn aaba(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.z, self.w) }
+    #[inline(always)] pure fn aaar(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.w, self.x) }
+    #[inline(always)] pure fn aaag(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.w, self.y) }
+    #[inline(always)] pure fn aaab(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.w, self.z) }
+    #[inline(always)] pure fn aaaa(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.w, self.w) }
+    
+    #[inline(always)] fn set_rg(&mut self, v: &Vec2<T>) { self.x = v.x; self.y = v.y; }
+    #[inline(always)] fn set_rb(&mut self, v: &Vec2<T>) { self.x = v.x; self.z = v.y; }
+    #[inline(always)] fn set_ra(&mut self, v: &Vec2<T>) { self.x = v.x; self.w = v.y; }
+    #[inline(always)] fn set_gr(&mut self, v: &Vec2<T>) { self.y = v.x; self.x = v.y; }
+    #[inline(always)] fn set_gb(&mut self, v: &Vec2<T>) { self.y = v.x; self.z = v.y; }
+    #[inline(always)] fn set_ga(&mut self, v: &Vec2<T>) { self.y = v.x; self.w = v.y; }
+    #[inline(always)] fn set_br(&mut self, v: &Vec2<T>) { self.z = v.x; self.x = v.y; }
+    #[inline(always)] fn set_bg(&mut self, v: &Vec2<T>) { self.z = v.x; self.y = v.y; }
+    #[inline(always)] fn set_ba(&mut self, v: &Vec2<T>) { self.z = v.x; self.w = v.y; }
+    #[inline(always)] fn set_ar(&mut self, v: &Vec2<T>) { self.w = v.x; self.x = v.y; }
+    #[inline(always)] fn set_ag(&mut self, v: &Vec2<T>) { self.w = v.x; self.y = v.y; }
+    #[inline(always)] fn set_ab(&mut self, v: &Vec2<T>) { self.w = v.x; self.z = v.y; }
+    #[inline(always)] fn set_rgb(&mut self, v: &Vec3<T>) { self.x = v.x; self.y = v.y; self.z = v.z; }
+    #[inline(always)] fn set_rga(&mut self, v: &Vec3<T>) { self.x = v.x; self.y = v.y; self.w = v.z; }
+    #[inline(always)] fn set_rbg(&mut self, v: &Vec3<T>) { self.x = v.x; self.z = v.y; self.y = v.z; }
+    #[inline(always)] fn set_rba(&mut self, v: &Vec3<T>) { self.x = v.x; self.z = v.y; self.w = v.z; }
+    #[inline(always)] fn set_rag(&mut self, v: &Vec3<T>) { self.x = v.x; self.w = v.y; self.y = v.z; }
+    #[inline(always)] fn set_rab(&mut self, v: &Vec3<T>) { self.x = v.x; self.w = v.y; self.z = v.z; }
+    #[inline(always)] fn set_grb(&mut self, v: &Vec3<T>) { self.y = v.x; self.x = v.y; self.z = v.z; }
+    #[inline(always)] fn set_gra(&mut self, v: &Vec3<T>) { self.y = v.x; self.x = v.y; self.w = v.z; }
+    #[inline(always)] fn set_gbr(&mut self, v: &Vec3<T>) { self.y = v.x; self.z = v.y; self.x = v.z; }
+    #[inline(always)] fn set_gba(&mut self, v: &Vec3<T>) { self.y = v.x; self.z = v.y; self.w = v.z; }
+    #[inline(always)] fn set_gar(&mut self, v: &Vec3<T>) { self.y = v.x; self.w = v.y; self.x = v.z; }
+    #[inline(always)] fn set_gab(&mut self, v: &Vec3<T>) { self.y = v.x; self.w = v.y; self.z = v.z; }
+    #[inline(always)] fn set_brg(&mut self, v: &Vec3<T>) { self.z = v.x; self.x = v.y; self.y = v.z; }
+    #[inline(always)] fn set_bra(&mut self, v: &Vec3<T>) { self.z = v.x; self.x = v.y; self.w = v.z; }
+    #[inline(always)] fn set_bgr(&mut self, v: &Vec3<T>) { self.z = v.x; self.y = v.y; self.x = v.z; }
+    #[inline(always)] fn set_bga(&mut self, v: &Vec3<T>) { self.z = v.x; self.y = v.y; self.w = v.z; }
+    #[inline(always)] fn set_bar(&mut self, v: &Vec3<T>) { self.z = v.x; self.w = v.y; self.x = v.z; }
+    #[inline(always)] fn set_bag(&mut self, v: &Vec3<T>) { self.z = v.x; self.w = v.y; self.y = v.z; }
+    #[inline(always)] fn set_arg(&mut self, v: &Vec3<T>) { self.w = v.x; self.x = v.y; self.y = v.z; }
+    #[inline(always)] fn set_arb(&mut self, v: &Vec3<T>) { self.w = v.x; self.x = v.y; self.z = v.z; }
+    #[inline(always)] fn set_agr(&mut self, v: &Vec3<T>) { self.w = v.x; self.y = v.y; self.x = v.z; }
+    #[inline(always)] fn set_agb(&mut self, v: &Vec3<T>) { self.w = v.x; self.y = v.y; self.z = v.z; }
+    #[inline(always)] fn set_abr(&mut self, v: &Vec3<T>) { self.w = v.x; self.z = v.y; self.x = v.z; }
+    #[inline(always)] fn set_abg(&mut self, v: &Vec3<T>) { self.w = v.x; self.z = v.y; self.y = v.z; }
+    #[inline(always)] fn set_rgba(&mut self, v: &Vec4<T>) { self.x = v.x; self.y = v.y; self.z = v.z; self.w = v.w; }
+    #[inline(always)] fn set_rgab(&mut self, v: &Vec4<T>) { self.x = v.x; self.y = v.y; self.w = v.z; self.z = v.w; }
+    #[inline(always)] fn set_rbga(&mut self, v: &Vec4<T>) { self.x = v.x; self.z = v.y; self.y = v.z; self.w = v.w; }
+    #[inline(always)] fn set_rbag(&mut self, v: &Vec4<T>) { self.x = v.x; self.z = v.y; self.w = v.z; self.y = v.w; }
+    #[inline(always)] fn set_ragb(&mut self, v: &Vec4<T>) { self.x = v.x; self.w = v.y; self.y = v.z; self.z = v.w; }
+    #[inline(always)] fn set_rabg(&mut self, v: &Vec4<T>) { self.x = v.x; self.w = v.y; self.z = v.z; self.y = v.w; }
+    #[inline(always)] fn set_grba(&mut self, v: &Vec4<T>) { self.y = v.x; self.x = v.y; self.z = v.z; self.w = v.w; }
+    #[inline(always)] fn set_grab(&mut self, v: &Vec4<T>) { self.y = v.x; self.x = v.y; self.w = v.z; self.z = v.w; }
+    #[inline(always)] fn set_gbra(&mut self, v: &Vec4<T>) { self.y = v.x; self.z = v.y; self.x = v.z; self.w = v.w; }
+    #[inline(always)] fn set_gbar(&mut self, v: &Vec4<T>) { self.y = v.x; self.z = v.y; self.w = v.z; self.x = v.w; }
+    #[inline(always)] fn set_garb(&mut self, v: &Vec4<T>) { self.y = v.x; self.w = v.y; self.x = v.z; self.z = v.w; }
+    #[inline(always)] fn set_gabr(&mut self, v: &Vec4<T>) { self.y = v.x; self.w = v.y; self.z = v.z; self.x = v.w; }
+    #[inline(always)] fn set_brga(&mut self, v: &Vec4<T>) { self.z = v.x; self.x = v.y; self.y = v.z; self.w = v.w; }
+    #[inline(always)] fn set_brag(&mut self, v: &Vec4<T>) { self.z = v.x; self.x = v.y; self.w = v.z; self.y = v.w; }
+    #[inline(always)] fn set_bgra(&mut self, v: &Vec4<T>) { self.z = v.x; self.y = v.y; self.x = v.z; self.w = v.w; }
+    #[inline(always)] fn set_bgar(&mut self, v: &Vec4<T>) { self.z = v.x; self.y = v.y; self.w = v.z; self.x = v.w; }
+    #[inline(always)] fn set_barg(&mut self, v: &Vec4<T>) { self.z = v.x; self.w = v.y; self.x = v.z; self.y = v.w; }
+    #[inline(always)] fn set_bagr(&mut self, v: &Vec4<T>) { self.z = v.x; self.w = v.y; self.y = v.z; self.x = v.w; }
+    #[inline(always)] fn set_argb(&mut self, v: &Vec4<T>) { self.w = v.x; self.x = v.y; self.y = v.z; self.z = v.w; }
+    #[inline(always)] fn set_arbg(&mut self, v: &Vec4<T>) { self.w = v.x; self.x = v.y; self.z = v.z; self.y = v.w; }
+    #[inline(always)] fn set_agrb(&mut self, v: &Vec4<T>) { self.w = v.x; self.y = v.y; self.x = v.z; self.z = v.w; }
+    #[inline(always)] fn set_agbr(&mut self, v: &Vec4<T>) { self.w = v.x; self.y = v.y; self.z = v.z; self.x = v.w; }
+    #[inline(always)] fn set_abrg(&mut self, v: &Vec4<T>) { self.w = v.x; self.z = v.y; self.x = v.z; self.y = v.w; }
+    #[inline(always)] fn set_abgr(&mut self, v: &Vec4<T>) { self.w = v.x; self.z = v.y; self.y = v.z; self.x = v.w; }
+}
+
+pub impl<T:Copy> Vec4<T> {
+    #[inline(always)] pure fn ss(&self) -> Vec2<T> { Vector2::new(self.x, self.x) }
+    #[inline(always)] pure fn st(&self) -> Vec2<T> { Vector2::new(self.x, self.y) }
+    #[inline(always)] pure fn sp(&self) -> Vec2<T> { Vector2::new(self.x, self.z) }
+    #[inline(always)] pure fn sq(&self) -> Vec2<T> { Vector2::new(self.x, self.w) }
+    #[inline(always)] pure fn ts(&self) -> Vec2<T> { Vector2::new(self.y, self.x) }
+    #[inline(always)] pure fn tt(&self) -> Vec2<T> { Vector2::new(self.y, self.y) }
+    #[inline(always)] pure fn tp(&self) -> Vec2<T> { Vector2::new(self.y, self.z) }
+    #[inline(always)] pure fn tq(&self) -> Vec2<T> { Vector2::new(self.y, self.w) }
+    #[inline(always)] pure fn ps(&self) -> Vec2<T> { Vector2::new(self.z, self.x) }
+    #[inline(always)] pure fn pt(&self) -> Vec2<T> { Vector2::new(self.z, self.y) }
+    #[inline(always)] pure fn pp(&self) -> Vec2<T> { Vector2::new(self.z, self.z) }
+    #[inline(always)] pure fn pq(&self) -> Vec2<T> { Vector2::new(self.z, self.w) }
+    #[inline(always)] pure fn qs(&self) -> Vec2<T> { Vector2::new(self.w, self.x) }
+    #[inline(always)] pure fn qt(&self) -> Vec2<T> { Vector2::new(self.w, self.y) }
+    #[inline(always)] pure fn qp(&self) -> Vec2<T> { Vector2::new(self.w, self.z) }
+    #[inline(always)] pure fn qq(&self) -> Vec2<T> { Vector2::new(self.w, self.w) }
+    #[inline(always)] pure fn sss(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.x) }
+    #[inline(always)] pure fn sst(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.y) }
+    #[inline(always)] pure fn ssp(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.z) }
+    #[inline(always)] pure fn ssq(&self) -> Vec3<T> { Vector3::new(self.x, self.x, self.w) }
+    #[inline(always)] pure fn sts(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.x) }
+    #[inline(always)] pure fn stt(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.y) }
+    #[inline(always)] pure fn stp(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.z) }
+    #[inline(always)] pure fn stq(&self) -> Vec3<T> { Vector3::new(self.x, self.y, self.w) }
+    #[inline(always)] pure fn sps(&self) -> Vec3<T> { Vector3::new(self.x, self.z, self.x) }
+    #[inline(always)] pure fn spt(&self) -> Vec3<T> { Vector3::new(self.x, self.z, self.y) }
+    #[inline(always)] pure fn spp(&self) -> Vec3<T> { Vector3::new(self.x, self.z, self.z) }
+    #[inline(always)] pure fn spq(&self) -> Vec3<T> { Vector3::new(self.x, self.z, self.w) }
+    #[inline(always)] pure fn sqs(&self) -> Vec3<T> { Vector3::new(self.x, self.w, self.x) }
+    #[inline(always)] pure fn sqt(&self) -> Vec3<T> { Vector3::new(self.x, self.w, self.y) }
+    #[inline(always)] pure fn sqp(&self) -> Vec3<T> { Vector3::new(self.x, self.w, self.z) }
+    #[inline(always)] pure fn sqq(&self) -> Vec3<T> { Vector3::new(self.x, self.w, self.w) }
+    #[inline(always)] pure fn tss(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.x) }
+    #[inline(always)] pure fn tst(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.y) }
+    #[inline(always)] pure fn tsp(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.z) }
+    #[inline(always)] pure fn tsq(&self) -> Vec3<T> { Vector3::new(self.y, self.x, self.w) }
+    #[inline(always)] pure fn tts(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.x) }
+    #[inline(always)] pure fn ttt(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.y) }
+    #[inline(always)] pure fn ttp(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.z) }
+    #[inline(always)] pure fn ttq(&self) -> Vec3<T> { Vector3::new(self.y, self.y, self.w) }
+    #[inline(always)] pure fn tps(&self) -> Vec3<T> { Vector3::new(self.y, self.z, self.x) }
+    #[inline(always)] pure fn tpt(&self) -> Vec3<T> { Vector3::new(self.y, self.z, self.y) }
+    #[inline(always)] pure fn tpp(&self) -> Vec3<T> { Vector3::new(self.y, self.z, self.z) }
+    #[inline(always)] pure fn tpq(&self) -> Vec3<T> { Vector3::new(self.y, self.z, self.w) }
+    #[inline(always)] pure fn tqs(&self) -> Vec3<T> { Vector3::new(self.y, self.w, self.x) }
+    #[inline(always)] pure fn tqt(&self) -> Vec3<T> { Vector3::new(self.y, self.w, self.y) }
+    #[inline(always)] pure fn tqp(&self) -> Vec3<T> { Vector3::new(self.y, self.w, self.z) }
+    #[inline(always)] pure fn tqq(&self) -> Vec3<T> { Vector3::new(self.y, self.w, self.w) }
+    #[inline(always)] pure fn pss(&self) -> Vec3<T> { Vector3::new(self.z, self.x, self.x) }
+    #[inline(always)] pure fn pst(&self) -> Vec3<T> { Vector3::new(self.z, self.x, self.y) }
+    #[inline(always)] pure fn psp(&self) -> Vec3<T> { Vector3::new(self.z, self.x, self.z) }
+    #[inline(always)] pure fn psq(&self) -> Vec3<T> { Vector3::new(self.z, self.x, self.w) }
+    #[inline(always)] pure fn pts(&self) -> Vec3<T> { Vector3::new(self.z, self.y, self.x) }
+    #[inline(always)] pure fn ptt(&self) -> Vec3<T> { Vector3::new(self.z, self.y, self.y) }
+    #[inline(always)] pure fn ptp(&self) -> Vec3<T> { Vector3::new(self.z, self.y, self.z) }
+    #[inline(always)] pure fn ptq(&self) -> Vec3<T> { Vector3::new(self.z, self.y, self.w) }
+    #[inline(always)] pure fn pps(&self) -> Vec3<T> { Vector3::new(self.z, self.z, self.x) }
+    #[inline(always)] pure fn ppt(&self) -> Vec3<T> { Vector3::new(self.z, self.z, self.y) }
+    #[inline(always)] pure fn ppp(&self) -> Vec3<T> { Vector3::new(self.z, self.z, self.z) }
+    #[inline(always)] pure fn ppq(&self) -> Vec3<T> { Vector3::new(self.z, self.z, self.w) }
+    #[inline(always)] pure fn pqs(&self) -> Vec3<T> { Vector3::new(self.z, self.w, self.x) }
+    #[inline(always)] pure fn pqt(&self) -> Vec3<T> { Vector3::new(self.z, self.w, self.y) }
+    #[inline(always)] pure fn pqp(&self) -> Vec3<T> { Vector3::new(self.z, self.w, self.z) }
+    #[inline(always)] pure fn pqq(&self) -> Vec3<T> { Vector3::new(self.z, self.w, self.w) }
+    #[inline(always)] pure fn qss(&self) -> Vec3<T> { Vector3::new(self.w, self.x, self.x) }
+    #[inline(always)] pure fn qst(&self) -> Vec3<T> { Vector3::new(self.w, self.x, self.y) }
+    #[inline(always)] pure fn qsp(&self) -> Vec3<T> { Vector3::new(self.w, self.x, self.z) }
+    #[inline(always)] pure fn qsq(&self) -> Vec3<T> { Vector3::new(self.w, self.x, self.w) }
+    #[inline(always)] pure fn qts(&self) -> Vec3<T> { Vector3::new(self.w, self.y, self.x) }
+    #[inline(always)] pure fn qtt(&self) -> Vec3<T> { Vector3::new(self.w, self.y, self.y) }
+    #[inline(always)] pure fn qtp(&self) -> Vec3<T> { Vector3::new(self.w, self.y, self.z) }
+    #[inline(always)] pure fn qtq(&self) -> Vec3<T> { Vector3::new(self.w, self.y, self.w) }
+    #[inline(always)] pure fn qps(&self) -> Vec3<T> { Vector3::new(self.w, self.z, self.x) }
+    #[inline(always)] pure fn qpt(&self) -> Vec3<T> { Vector3::new(self.w, self.z, self.y) }
+    #[inline(always)] pure fn qpp(&self) -> Vec3<T> { Vector3::new(self.w, self.z, self.z) }
+    #[inline(always)] pure fn qpq(&self) -> Vec3<T> { Vector3::new(self.w, self.z, self.w) }
+    #[inline(always)] pure fn qqs(&self) -> Vec3<T> { Vector3::new(self.w, self.w, self.x) }
+    #[inline(always)] pure fn qqt(&self) -> Vec3<T> { Vector3::new(self.w, self.w, self.y) }
+    #[inline(always)] pure fn qqp(&self) -> Vec3<T> { Vector3::new(self.w, self.w, self.z) }
+    #[inline(always)] pure fn qqq(&self) -> Vec3<T> { Vector3::new(self.w, self.w, self.w) }
+    #[inline(always)] pure fn ssss(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.x) }
+    #[inline(always)] pure fn ssst(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.y) }
+    #[inline(always)] pure fn sssp(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.z) }
+    #[inline(always)] pure fn sssq(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.x, self.w) }
+    #[inline(always)] pure fn ssts(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.x) }
+    #[inline(always)] pure fn sstt(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.y) }
+    #[inline(always)] pure fn sstp(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.z) }
+    #[inline(always)] pure fn sstq(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.y, self.w) }
+    #[inline(always)] pure fn ssps(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.z, self.x) }
+    #[inline(always)] pure fn sspt(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.z, self.y) }
+    #[inline(always)] pure fn sspp(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.z, self.z) }
+    #[inline(always)] pure fn sspq(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.z, self.w) }
+    #[inline(always)] pure fn ssqs(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.w, self.x) }
+    #[inline(always)] pure fn ssqt(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.w, self.y) }
+    #[inline(always)] pure fn ssqp(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.w, self.z) }
+    #[inline(always)] pure fn ssqq(&self) -> Vec4<T> { Vector4::new(self.x, self.x, self.w, self.w) }
+    #[inline(always)] pure fn stss(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.x) }
+    #[inline(always)] pure fn stst(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.y) }
+    #[inline(always)] pure fn stsp(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.z) }
+    #[inline(always)] pure fn stsq(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.x, self.w) }
+    #[inline(always)] pure fn stts(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.x) }
+    #[inline(always)] pure fn sttt(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.y) }
+    #[inline(always)] pure fn sttp(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.z) }
+    #[inline(always)] pure fn sttq(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.y, self.w) }
+    #[inline(always)] pure fn stps(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.z, self.x) }
+    #[inline(always)] pure fn stpt(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.z, self.y) }
+    #[inline(always)] pure fn stpp(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.z, self.z) }
+    #[inline(always)] pure fn stpq(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.z, self.w) }
+    #[inline(always)] pure fn stqs(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.w, self.x) }
+    #[inline(always)] pure fn stqt(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.w, self.y) }
+    #[inline(always)] pure fn stqp(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.w, self.z) }
+    #[inline(always)] pure fn stqq(&self) -> Vec4<T> { Vector4::new(self.x, self.y, self.w, self.w) }
+    #[inline(always)] pure fn spss(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.x, self.x) }
+    #[inline(always)] pure fn spst(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.x, self.y) }
+    #[inline(always)] pure fn spsp(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.x, self.z) }
+    #[inline(always)] pure fn spsq(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.x, self.w) }
+    #[inline(always)] pure fn spts(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.y, self.x) }
+    #[inline(always)] pure fn sptt(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.y, self.y) }
+    #[inline(always)] pure fn sptp(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.y, self.z) }
+    #[inline(always)] pure fn sptq(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.y, self.w) }
+    #[inline(always)] pure fn spps(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.z, self.x) }
+    #[inline(always)] pure fn sppt(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.z, self.y) }
+    #[inline(always)] pure fn sppp(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.z, self.z) }
+    #[inline(always)] pure fn sppq(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.z, self.w) }
+    #[inline(always)] pure fn spqs(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.w, self.x) }
+    #[inline(always)] pure fn spqt(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.w, self.y) }
+    #[inline(always)] pure fn spqp(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.w, self.z) }
+    #[inline(always)] pure fn spqq(&self) -> Vec4<T> { Vector4::new(self.x, self.z, self.w, self.w) }
+    #[inline(always)] pure fn sqss(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.x, self.x) }
+    #[inline(always)] pure fn sqst(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.x, self.y) }
+    #[inline(always)] pure fn sqsp(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.x, self.z) }
+    #[inline(always)] pure fn sqsq(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.x, self.w) }
+    #[inline(always)] pure fn sqts(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.y, self.x) }
+    #[inline(always)] pure fn sqtt(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.y, self.y) }
+    #[inline(always)] pure fn sqtp(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.y, self.z) }
+    #[inline(always)] pure fn sqtq(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.y, self.w) }
+    #[inline(always)] pure fn sqps(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.z, self.x) }
+    #[inline(always)] pure fn sqpt(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.z, self.y) }
+    #[inline(always)] pure fn sqpp(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.z, self.z) }
+    #[inline(always)] pure fn sqpq(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.z, self.w) }
+    #[inline(always)] pure fn sqqs(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.w, self.x) }
+    #[inline(always)] pure fn sqqt(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.w, self.y) }
+    #[inline(always)] pure fn sqqp(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.w, self.z) }
+    #[inline(always)] pure fn sqqq(&self) -> Vec4<T> { Vector4::new(self.x, self.w, self.w, self.w) }
+    #[inline(always)] pure fn tsss(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.x) }
+    #[inline(always)] pure fn tsst(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.y) }
+    #[inline(always)] pure fn tssp(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.z) }
+    #[inline(always)] pure fn tssq(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.x, self.w) }
+    #[inline(always)] pure fn tsts(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.x) }
+    #[inline(always)] pure fn tstt(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.y) }
+    #[inline(always)] pure fn tstp(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.z) }
+    #[inline(always)] pure fn tstq(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.y, self.w) }
+    #[inline(always)] pure fn tsps(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.z, self.x) }
+    #[inline(always)] pure fn tspt(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.z, self.y) }
+    #[inline(always)] pure fn tspp(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.z, self.z) }
+    #[inline(always)] pure fn tspq(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.z, self.w) }
+    #[inline(always)] pure fn tsqs(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.w, self.x) }
+    #[inline(always)] pure fn tsqt(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.w, self.y) }
+    #[inline(always)] pure fn tsqp(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.w, self.z) }
+    #[inline(always)] pure fn tsqq(&self) -> Vec4<T> { Vector4::new(self.y, self.x, self.w, self.w) }
+    #[inline(always)] pure fn ttss(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.x) }
+    #[inline(always)] pure fn ttst(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.y) }
+    #[inline(always)] pure fn ttsp(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.z) }
+    #[inline(always)] pure fn ttsq(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.x, self.w) }
+    #[inline(always)] pure fn ttts(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.x) }
+    #[inline(always)] pure fn tttt(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.y) }
+    #[inline(always)] pure fn tttp(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.z) }
+    #[inline(always)] pure fn tttq(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.y, self.w) }
+    #[inline(always)] pure fn ttps(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.z, self.x) }
+    #[inline(always)] pure fn ttpt(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.z, self.y) }
+    #[inline(always)] pure fn ttpp(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.z, self.z) }
+    #[inline(always)] pure fn ttpq(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.z, self.w) }
+    #[inline(always)] pure fn ttqs(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.w, self.x) }
+    #[inline(always)] pure fn ttqt(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.w, self.y) }
+    #[inline(always)] pure fn ttqp(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.w, self.z) }
+    #[inline(always)] pure fn ttqq(&self) -> Vec4<T> { Vector4::new(self.y, self.y, self.w, self.w) }
+    #[inline(always)] pure fn tpss(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.x, self.x) }
+    #[inline(always)] pure fn tpst(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.x, self.y) }
+    #[inline(always)] pure fn tpsp(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.x, self.z) }
+    #[inline(always)] pure fn tpsq(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.x, self.w) }
+    #[inline(always)] pure fn tpts(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.y, self.x) }
+    #[inline(always)] pure fn tptt(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.y, self.y) }
+    #[inline(always)] pure fn tptp(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.y, self.z) }
+    #[inline(always)] pure fn tptq(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.y, self.w) }
+    #[inline(always)] pure fn tpps(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.z, self.x) }
+    #[inline(always)] pure fn tppt(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.z, self.y) }
+    #[inline(always)] pure fn tppp(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.z, self.z) }
+    #[inline(always)] pure fn tppq(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.z, self.w) }
+    #[inline(always)] pure fn tpqs(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.w, self.x) }
+    #[inline(always)] pure fn tpqt(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.w, self.y) }
+    #[inline(always)] pure fn tpqp(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.w, self.z) }
+    #[inline(always)] pure fn tpqq(&self) -> Vec4<T> { Vector4::new(self.y, self.z, self.w, self.w) }
+    #[inline(always)] pure fn tqss(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.x, self.x) }
+    #[inline(always)] pure fn tqst(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.x, self.y) }
+    #[inline(always)] pure fn tqsp(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.x, self.z) }
+    #[inline(always)] pure fn tqsq(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.x, self.w) }
+    #[inline(always)] pure fn tqts(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.y, self.x) }
+    #[inline(always)] pure fn tqtt(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.y, self.y) }
+    #[inline(always)] pure fn tqtp(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.y, self.z) }
+    #[inline(always)] pure fn tqtq(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.y, self.w) }
+    #[inline(always)] pure fn tqps(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.z, self.x) }
+    #[inline(always)] pure fn tqpt(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.z, self.y) }
+    #[inline(always)] pure fn tqpp(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.z, self.z) }
+    #[inline(always)] pure fn tqpq(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.z, self.w) }
+    #[inline(always)] pure fn tqqs(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.w, self.x) }
+    #[inline(always)] pure fn tqqt(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.w, self.y) }
+    #[inline(always)] pure fn tqqp(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.w, self.z) }
+    #[inline(always)] pure fn tqqq(&self) -> Vec4<T> { Vector4::new(self.y, self.w, self.w, self.w) }
+    #[inline(always)] pure fn psss(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.x, self.x) }
+    #[inline(always)] pure fn psst(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.x, self.y) }
+    #[inline(always)] pure fn pssp(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.x, self.z) }
+    #[inline(always)] pure fn pssq(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.x, self.w) }
+    #[inline(always)] pure fn psts(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.y, self.x) }
+    #[inline(always)] pure fn pstt(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.y, self.y) }
+    #[inline(always)] pure fn pstp(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.y, self.z) }
+    #[inline(always)] pure fn pstq(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.y, self.w) }
+    #[inline(always)] pure fn psps(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.z, self.x) }
+    #[inline(always)] pure fn pspt(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.z, self.y) }
+    #[inline(always)] pure fn pspp(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.z, self.z) }
+    #[inline(always)] pure fn pspq(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.z, self.w) }
+    #[inline(always)] pure fn psqs(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.w, self.x) }
+    #[inline(always)] pure fn psqt(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.w, self.y) }
+    #[inline(always)] pure fn psqp(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.w, self.z) }
+    #[inline(always)] pure fn psqq(&self) -> Vec4<T> { Vector4::new(self.z, self.x, self.w, self.w) }
+    #[inline(always)] pure fn ptss(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.x, self.x) }
+    #[inline(always)] pure fn ptst(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.x, self.y) }
+    #[inline(always)] pure fn ptsp(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.x, self.z) }
+    #[inline(always)] pure fn ptsq(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.x, self.w) }
+    #[inline(always)] pure fn ptts(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.y, self.x) }
+    #[inline(always)] pure fn pttt(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.y, self.y) }
+    #[inline(always)] pure fn pttp(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.y, self.z) }
+    #[inline(always)] pure fn pttq(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.y, self.w) }
+    #[inline(always)] pure fn ptps(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.z, self.x) }
+    #[inline(always)] pure fn ptpt(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.z, self.y) }
+    #[inline(always)] pure fn ptpp(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.z, self.z) }
+    #[inline(always)] pure fn ptpq(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.z, self.w) }
+    #[inline(always)] pure fn ptqs(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.w, self.x) }
+    #[inline(always)] pure fn ptqt(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.w, self.y) }
+    #[inline(always)] pure fn ptqp(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.w, self.z) }
+    #[inline(always)] pure fn ptqq(&self) -> Vec4<T> { Vector4::new(self.z, self.y, self.w, self.w) }
+    #[inline(always)] pure fn ppss(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.x, self.x) }
+    #[inline(always)] pure fn ppst(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.x, self.y) }
+    #[inline(always)] pure fn ppsp(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.x, self.z) }
+    #[inline(always)] pure fn ppsq(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.x, self.w) }
+    #[inline(always)] pure fn ppts(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.y, self.x) }
+    #[inline(always)] pure fn pptt(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.y, self.y) }
+    #[inline(always)] pure fn pptp(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.y, self.z) }
+    #[inline(always)] pure fn pptq(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.y, self.w) }
+    #[inline(always)] pure fn ppps(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.z, self.x) }
+    #[inline(always)] pure fn pppt(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.z, self.y) }
+    #[inline(always)] pure fn pppp(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.z, self.z) }
+    #[inline(always)] pure fn pppq(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.z, self.w) }
+    #[inline(always)] pure fn ppqs(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.w, self.x) }
+    #[inline(always)] pure fn ppqt(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.w, self.y) }
+    #[inline(always)] pure fn ppqp(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.w, self.z) }
+    #[inline(always)] pure fn ppqq(&self) -> Vec4<T> { Vector4::new(self.z, self.z, self.w, self.w) }
+    #[inline(always)] pure fn pqss(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.x, self.x) }
+    #[inline(always)] pure fn pqst(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.x, self.y) }
+    #[inline(always)] pure fn pqsp(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.x, self.z) }
+    #[inline(always)] pure fn pqsq(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.x, self.w) }
+    #[inline(always)] pure fn pqts(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.y, self.x) }
+    #[inline(always)] pure fn pqtt(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.y, self.y) }
+    #[inline(always)] pure fn pqtp(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.y, self.z) }
+    #[inline(always)] pure fn pqtq(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.y, self.w) }
+    #[inline(always)] pure fn pqps(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.z, self.x) }
+    #[inline(always)] pure fn pqpt(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.z, self.y) }
+    #[inline(always)] pure fn pqpp(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.z, self.z) }
+    #[inline(always)] pure fn pqpq(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.z, self.w) }
+    #[inline(always)] pure fn pqqs(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.w, self.x) }
+    #[inline(always)] pure fn pqqt(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.w, self.y) }
+    #[inline(always)] pure fn pqqp(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.w, self.z) }
+    #[inline(always)] pure fn pqqq(&self) -> Vec4<T> { Vector4::new(self.z, self.w, self.w, self.w) }
+    #[inline(always)] pure fn qsss(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.x, self.x) }
+    #[inline(always)] pure fn qsst(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.x, self.y) }
+    #[inline(always)] pure fn qssp(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.x, self.z) }
+    #[inline(always)] pure fn qssq(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.x, self.w) }
+    #[inline(always)] pure fn qsts(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.y, self.x) }
+    #[inline(always)] pure fn qstt(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.y, self.y) }
+    #[inline(always)] pure fn qstp(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.y, self.z) }
+    #[inline(always)] pure fn qstq(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.y, self.w) }
+    #[inline(always)] pure fn qsps(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.z, self.x) }
+    #[inline(always)] pure fn qspt(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.z, self.y) }
+    #[inline(always)] pure fn qspp(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.z, self.z) }
+    #[inline(always)] pure fn qspq(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.z, self.w) }
+    #[inline(always)] pure fn qsqs(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.w, self.x) }
+    #[inline(always)] pure fn qsqt(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.w, self.y) }
+    #[inline(always)] pure fn qsqp(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.w, self.z) }
+    #[inline(always)] pure fn qsqq(&self) -> Vec4<T> { Vector4::new(self.w, self.x, self.w, self.w) }
+    #[inline(always)] pure fn qtss(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.x, self.x) }
+    #[inline(always)] pure fn qtst(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.x, self.y) }
+    #[inline(always)] pure fn qtsp(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.x, self.z) }
+    #[inline(always)] pure fn qtsq(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.x, self.w) }
+    #[inline(always)] pure fn qtts(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.y, self.x) }
+    #[inline(always)] pure fn qttt(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.y, self.y) }
+    #[inline(always)] pure fn qttp(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.y, self.z) }
+    #[inline(always)] pure fn qttq(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.y, self.w) }
+    #[inline(always)] pure fn qtps(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.z, self.x) }
+    #[inline(always)] pure fn qtpt(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.z, self.y) }
+    #[inline(always)] pure fn qtpp(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.z, self.z) }
+    #[inline(always)] pure fn qtpq(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.z, self.w) }
+    #[inline(always)] pure fn qtqs(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.w, self.x) }
+    #[inline(always)] pure fn qtqt(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.w, self.y) }
+    #[inline(always)] pure fn qtqp(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.w, self.z) }
+    #[inline(always)] pure fn qtqq(&self) -> Vec4<T> { Vector4::new(self.w, self.y, self.w, self.w) }
+    #[inline(always)] pure fn qpss(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.x, self.x) }
+    #[inline(always)] pure fn qpst(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.x, self.y) }
+    #[inline(always)] pure fn qpsp(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.x, self.z) }
+    #[inline(always)] pure fn qpsq(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.x, self.w) }
+    #[inline(always)] pure fn qpts(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.y, self.x) }
+    #[inline(always)] pure fn qptt(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.y, self.y) }
+    #[inline(always)] pure fn qptp(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.y, self.z) }
+    #[inline(always)] pure fn qptq(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.y, self.w) }
+    #[inline(always)] pure fn qpps(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.z, self.x) }
+    #[inline(always)] pure fn qppt(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.z, self.y) }
+    #[inline(always)] pure fn qppp(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.z, self.z) }
+    #[inline(always)] pure fn qppq(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.z, self.w) }
+    #[inline(always)] pure fn qpqs(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.w, self.x) }
+    #[inline(always)] pure fn qpqt(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.w, self.y) }
+    #[inline(always)] pure fn qpqp(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.w, self.z) }
+    #[inline(always)] pure fn qpqq(&self) -> Vec4<T> { Vector4::new(self.w, self.z, self.w, self.w) }
+    #[inline(always)] pure fn qqss(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.x, self.x) }
+    #[inline(always)] pure fn qqst(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.x, self.y) }
+    #[inline(always)] pure fn qqsp(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.x, self.z) }
+    #[inline(always)] pure fn qqsq(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.x, self.w) }
+    #[inline(always)] pure fn qqts(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.y, self.x) }
+    #[inline(always)] pure fn qqtt(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.y, self.y) }
+    #[inline(always)] pure fn qqtp(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.y, self.z) }
+    #[inline(always)] pure fn qqtq(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.y, self.w) }
+    #[inline(always)] pure fn qqps(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.z, self.x) }
+    #[inline(always)] pure fn qqpt(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.z, self.y) }
+    #[inline(always)] pure fn qqpp(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.z, self.z) }
+    #[inline(always)] pure fn qqpq(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.z, self.w) }
+    #[inline(always)] pure fn qqqs(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.w, self.x) }
+    #[inline(always)] pure fn qqqt(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.w, self.y) }
+    #[inline(always)] pure fn qqqp(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.w, self.z) }
+    #[inline(always)] pure fn qqqq(&self) -> Vec4<T> { Vector4::new(self.w, self.w, self.w, self.w) }
+    
+    #[inline(always)] fn set_st(&mut self, v: &Vec2<T>) { self.x = v.x; self.y = v.y; }
+    #[inline(always)] fn set_sp(&mut self, v: &Vec2<T>) { self.x = v.x; self.z = v.y; }
+    #[inline(always)] fn set_sq(&mut self, v: &Vec2<T>) { self.x = v.x; self.w = v.y; }
+    #[inline(always)] fn set_ts(&mut self, v: &Vec2<T>) { self.y = v.x; self.x = v.y; }
+    #[inline(always)] fn set_tp(&mut self, v: &Vec2<T>) { self.y = v.x; self.z = v.y; }
+    #[inline(always)] fn set_tq(&mut self, v: &Vec2<T>) { self.y = v.x; self.w = v.y; }
+    #[inline(always)] fn set_ps(&mut self, v: &Vec2<T>) { self.z = v.x; self.x = v.y; }
+    #[inline(always)] fn set_pt(&mut self, v: &Vec2<T>) { self.z = v.x; self.y = v.y; }
+    #[inline(always)] fn set_pq(&mut self, v: &Vec2<T>) { self.z = v.x; self.w = v.y; }
+    #[inline(always)] fn set_qs(&mut self, v: &Vec2<T>) { self.w = v.x; self.x = v.y; }
+    #[inline(always)] fn set_qt(&mut self, v: &Vec2<T>) { self.w = v.x; self.y = v.y; }
+    #[inline(always)] fn set_qp(&mut self, v: &Vec2<T>) { self.w = v.x; self.z = v.y; }
+    #[inline(always)] fn set_stp(&mut self, v: &Vec3<T>) { self.x = v.x; self.y = v.y; self.z = v.z; }
+    #[inline(always)] fn set_stq(&mut self, v: &Vec3<T>) { self.x = v.x; self.y = v.y; self.w = v.z; }
+    #[inline(always)] fn set_spt(&mut self, v: &Vec3<T>) { self.x = v.x; self.z = v.y; self.y = v.z; }
+    #[inline(always)] fn set_spq(&mut self, v: &Vec3<T>) { self.x = v.x; self.z = v.y; self.w = v.z; }
+    #[inline(always)] fn set_sqt(&mut self, v: &Vec3<T>) { self.x = v.x; self.w = v.y; self.y = v.z; }
+    #[inline(always)] fn set_sqp(&mut self, v: &Vec3<T>) { self.x = v.x; self.w = v.y; self.z = v.z; }
+    #[inline(always)] fn set_tsp(&mut self, v: &Vec3<T>) { self.y = v.x; self.x = v.y; self.z = v.z; }
+    #[inline(always)] fn set_tsq(&mut self, v: &Vec3<T>) { self.y = v.x; self.x = v.y; self.w = v.z; }
+    #[inline(always)] fn set_tps(&mut self, v: &Vec3<T>) { self.y = v.x; self.z = v.y; self.x = v.z; }
+    #[inline(always)] fn set_tpq(&mut self, v: &Vec3<T>) { self.y = v.x; self.z = v.y; self.w = v.z; }
+    #[inline(always)] fn set_tqs(&mut self, v: &Vec3<T>) { self.y = v.x; self.w = v.y; self.x = v.z; }
+    #[inline(always)] fn set_tqp(&mut self, v: &Vec3<T>) { self.y = v.x; self.w = v.y; self.z = v.z; }
+    #[inline(always)] fn set_pst(&mut self, v: &Vec3<T>) { self.z = v.x; self.x = v.y; self.y = v.z; }
+    #[inline(always)] fn set_psq(&mut self, v: &Vec3<T>) { self.z = v.x; self.x = v.y; self.w = v.z; }
+    #[inline(always)] fn set_pts(&mut self, v: &Vec3<T>) { self.z = v.x; self.y = v.y; self.x = v.z; }
+    #[inline(always)] fn set_ptq(&mut self, v: &Vec3<T>) { self.z = v.x; self.y = v.y; self.w = v.z; }
+    #[inline(always)] fn set_pqs(&mut self, v: &Vec3<T>) { self.z = v.x; self.w = v.y; self.x = v.z; }
+    #[inline(always)] fn set_pqt(&mut self, v: &Vec3<T>) { self.z = v.x; self.w = v.y; self.y = v.z; }
+    #[inline(always)] fn set_qst(&mut self, v: &Vec3<T>) { self.w = v.x; self.x = v.y; self.y = v.z; }
+    #[inline(always)] fn set_qsp(&mut self, v: &Vec3<T>) { self.w = v.x; self.x = v.y; self.z = v.z; }
+    #[inline(always)] fn set_qts(&mut self, v: &Vec3<T>) { self.w = v.x; self.y = v.y; self.x = v.z; }
+    #[inline(always)] fn set_qtp(&mut self, v: &Vec3<T>) { self.w = v.x; self.y = v.y; self.z = v.z; }
+    #[inline(always)] fn set_qps(&mut self, v: &Vec3<T>) { self.w = v.x; self.z = v.y; self.x = v.z; }
+    #[inline(always)] fn set_qpt(&mut self, v: &Vec3<T>) { self.w = v.x; self.z = v.y; self.y = v.z; }
+    #[inline(always)] fn set_stpq(&mut self, v: &Vec4<T>) { self.x = v.x; self.y = v.y; self.z = v.z; self.w = v.w; }
+    #[inline(always)] fn set_stqp(&mut self, v: &Vec4<T>) { self.x = v.x; self.y = v.y; self.w = v.z; self.z = v.w; }
+    #[inline(always)] fn set_sptq(&mut self, v: &Vec4<T>) { self.x = v.x; self.z = v.y; self.y = v.z; self.w = v.w; }
+    #[inline(always)] fn set_spqt(&mut self, v: &Vec4<T>) { self.x = v.x; self.z = v.y; self.w = v.z; self.y = v.w; }
+    #[inline(always)] fn set_sqtp(&mut self, v: &Vec4<T>) { self.x = v.x; self.w = v.y; self.y = v.z; self.z = v.w; }
+    #[inline(always)] fn set_sqpt(&mut self, v: &Vec4<T>) { self.x = v.x; self.w = v.y; self.z = v.z; self.y = v.w; }
+    #[inline(always)] fn set_tspq(&mut self, v: &Vec4<T>) { self.y = v.x; self.x = v.y; self.z = v.z; self.w = v.w; }
+    #[inline(always)] fn set_tsqp(&mut self, v: &Vec4<T>) { self.y = v.x; self.x = v.y; self.w = v.z; self.z = v.w; }
+    #[inline(always)] fn set_tpsq(&mut self, v: &Vec4<T>) { self.y = v.x; self.z = v.y; self.x = v.z; self.w = v.w; }
+    #[inline(always)] fn set_tpqs(&mut self, v: &Vec4<T>) { self.y = v.x; self.z = v.y; self.w = v.z; self.x = v.w; }
+    #[inline(always)] fn set_tqsp(&mut self, v: &Vec4<T>) { self.y = v.x; self.w = v.y; self.x = v.z; self.z = v.w; }
+    #[inline(always)] fn set_tqps(&mut self, v: &Vec4<T>) { self.y = v.x; self.w = v.y; self.z = v.z; self.x = v.w; }
+    #[inline(always)] fn set_pstq(&mut self, v: &Vec4<T>) { self.z = v.x; self.x = v.y; self.y = v.z; self.w = v.w; }
+    #[inline(always)] fn set_psqt(&mut self, v: &Vec4<T>) { self.z = v.x; self.x = v.y; self.w = v.z; self.y = v.w; }
+    #[inline(always)] fn set_ptsq(&mut self, v: &Vec4<T>) { self.z = v.x; self.y = v.y; self.x = v.z; self.w = v.w; }
+    #[inline(always)] fn set_ptqs(&mut self, v: &Vec4<T>) { self.z = v.x; self.y = v.y; self.w = v.z; self.x = v.w; }
+    #[inline(always)] fn set_pqst(&mut self, v: &Vec4<T>) { self.z = v.x; self.w = v.y; self.x = v.z; self.y = v.w; }
+    #[inline(always)] fn set_pqts(&mut self, v: &Vec4<T>) { self.z = v.x; self.w = v.y; self.y = v.z; self.x = v.w; }
+    #[inline(always)] fn set_qstp(&mut self, v: &Vec4<T>) { self.w = v.x; self.x = v.y; self.y = v.z; self.z = v.w; }
+    #[inline(always)] fn set_qspt(&mut self, v: &Vec4<T>) { self.w = v.x; self.x = v.y; self.z = v.z; self.y = v.w; }
+    #[inline(always)] fn set_qtsp(&mut self, v: &Vec4<T>) { self.w = v.x; self.y = v.y; self.x = v.z; self.z = v.w; }
+    #[inline(always)] fn set_qtps(&mut self, v: &Vec4<T>) { self.w = v.x; self.y = v.y; self.z = v.z; self.x = v.w; }
+    #[inline(always)] fn set_qpst(&mut self, v: &Vec4<T>) { self.w = v.x; self.z = v.y; self.x = v.z; self.y = v.w; }
+    #[inline(always)] fn set_qpts(&mut self, v: &Vec4<T>) { self.w = v.x; self.z = v.y; self.y = v.z; self.x = v.w; }
+}
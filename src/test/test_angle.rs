@@ -0,0 +1,63 @@
+use std::cmp::FuzzyEq;
+use numeric::*;
+use numeric::float::Float;
+
+use angle::*;
+
+#[test]
+fn test_angle_conversion() {
+    assert Deg(180f64).to_rad().fuzzy_eq(&Rad(Float::pi()));
+    assert Rad(Float::frac_pi_2::<f64>()).to_deg().fuzzy_eq(&Deg(90.0));
+    assert Deg(45f64).to_deg() == Deg(45.0);
+    assert Rad(0.5f64).to_rad() == Rad(0.5);
+    
+    assert Angle::full_turn::<f64, Deg<f64>>() == Deg(360.0);
+    assert Angle::full_turn::<f64, Rad<f64>>().to_deg().fuzzy_eq(&Deg(360.0));
+    assert Angle::quarter_turn::<f64, Rad<f64>>().to_deg().fuzzy_eq(&Deg(90.0));
+}
+
+#[test]
+fn test_angle_arithmetic() {
+    let a = Deg(30f64);
+    let b = Deg(45f64);
+    
+    assert a + b == Deg(75.0);
+    assert a - b == Deg(-15.0);
+    assert -a == Deg(-30.0);
+    assert a * 2.0 == Deg(60.0);
+    assert b / 3.0 == Deg(15.0);
+    assert a.mul_t(2.0) == a * 2.0;
+    assert b.div_t(3.0) == b / 3.0;
+    assert a < b;
+    
+    assert Rad(1f64) + Rad(0.5) == Rad(1.5);
+}
+
+#[test]
+fn test_angle_wrap() {
+    assert Deg(370f64).wrap().fuzzy_eq(&Deg(10.0));
+    assert Deg(-90f64).wrap().fuzzy_eq(&Deg(270.0));
+    assert Deg(360f64).wrap().fuzzy_eq(&Deg(0.0));
+    assert Deg(270f64).wrap_signed().fuzzy_eq(&Deg(-90.0));
+    assert Deg(-190f64).wrap_signed().fuzzy_eq(&Deg(170.0));
+    assert Deg(45f64).wrap_signed().fuzzy_eq(&Deg(45.0));
+    
+    let pi: f64 = Float::pi();
+    
+    assert Rad(3.0 * pi).wrap().fuzzy_eq(&Rad(pi));
+    assert Rad(-0.5 * pi).wrap_signed().fuzzy_eq(&Rad(-0.5 * pi));
+    assert Rad(1.5 * pi).wrap_signed().fuzzy_eq(&Rad(-0.5 * pi));
+}
+
+#[test]
+fn test_angle_trig() {
+    assert Deg(30f64).sin().fuzzy_eq(&0.5);
+    assert Deg(60f64).cos().fuzzy_eq(&0.5);
+    assert Deg(45f64).tan().fuzzy_eq(&1.0);
+    assert Rad(0f64).cos() == 1.0;
+    
+    assert Rad::asin(0.5f64).to_deg().fuzzy_eq(&Deg(30.0));
+    assert Rad::acos(0.5f64).to_deg().fuzzy_eq(&Deg(60.0));
+    assert Rad::atan(1f64).to_deg().fuzzy_eq(&Deg(45.0));
+    assert Rad::atan2(1f64, -1.0).to_deg().fuzzy_eq(&Deg(135.0));
+}
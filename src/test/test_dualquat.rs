@@ -0,0 +1,92 @@
+use std::cmp::FuzzyEq;
+use numeric::*;
+use numeric::float::Float;
+
+use angle::*;
+use dualquat::*;
+use mat::*;
+use quat::*;
+use vec::*;
+
+#[test]
+fn test_dualquat() {
+    let r = dquat::from_angle_axis(Rad(Float::frac_pi_2()), &dvec3::unit_z());
+    let a = ddualquat::from_rotation_translation(r, &dvec3::new(1.0, 2.0, 3.0));
+    
+    assert a.rotation().fuzzy_eq(&r);
+    assert a.translation().fuzzy_eq(&dvec3::new(1.0, 2.0, 3.0));
+    assert a.transform_point(&dvec3::new(1.0, 0.0, 0.0)).fuzzy_eq(&dvec3::new(1.0, 3.0, 3.0));
+    assert a.transform_vector(&dvec3::new(1.0, 0.0, 0.0)).fuzzy_eq(&dvec3::new(0.0, 1.0, 0.0));
+    assert a.to_mat4().mul_v(&dvec4::new(1.0, 0.0, 0.0, 1.0)).fuzzy_eq(&dvec4::new(1.0, 3.0, 3.0, 1.0));
+    assert ddualquat::from_mat4(&a.to_mat4()).fuzzy_eq(&a);
+    
+    let b = ddualquat::from_rotation_translation(dquat::from_angle_axis(Rad(1.0), &dvec3::new(0.6, 0.0, 0.8)),
+                                                 &dvec3::new(-1.0, 0.5, 2.0));
+    
+    assert a.mul_dq(&b).to_mat4().fuzzy_eq(&a.to_mat4().mul_m(&b.to_mat4()));
+    assert a.mul_dq(&a.inverse()).fuzzy_eq(&ddualquat::identity());
+    assert a.conjugate().fuzzy_eq(&a.inverse());
+    assert a.inverse().transform_point(&a.transform_point(&dvec3::new(4.0, 5.0, 6.0))).fuzzy_eq(&dvec3::new(4.0, 5.0, 6.0));
+    
+    let c = ddualquat::new(a.real.mul_t(2.0), a.dual.mul_t(2.0).add_q(&a.real.mul_t(0.5)));
+    
+    assert c.normalize().fuzzy_eq(&a);
+    assert ddualquat::identity().normalize().fuzzy_eq(&ddualquat::identity());
+}
+
+#[test]
+fn test_dualquat_sclerp() {
+    let a = ddualquat::from_rotation_translation(dquat::from_angle_z(Rad(0.4)), &dvec3::new(1.0, 2.0, 3.0));
+    let b = ddualquat::from_rotation_translation(dquat::from_angle_axis(Rad(2.0), &dvec3::new(0.0, 0.6, 0.8)),
+                                                 &dvec3::new(-2.0, 1.0, 0.5));
+    
+    assert b.pow(1.0).fuzzy_eq(&b);
+    assert b.pow(0.0).fuzzy_eq(&ddualquat::identity());
+    assert b.pow(0.5).mul_dq(&b.pow(0.5)).fuzzy_eq(&b);
+    
+    assert a.sclerp(&b, 0.0).fuzzy_eq(&a);
+    assert a.sclerp(&b, 1.0).fuzzy_eq(&b);
+    assert a.sclerp(&-b, 0.3).fuzzy_eq(&a.sclerp(&b, 0.3));
+    assert a.sclerp(&b, 0.3).rotation().fuzzy_eq(&a.rotation().slerp(&b.rotation(), 0.3));
+    
+    // a pure translation is interpolated linearly
+    let t = ddualquat::from_rotation_translation(dquat::identity(), &dvec3::new(4.0, 0.0, -2.0));
+    
+    assert ddualquat::identity().sclerp(&t, 0.25).translation().fuzzy_eq(&dvec3::new(1.0, 0.0, -0.5));
+    
+    // a negative real part represents the same translation
+    let u = ddualquat::from_rotation_translation(-dquat::identity(), &dvec3::new(4.0, 0.0, -2.0));
+    
+    assert u.pow(1.0).fuzzy_eq(&-u);
+    assert u.pow(1.0).translation().fuzzy_eq(&dvec3::new(4.0, 0.0, -2.0));
+    assert u.pow(0.25).fuzzy_eq(&t.pow(0.25));
+    
+    // a rotation around the `z` axis through `(1, 0, 0)` moves points along
+    // circular arcs, rather than straight lines
+    let p = ddualquat::from_rotation_translation(dquat::identity(), &dvec3::new(1.0, 0.0, 0.0));
+    let q = p.mul_dq(&ddualquat::from_rotation_translation(dquat::from_angle_z(Rad(Float::frac_pi_2())),
+                                                           &dvec3::new(0.0, 0.0, 0.0)));
+    let m = p.sclerp(&q, 0.5);
+    
+    assert m.rotation().fuzzy_eq(&dquat::from_angle_z(Rad(Float::frac_pi_4())));
+    assert m.transform_point(&dvec3::new(0.0, 0.0, 0.0)).fuzzy_eq(&dvec3::new(1.0, 0.0, 0.0));
+    assert m.transform_point(&dvec3::new(1.0, 0.0, 0.0)).fuzzy_eq(&dvec3::new(1.0 + sqrt(0.5), sqrt(0.5), 0.0));
+}
+
+#[test]
+fn test_dualquat_dlb() {
+    let a = ddualquat::from_rotation_translation(dquat::from_angle_z(Rad(0.4)), &dvec3::new(1.0, 2.0, 3.0));
+    let b = ddualquat::from_rotation_translation(dquat::from_angle_z(Rad(1.0)), &dvec3::new(1.0, 2.0, 3.0));
+    
+    assert dlb(~[a], ~[1.0]).fuzzy_eq(&a);
+    assert dlb(~[a, b], ~[0.0, 1.0]).fuzzy_eq(&b);
+    
+    // the blend takes the shortest path, regardless of the sign
+    let c = dlb(~[a, -b], ~[0.5, 0.5]);
+    
+    assert c.fuzzy_eq(&dlb(~[a, b], ~[0.5, 0.5]));
+    assert c.rotation().fuzzy_eq(&dquat::from_angle_z(Rad(0.7)));
+    assert c.translation().fuzzy_eq(&dvec3::new(1.0, 2.0, 3.0));
+    assert c.real.magnitude().fuzzy_eq(&1.0);
+    assert c.real.dot(&c.dual).fuzzy_eq(&0.0);
+}
@@ -0,0 +1,83 @@
+use std::cmp::FuzzyEq;
+use numeric::*;
+use numeric::float::Float;
+
+use angle::*;
+use euler::*;
+use mat::*;
+use quat::*;
+
+#[test]
+fn test_euler() {
+    let e = deuler::new(Rad(0.3), Rad(-0.7), Rad(1.1), XYZ, Extrinsic);
+    let m = dmat3::from_angle_z(Rad(1.1)).mul_m(&dmat3::from_angle_y(Rad(-0.7))).mul_m(&dmat3::from_angle_x(Rad(0.3)));
+    
+    assert e.to_mat3().fuzzy_eq(&m);
+    assert e.to_quat().to_mat3().fuzzy_eq(&m);
+    
+    // extrinsic angles are equivalent to intrinsic angles in reverse order
+    assert deuler::new(Rad(1.1), Rad(-0.7), Rad(0.3), ZYX, Intrinsic).to_mat3().fuzzy_eq(&m);
+    assert e.to_order(ZYX, Intrinsic).fuzzy_eq(&deuler::new(Rad(1.1), Rad(-0.7), Rad(0.3), ZYX, Intrinsic));
+    
+    // `from_angle_xyz` uses extrinsic `XYZ` angles
+    assert dmat3::from_angle_xyz(Rad(0.3), Rad(-0.7), Rad(1.1)).fuzzy_eq(&m);
+    assert dquat::from_angle_xyz(Rad(0.3), Rad(-0.7), Rad(1.1)).to_mat3().fuzzy_eq(&m);
+    
+    assert deuler::new(Deg(90.0), Deg(0.0), Deg(0.0), XYZ, Extrinsic)
+        .fuzzy_eq(&deuler::new(Rad(Float::frac_pi_2()), Rad(0.0), Rad(0.0), XYZ, Extrinsic));
+}
+
+#[test]
+fn test_euler_round_trip() {
+    for [XYZ, XZY, YXZ, YZX, ZXY, ZYX,
+         XYX, XZX, YXY, YZY, ZXZ, ZYZ].each |order| {
+        for [Extrinsic, Intrinsic].each |frame| {
+            let e = deuler::new(Rad(0.3), Rad(0.7), Rad(-1.1), *order, *frame);
+            let m = e.to_mat3();
+            
+            assert e.to_quat().to_mat3().fuzzy_eq(&m);
+            assert deuler::from_mat3(&m, *order, *frame).fuzzy_eq(&e);
+            assert deuler::from_quat(&e.to_quat(), *order, *frame).fuzzy_eq(&e);
+            
+            // every order can express every rotation
+            for [XYZ, ZYX, XYX, ZXZ].each |other| {
+                assert e.to_order(*other, Intrinsic).to_mat3().fuzzy_eq(&m);
+                assert e.to_order(*other, Extrinsic).to_mat3().fuzzy_eq(&m);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_euler_gimbal_lock() {
+    let pi = 2.0 * Float::frac_pi_2();
+    
+    // at a gimbal lock the first angle holds the combined rotation of the
+    // first and third axes
+    for [Extrinsic, Intrinsic].each |frame| {
+        let e = deuler::new(Rad(0.3), Rad(Float::frac_pi_2()), Rad(0.4), XYZ, *frame);
+        let f = deuler::from_mat3(&e.to_mat3(), XYZ, *frame);
+        
+        assert f.b.fuzzy_eq(&Rad(Float::frac_pi_2()));
+        assert f.c.fuzzy_eq(&Rad(0.0));
+        assert f.to_mat3().fuzzy_eq(&e.to_mat3());
+        
+        let e = deuler::new(Rad(0.3), Rad(-Float::frac_pi_2()), Rad(0.4), YZX, *frame);
+        let f = deuler::from_mat3(&e.to_mat3(), YZX, *frame);
+        
+        assert f.b.fuzzy_eq(&Rad(-Float::frac_pi_2()));
+        assert f.c.fuzzy_eq(&Rad(0.0));
+        assert f.to_mat3().fuzzy_eq(&e.to_mat3());
+        
+        let e = deuler::new(Rad(0.3), Rad(0.0), Rad(0.4), ZXZ, *frame);
+        
+        assert deuler::from_mat3(&e.to_mat3(), ZXZ, *frame).fuzzy_eq(&deuler::new(Rad(0.7), Rad(0.0), Rad(0.0), ZXZ, *frame));
+        
+        let e = deuler::new(Rad(0.3), Rad(pi), Rad(0.4), YXY, *frame);
+        let f = deuler::from_mat3(&e.to_mat3(), YXY, *frame);
+        
+        assert f.b.fuzzy_eq(&Rad(pi));
+        assert f.c.fuzzy_eq(&Rad(0.0));
+        assert f.to_mat3().fuzzy_eq(&e.to_mat3());
+    }
+}
@@ -0,0 +1,68 @@
+use std::cmp::FuzzyEq;
+use numeric::*;
+
+use angle::*;
+use frustum::*;
+use mat::*;
+use plane::*;
+use projection::*;
+use vec::*;
+
+#[test]
+fn test_plane() {
+    let p = plane::from_abcd(0.0, 2.0, 0.0, -4.0);
+    
+    assert p.distance(&vec3::new(5.0, 3.0, 1.0)) == 2.0;
+    assert p.normalize() == plane::new(vec3::new(0.0, 1.0, 0.0), -2.0);
+    assert p.normalize().distance(&vec3::new(5.0, 3.0, 1.0)) == 1.0;
+    assert p.flip().distance(&vec3::new(5.0, 3.0, 1.0)) == -2.0;
+    
+    assert plane::from_point_normal(&vec3::new(1.0, 2.0, 3.0), &vec3::new(0.0, 0.0, 1.0)) == plane::new(vec3::new(0.0, 0.0, 1.0), -3.0);
+    assert plane::from_vec4(&vec4::new(1.0, 2.0, 3.0, 4.0)) == plane::from_abcd(1.0, 2.0, 3.0, 4.0);
+}
+
+#[test]
+fn test_frustum_from_mat4() {
+    let f = frustum::from_bounds(-1f32, 1f32, -1f32, 1f32, 1f32, 10f32);
+    let r = 0.70710678;
+    
+    assert f.left.fuzzy_eq(&plane::new(vec3::new(r, 0.0, -r), 0.0));
+    assert f.right.fuzzy_eq(&plane::new(vec3::new(-r, 0.0, -r), 0.0));
+    assert f.bottom.fuzzy_eq(&plane::new(vec3::new(0.0, r, -r), 0.0));
+    assert f.top.fuzzy_eq(&plane::new(vec3::new(0.0, -r, -r), 0.0));
+    assert f.near.fuzzy_eq(&plane::new(vec3::new(0.0, 0.0, -1.0), -1.0));
+    assert f.far.fuzzy_eq(&plane::new(vec3::new(0.0, 0.0, 1.0), 10.0));
+    
+    assert f.fuzzy_eq(&frustum::from_mat4(&perspective(Deg(90f32), 1f32, 1f32, 10f32)));
+    assert f.fuzzy_eq(&frustum::from_mat4_zo(&perspective_zo(Deg(90f32), 1f32, 1f32, 10f32)));
+    
+    // moving the camera moves the frustum into world space
+    let view = mat4::from_translation(&vec3::new(0.0, 0.0, -5.0));
+    let g = frustum::from_mat4(&perspective(Deg(90f32), 1f32, 1f32, 10f32).mul_m(&view));
+    
+    assert g.near.fuzzy_eq(&plane::new(vec3::new(0.0, 0.0, -1.0), 4.0));
+    assert g.far.fuzzy_eq(&plane::new(vec3::new(0.0, 0.0, 1.0), 5.0));
+}
+
+#[test]
+fn test_frustum_classify() {
+    let f = frustum::from_bounds(-1f32, 1f32, -1f32, 1f32, 1f32, 10f32);
+    
+    assert f.classify_point(&vec3::new(0.0, 0.0, -5.0)) == Inside;
+    assert f.classify_point(&vec3::new(4.0, 0.0, -5.0)) == Inside;
+    assert f.classify_point(&vec3::new(6.0, 0.0, -5.0)) == Outside;
+    assert f.classify_point(&vec3::new(0.0, 0.0, -0.5)) == Outside;
+    assert f.classify_point(&vec3::new(0.0, 0.0, -11.0)) == Outside;
+    
+    assert f.classify_sphere(&vec3::new(0.0, 0.0, -5.0), 1.0) == Inside;
+    assert f.classify_sphere(&vec3::new(0.0, 0.0, -10.0), 1.0) == Intersecting;
+    assert f.classify_sphere(&vec3::new(0.0, 0.0, 2.0), 1.0) == Outside;
+    assert f.classify_sphere(&vec3::new(0.0, -8.0, -5.0), 1.0) == Outside;
+    assert f.classify_sphere(&vec3::new(0.0, -8.0, -5.0), 3.0) == Intersecting;
+    
+    assert f.classify_aabb(&vec3::new(-1.0, -1.0, -6.0), &vec3::new(1.0, 1.0, -4.0)) == Inside;
+    assert f.classify_aabb(&vec3::new(-1.0, -1.0, -12.0), &vec3::new(1.0, 1.0, -8.0)) == Intersecting;
+    assert f.classify_aabb(&vec3::new(-20.0, -20.0, -20.0), &vec3::new(20.0, 20.0, 20.0)) == Intersecting;
+    assert f.classify_aabb(&vec3::new(7.0, -1.0, -6.0), &vec3::new(8.0, 1.0, -4.0)) == Outside;
+    assert f.classify_aabb(&vec3::new(-1.0, -1.0, 1.0), &vec3::new(1.0, 1.0, 2.0)) == Outside;
+}
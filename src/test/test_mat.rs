@@ -1,4 +1,9 @@
+use std::cmp::FuzzyEq;
+use numeric::*;
+
+use angle::*;
 use mat::*;
+use quat::*;
 use vec::*;
 
 // TODO
@@ -560,4 +565,591 @@ fn test_mat4_fuzzy_eq() {
                      0.0000001, 0.0000001, 0.0000001, 0.0000001,
                      0.0000001, 0.0000001, 0.0000001, 0.0000001,
                      0.0000001, 0.0000001, 0.0000001, 0.0000001).fuzzy_eq(&mat4::zero());
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_mat_ops() {
+    let a = mat2::new(1.0, 3.0,
+                      2.0, 4.0);
+    let b = mat2::new(2.0, 4.0,
+                      3.0, 5.0);
+    let v = vec2::new(1.0, 2.0);
+    
+    assert a + b == a.add_m(&b);
+    assert a - b == a.sub_m(&b);
+    assert a * b == a.mul_m(&b);
+    assert a * v == a.mul_v(&v);
+    assert a * 0.5f32 == a.mul_t(0.5);
+    assert a / 2.0f32 == a.mul_t(0.5);
+    
+    let mut c = a;
+    c *= b;
+    assert c == a.mul_m(&b);
+    
+    let m = mat3::from_angle_z(Rad(1.0f32));
+    let n = mat3::from_angle_x(Rad(0.5f32));
+    let u = vec3::new(1.0, 2.0, 3.0);
+    
+    assert m * n * u == m.mul_m(&n).mul_v(&u);
+    assert (m + n) * u == m.add_m(&n).mul_v(&u);
+    
+    let p = mat4::from_value(2.0);
+    
+    assert p * vec4::new(1.0, 2.0, 3.0, 4.0) == vec4::new(2.0, 4.0, 6.0, 8.0);
+    assert p * p == mat4::from_value(4.0);
+    assert p - p == mat4::zero();
+    assert p / 2.0f32 == mat4::identity();
+}
+
+#[test]
+fn test_mat4_transform() {
+    let p = vec4::new(1.0, 2.0, 3.0, 1.0);
+    let d = vec4::new(1.0, 2.0, 3.0, 0.0);
+    
+    let t = mat4::from_translation(&vec3::new(10.0, 20.0, 30.0));
+    
+    assert t.mul_v(&p) == vec4::new(11.0, 22.0, 33.0, 1.0);
+    assert t.mul_v(&d) == d;
+    assert t.inverse().unwrap() == mat4::from_translation(&vec3::new(-10.0, -20.0, -30.0));
+    
+    let s = mat4::from_scale(&vec3::new(2.0, 3.0, 4.0));
+    
+    assert s.mul_v(&p) == vec4::new(2.0, 6.0, 12.0, 1.0);
+    assert mat4::from_uniform_scale(2.0) == mat4::from_scale(&vec3::new(2.0, 2.0, 2.0));
+    
+    let axis = vec3::new(1.0, 2.0, 2.0).normalize();
+    let r = mat4::from_angle_axis(Rad(0.5), &axis);
+    
+    assert r == mat3::from_angle_axis(Rad(0.5), &axis).to_mat4();
+    assert r == mat4::from_mat3(&mat3::from_angle_axis(Rad(0.5), &axis));
+    assert mat4::from_quat(&quat::from_angle_axis(Rad(0.5), &axis)).fuzzy_eq(&r);
+    
+    // the composition helpers apply the new transformation first
+    assert t.scale(&vec3::new(2.0, 3.0, 4.0)) == t.mul_m(&s);
+    assert t.rotate(Rad(0.5), &axis) == t.mul_m(&r);
+    assert s.translate(&vec3::new(10.0, 20.0, 30.0)) == s.mul_m(&t);
+    assert s.translate(&vec3::new(1.0, 1.0, 1.0)).mul_v(&p) == vec4::new(4.0, 9.0, 16.0, 1.0);
+    assert mat4::identity().translate(&vec3::new(10.0, 20.0, 30.0)).scale(&vec3::new(2.0, 3.0, 4.0))
+        .mul_v(&p) == vec4::new(12.0, 26.0, 42.0, 1.0);
+}
+
+#[test]
+fn test_mat4_look_at() {
+    let eye = vec3::new(1.0, 2.0, 3.0);
+    let v = mat4::look_at(&eye, &vec3::new(1.0, 2.0, 0.0), &vec3::unit_y());
+    
+    // the eye is moved to the origin, looking down the negative z axis
+    assert v.mul_v(&vec4::new(1.0, 2.0, 3.0, 1.0)).fuzzy_eq(&vec4::new(0.0, 0.0, 0.0, 1.0));
+    assert v.mul_v(&vec4::new(1.0, 2.0, 0.0, 1.0)).fuzzy_eq(&vec4::new(0.0, 0.0, -3.0, 1.0));
+    assert v.mul_v(&vec4::new(1.0, 3.0, 3.0, 1.0)).fuzzy_eq(&vec4::new(0.0, 1.0, 0.0, 1.0));
+    assert v.mul_v(&vec4::new(2.0, 2.0, 3.0, 1.0)).fuzzy_eq(&vec4::new(1.0, 0.0, 0.0, 1.0));
+    
+    // looking down the positive x axis
+    let w = mat4::look_at(&vec3::zero(), &vec3::unit_x(), &vec3::unit_y());
+    assert w.mul_v(&vec4::new(5.0, 0.0, 0.0, 1.0)).fuzzy_eq(&vec4::new(0.0, 0.0, -5.0, 1.0));
+    assert w.mul_v(&vec4::new(0.0, 0.0, 1.0, 1.0)).fuzzy_eq(&vec4::new(1.0, 0.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_mat_non_square() {
+    let a = mat2x3::new(1.0, 2.0, 3.0,
+                        4.0, 5.0, 6.0);
+    
+    assert a.col(1) == vec3::new(4.0, 5.0, 6.0);
+    assert a.row(0) == vec2::new(1.0, 4.0);
+    assert a.mul_v(&vec2::new(1.0, 1.0)) == vec3::new(5.0, 7.0, 9.0);
+    
+    assert a.transpose() == mat3x2::new(1.0, 4.0,
+                                        2.0, 5.0,
+                                        3.0, 6.0);
+    assert a.transpose().transpose() == a;
+    
+    assert a.mul_m3x2(&a.transpose()) == mat3::new(17.0, 22.0, 27.0,
+                                                   22.0, 29.0, 36.0,
+                                                   27.0, 36.0, 45.0);
+    assert a.transpose().mul_m2x3(&a) == mat2::new(14.0, 32.0,
+                                                   32.0, 77.0);
+    assert a.mul_m2(&mat2::identity()) == a;
+    assert a.mul_m4x2(&mat4x2::zero()) == mat4x3::zero();
+    
+    assert mat2x3::from_value(2.0) == mat2x3::new(2.0, 0.0, 0.0,
+                                                  0.0, 2.0, 0.0);
+    assert mat4x2::identity() == mat4x2::new(1.0, 0.0,
+                                             0.0, 1.0,
+                                             0.0, 0.0,
+                                             0.0, 0.0);
+    
+    assert a + a == a * 2.0f32;
+    assert a - a == mat2x3::zero();
+    assert -a == a.mul_t(-1.0);
+    assert a / 2.0f32 == a.mul_t(0.5);
+    
+    let b = mat4x2::new(1.0, 2.0,
+                        3.0, 4.0,
+                        5.0, 6.0,
+                        7.0, 8.0);
+    
+    assert b.transpose() == mat2x4::new(1.0, 3.0, 5.0, 7.0,
+                                        2.0, 4.0, 6.0, 8.0);
+    assert b.mul_v(&vec4::new(1.0, 0.0, 0.0, 1.0)) == vec2::new(8.0, 10.0);
+    assert b.mul_m4(&mat4::identity()) == b;
+    
+    let c = mat3x4::new(1.0,  2.0,  3.0,  4.0,
+                        5.0,  6.0,  7.0,  8.0,
+                        9.0, 10.0, 11.0, 12.0);
+    
+    assert c.transpose().transpose() == c;
+    assert c.transpose().row(2) == c.col(2);
+    assert c.mul_m3(&mat3::from_value(2.0)) == c.mul_t(2.0);
+    
+    // the `*` operator dispatches on the right hand side, as for the square matrices
+    assert a * vec2::new(1.0, 1.0) == a.mul_v(&vec2::new(1.0, 1.0));
+    assert a * a.transpose() == a.mul_m3x2(&a.transpose());
+    assert a.transpose() * a == a.transpose().mul_m2x3(&a);
+    assert b * vec4::new(1.0, 0.0, 0.0, 1.0) == vec2::new(8.0, 10.0);
+    assert b * mat4::identity() == b;
+    assert c * mat3::from_value(2.0) == c * 2.0f32;
+    assert c.transpose() * c.mul_t(0.0) == mat3::zero();
+    
+    assert mat2x3::rows() == 3;
+    assert mat2x3::cols() == 2;
+}
+
+#[test]
+fn test_mat_non_square_affine() {
+    let m = mat4::from_translation(&vec3::new(1.0, 2.0, 3.0))
+                 .mul_m(&mat4::from_scale(&vec3::new(2.0, 2.0, 2.0)));
+    let a = mat4x3::from_mat4(&m);
+    
+    assert a.to_mat4() == m;
+    assert a.mul_v(&vec4::new(1.0, 1.0, 1.0, 1.0)) == vec3::new(3.0, 4.0, 5.0);
+    
+    let n = mat3::new(2.0, 0.0, 0.0,
+                      0.0, 3.0, 0.0,
+                      5.0, 6.0, 1.0);
+    let b = mat3x2::from_mat3(&n);
+    
+    assert b == mat3x2::new(2.0, 0.0,
+                            0.0, 3.0,
+                            5.0, 6.0);
+    assert b.to_mat3() == n;
+    assert b.mul_v(&vec3::new(1.0, 1.0, 1.0)) == vec2::new(7.0, 9.0);
+}
+
+#[test]
+fn test_mat3_symmetric_eigen() {
+    // a diagonal matrix is already decomposed, so only needs sorting
+    let (values, vectors) = dmat3::new(3.0, 0.0, 0.0,
+                                       0.0, 1.0, 0.0,
+                                       0.0, 0.0, 2.0).symmetric_eigen().unwrap();
+    
+    assert values == dvec3::new(1.0, 2.0, 3.0);
+    assert vectors == dmat3::from_cols(dvec3::new(0.0, 1.0, 0.0),
+                                       dvec3::new(0.0, 0.0, 1.0),
+                                       dvec3::new(1.0, 0.0, 0.0));
+    
+    // eigenvalues 1, 3 and 5, with eigenvectors (1, -1, 0), (1, 1, 0) and (0, 0, 1)
+    let a = dmat3::new(2.0, 1.0, 0.0,
+                       1.0, 2.0, 0.0,
+                       0.0, 0.0, 5.0);
+    let (values, vectors) = a.symmetric_eigen().unwrap();
+    let r = 0.70710678118654752;
+    
+    assert values.fuzzy_eq(&dvec3::new(1.0, 3.0, 5.0));
+    assert abs(vectors[0].dot(&dvec3::new(r, -r, 0.0))).fuzzy_eq(&1.0);
+    assert abs(vectors[1].dot(&dvec3::new(r, r, 0.0))).fuzzy_eq(&1.0);
+    assert abs(vectors[2].dot(&dvec3::new(0.0, 0.0, 1.0))).fuzzy_eq(&1.0);
+    
+    // a dense matrix with distinct eigenvalues
+    let b = dmat3::new(4.0, 1.0, 2.0,
+                       1.0, 3.0, 0.5,
+                       2.0, 0.5, 6.0);
+    let (values, vectors) = b.symmetric_eigen().unwrap();
+    let diag = dmat3::new(values.x, 0.0, 0.0,
+                          0.0, values.y, 0.0,
+                          0.0, 0.0, values.z);
+    
+    assert values.x <= values.y && values.y <= values.z;
+    assert (values.x + values.y + values.z).fuzzy_eq(&b.trace());
+    assert (values.x * values.y * values.z).fuzzy_eq(&b.determinant());
+    assert vectors.transpose().mul_m(&vectors).fuzzy_eq(&dmat3::identity());
+    assert vectors.determinant().fuzzy_eq(&1.0);
+    assert vectors.mul_m(&diag).mul_m(&vectors.transpose()).fuzzy_eq(&b);
+    
+    // each eigenvector is only scaled by the matrix
+    assert b.mul_v(&vectors[1]).fuzzy_eq(&vectors[1].mul_t(values.y));
+    
+    // the decomposition is only defined for symmetric matrices
+    assert dmat3::new(1.0, 2.0, 3.0,
+                      4.0, 5.0, 6.0,
+                      7.0, 8.0, 9.0).symmetric_eigen().is_none();
+}
+
+#[test]
+fn test_mat4_symmetric_eigen() {
+    let a = dmat4::new(4.0, 1.0, 0.0, 0.5,
+                       1.0, 3.0, 1.0, 0.0,
+                       0.0, 1.0, 2.0, 1.0,
+                       0.5, 0.0, 1.0, 1.0);
+    let (values, vectors) = a.symmetric_eigen().unwrap();
+    let diag = dmat4::new(values.x, 0.0, 0.0, 0.0,
+                          0.0, values.y, 0.0, 0.0,
+                          0.0, 0.0, values.z, 0.0,
+                          0.0, 0.0, 0.0, values.w);
+    
+    assert values.x <= values.y && values.y <= values.z && values.z <= values.w;
+    assert (values.x + values.y + values.z + values.w).fuzzy_eq(&a.trace());
+    assert vectors.transpose().mul_m(&vectors).fuzzy_eq(&dmat4::identity());
+    assert vectors.mul_m(&diag).mul_m(&vectors.transpose()).fuzzy_eq(&a);
+    assert a.mul_v(&vectors[3]).fuzzy_eq(&vectors[3].mul_t(values.w));
+    
+    assert dmat4::new(1.0, 2.0, 0.0, 0.0,
+                      0.0, 1.0, 0.0, 0.0,
+                      0.0, 0.0, 1.0, 0.0,
+                      0.0, 0.0, 0.0, 1.0).symmetric_eigen().is_none();
+}
+
+#[test]
+fn test_mat_lu() {
+    let a = dmat3::new( 2.0,  1.0, 1.0,
+                        4.0, -6.0, 0.0,
+                       -2.0,  7.0, 2.0);
+    let (p, l, u) = a.lu();
+    
+    assert p.mul_m(&a).fuzzy_eq(&l.mul_m(&u));
+    assert l[0][0] == 1.0 && l[1][1] == 1.0 && l[2][2] == 1.0;
+    assert l[1][0] == 0.0 && l[2][0] == 0.0 && l[2][1] == 0.0;
+    assert u[0][1] == 0.0 && u[0][2] == 0.0 && u[1][2] == 0.0;
+    
+    let b = dvec3::new(5.0, -2.0, 9.0);
+    let x = a.solve(&b).unwrap();
+    
+    assert a.mul_v(&x).fuzzy_eq(&b);
+    assert x.fuzzy_eq(&a.inverse().unwrap().mul_v(&b));
+    
+    // a zero in the top left corner requires a row swap
+    let c = mat2::new(0.0, 1.0,
+                      1.0, 0.0);
+    let (p, l, u) = c.lu();
+    
+    assert p == c;
+    assert l == mat2::identity();
+    assert u == mat2::identity();
+    assert c.solve(&vec2::new(2.0, 3.0)).unwrap() == vec2::new(3.0, 2.0);
+    
+    assert mat3::new(1.0, 2.0, 3.0,
+                     4.0, 5.0, 6.0,
+                     7.0, 8.0, 9.0).solve(&vec3::new(1.0, 2.0, 3.0)).is_none();
+    
+    let d = dmat4::new(4.0, 3.0, 2.0, 1.0,
+                       3.0, 5.0, 3.0, 2.0,
+                       2.0, 3.0, 6.0, 3.0,
+                       1.0, 2.0, 3.0, 7.0);
+    let e = dvec4::new(1.0, 2.0, 3.0, 4.0);
+    let (p, l, u) = d.lu();
+    
+    assert p.mul_m(&d).fuzzy_eq(&l.mul_m(&u));
+    assert d.mul_v(&d.solve(&e).unwrap()).fuzzy_eq(&e);
+}
+
+#[test]
+fn test_mat4_lu_inverse() {
+    let c = mat4::new(3.0, 2.0, 1.0, 1.0,
+                      2.0, 3.0, 2.0, 2.0,
+                      1.0, 2.0, 3.0, 3.0,
+                      0.0, 1.0, 1.0, 0.0);
+    
+    assert c.determinant().fuzzy_eq(&-8.0);
+    assert c.mul_m(&c.inverse().unwrap()).fuzzy_eq(&mat4::identity());
+    
+    // the determinant of a small scale is tiny, but the matrix is still
+    // perfectly well conditioned
+    let s = dmat4::from_value(0.01);
+    
+    assert s.determinant().fuzzy_eq_eps(&0.00000001, &0.0000000000001);
+    assert s.is_invertible();
+    assert s.inverse().unwrap().fuzzy_eq(&dmat4::from_value(100.0));
+    
+    // singularity is judged relative to the scale of the matrix
+    let s = dmat4::from_value(0.0000001);
+    
+    assert s.determinant() == 0.0000001 * 0.0000001 * 0.0000001 * 0.0000001;
+    assert s.is_invertible();
+    assert s.inverse().unwrap().mul_m(&s).fuzzy_eq(&dmat4::identity());
+    
+    let a = dmat4::new( 1.0,  2.0,  3.0,  4.0,
+                        5.0,  6.0,  7.0,  8.0,
+                        9.0, 10.0, 11.0, 12.0,
+                       13.0, 14.0, 15.0, 16.0);
+    
+    assert !a.is_invertible();
+    assert !a.mul_t(0.000001).is_invertible();
+    assert a.mul_t(1000000.0).inverse().is_none();
+    
+    let t = dmat4::from_translation(&dvec3::new(1.0, 2.0, 3.0))
+                  .mul_m(&dmat4::from_angle_axis(Rad(1.0), &dvec3::new(0.0, 0.6, 0.8)));
+    
+    assert t.determinant().fuzzy_eq(&1.0);
+    assert t.inverse().unwrap().mul_m(&t).fuzzy_eq(&dmat4::identity());
+}
+
+#[test]
+fn test_mat_qr() {
+    let a = dmat4::new(2.0, 1.0, 0.0, 1.0,
+                      -1.0, 3.0, 1.0, 0.0,
+                       0.5, 2.0, 4.0, 1.0,
+                       1.0, 0.0, 1.0, 2.0);
+    let (q, r) = a.qr();
+    
+    assert q.mul_m(&r).fuzzy_eq(&a);
+    assert q.transpose().mul_m(&q).fuzzy_eq(&dmat4::identity());
+    assert r[0][0] >= 0.0 && r[1][1] >= 0.0 && r[2][2] >= 0.0 && r[3][3] >= 0.0;
+    assert r[0][1].fuzzy_eq(&0.0) && r[0][2].fuzzy_eq(&0.0) && r[0][3].fuzzy_eq(&0.0);
+    assert r[1][2].fuzzy_eq(&0.0) && r[1][3].fuzzy_eq(&0.0) && r[2][3].fuzzy_eq(&0.0);
+    
+    let b = dmat3::new( 12.0,   6.0, -4.0,
+                       -51.0, 167.0, 24.0,
+                         4.0, -68.0, -41.0);
+    let (q, r) = b.qr();
+    
+    assert q.mul_m(&r).fuzzy_eq(&b);
+    assert q.transpose().mul_m(&q).fuzzy_eq(&dmat3::identity());
+    assert r.fuzzy_eq(&dmat3::new(14.0,   0.0,  0.0,
+                                  21.0, 175.0,  0.0,
+                                 -14.0, -70.0, 35.0));
+    
+    // a small matrix is decomposed the same way, just scaled down
+    let (q_small, r_small) = b.mul_t(1e-5).qr();
+    
+    assert q_small.fuzzy_eq(&q);
+    assert r_small.mul_t(1e5).fuzzy_eq(&r);
+    
+    // a rotation is already orthogonal
+    let c = mat2::from_angle(Rad(0.5f32));
+    let (q, r) = c.qr();
+    
+    assert q.fuzzy_eq(&c);
+    assert r.fuzzy_eq(&mat2::identity());
+}
+
+#[test]
+fn test_mat_svd() {
+    let a = dmat3::new(1.0, 2.0, 3.0,
+                       4.0, 5.0, 6.0,
+                       7.0, 8.0, 10.0);
+    let (u, s, vt) = a.svd();
+    
+    assert u.mul_m(&dmat3::new(s[0], 0.0,  0.0,
+                               0.0,  s[1], 0.0,
+                               0.0,  0.0,  s[2])).mul_m(&vt).fuzzy_eq(&a);
+    assert u.transpose().mul_m(&u).fuzzy_eq(&dmat3::identity());
+    assert vt.mul_m(&vt.transpose()).fuzzy_eq(&dmat3::identity());
+    assert s[0] >= s[1] && s[1] >= s[2] && s[2] > 0.0;
+    
+    let b = dmat2::new(3.0, 0.0,
+                       4.0, 5.0);
+    let (_, s, _) = b.svd();
+    
+    assert s.fuzzy_eq(&dvec2::new(sqrt(45.0), sqrt(5.0)));
+    
+    // a reflection has a negative determinant, so the proper decomposition
+    // negates the smallest singular value
+    let c = dmat3::new(0.0, 1.0, 0.0,
+                       1.0, 0.0, 0.0,
+                       0.0, 0.0, 2.0);
+    let (u, s, vt) = c.proper_svd();
+    
+    assert u.determinant().fuzzy_eq(&1.0);
+    assert vt.determinant().fuzzy_eq(&1.0);
+    assert s.fuzzy_eq(&dvec3::new(2.0, 1.0, -1.0));
+    
+    let d = dmat4::new(2.0, 1.0, 0.0, 1.0,
+                      -1.0, 3.0, 1.0, 0.0,
+                       0.5, 2.0, 4.0, 1.0,
+                       1.0, 0.0, 1.0, 2.0);
+    let (u, s, vt) = d.svd();
+    
+    assert u.mul_m(&dmat4::new(s[0], 0.0,  0.0,  0.0,
+                               0.0,  s[1], 0.0,  0.0,
+                               0.0,  0.0,  s[2], 0.0,
+                               0.0,  0.0,  0.0,  s[3])).mul_m(&vt).fuzzy_eq(&d);
+    assert u.transpose().mul_m(&u).fuzzy_eq(&dmat4::identity());
+}
+
+#[test]
+fn test_mat_pseudo_inverse() {
+    // singular
+    let a = dmat3::new(1.0, 2.0, 3.0,
+                       4.0, 5.0, 6.0,
+                       7.0, 8.0, 9.0);
+    let b = a.pseudo_inverse();
+    
+    assert a.mul_m(&b).mul_m(&a).fuzzy_eq(&a);
+    assert b.mul_m(&a).mul_m(&b).fuzzy_eq(&b);
+    assert a.rank(1e-6) == 2;
+    
+    // the singular vectors of the zero matrix are completed to a basis
+    let (u, s, _) = dmat3::zero().svd();
+    
+    assert u.transpose().mul_m(&u).fuzzy_eq(&dmat3::identity());
+    assert s == dvec3::new(0.0, 0.0, 0.0);
+    assert dmat3::zero().rank(1e-6) == 0;
+    assert dmat3::zero().pseudo_inverse() == dmat3::zero();
+    
+    // invertible
+    let c = dmat4::new(2.0, 1.0, 0.0, 1.0,
+                      -1.0, 3.0, 1.0, 0.0,
+                       0.5, 2.0, 4.0, 1.0,
+                       1.0, 0.0, 1.0, 2.0);
+    
+    assert c.pseudo_inverse().fuzzy_eq(&c.inverse().unwrap());
+    assert c.rank(1e-6) == 4;
+    
+    // small singular values are judged relative to the largest one
+    let e = dmat4::from_value(0.0000001);
+    let (u, _, _) = e.svd();
+    
+    assert u.fuzzy_eq(&dmat4::identity());
+    assert e.pseudo_inverse().fuzzy_eq(&dmat4::from_value(10000000.0));
+    assert a.mul_t(0.0000001).pseudo_inverse().mul_t(0.0000001).fuzzy_eq(&b);
+    
+    let d = dmat3::new(4.0, 0.0, 0.0,
+                       0.0, 1.0, 0.0,
+                       0.0, 0.0, 2.0);
+    
+    assert d.condition_number().fuzzy_eq(&4.0);
+    assert dmat2::identity().condition_number().fuzzy_eq(&1.0);
+}
+
+#[test]
+fn test_mat3_orthonormalize() {
+    let a = dmat3::from_angle_axis(Rad(1.0), &dvec3::new(0.6, 0.0, 0.8));
+    
+    // accumulate some error in the rotation
+    let b = a.add_m(&dmat3::new(0.01, -0.02,  0.0,
+                                0.03,  0.0,  -0.01,
+                                0.0,   0.02,  0.01));
+    let c = b.orthonormalize();
+    
+    assert !b.transpose().mul_m(&b).fuzzy_eq(&dmat3::identity());
+    assert c.transpose().mul_m(&c).fuzzy_eq(&dmat3::identity());
+    assert c.determinant().fuzzy_eq(&1.0);
+    assert c.fuzzy_eq_eps(&a, &0.05);
+    assert a.orthonormalize().fuzzy_eq(&a);
+    
+    let mut d = b;
+    d.orthonormalize_self();
+    assert d == c;
+}
+
+#[test]
+fn test_mat_polar() {
+    let r = dmat3::from_angle_axis(Rad(0.5), &dvec3::new(0.0, 0.6, 0.8));
+    let s = dmat3::new(2.0, 0.5, 0.0,
+                       0.5, 1.0, 0.0,
+                       0.0, 0.0, 3.0);
+    let (a, b) = r.mul_m(&s).polar();
+    
+    assert a.fuzzy_eq(&r);
+    assert b.fuzzy_eq(&s);
+    
+    // a reflection is kept in the symmetric part
+    let m = dmat2::new(-1.0, 0.0,
+                        0.0, 1.0);
+    let (a, b) = m.polar();
+    
+    assert a.determinant().fuzzy_eq(&1.0);
+    assert b.is_symmetric();
+    assert a.mul_m(&b).fuzzy_eq(&m);
+}
+
+#[test]
+fn test_mat4_decompose() {
+    let t = dmat4::from_translation(&dvec3::new(1.0, 2.0, 3.0));
+    let r = dmat3::from_angle_axis(Rad(0.5), &dvec3::new(0.0, 0.6, 0.8));
+    let a = t.mul_m(&r.to_mat4()).mul_m(&dmat4::from_scale(&dvec3::new(2.0, 3.0, 4.0)));
+    let d = a.decompose().unwrap();
+    
+    assert d.translation == dvec3::new(1.0, 2.0, 3.0);
+    assert d.rotation.to_mat3().fuzzy_eq(&r);
+    assert d.scale.fuzzy_eq(&dvec3::new(2.0, 3.0, 4.0));
+    assert d.shear.fuzzy_eq(&dvec3::new(0.0, 0.0, 0.0));
+    assert d.compose().fuzzy_eq(&a);
+    
+    // with shear and a reflection
+    let h = dmat4::new(1.0,  0.0, 0.0, 0.0,
+                       0.5,  1.0, 0.0, 0.0,
+                       0.25, -0.5, 1.0, 0.0,
+                       0.0,  0.0, 0.0, 1.0);
+    let b = t.mul_m(&r.to_mat4()).mul_m(&h).mul_m(&dmat4::from_scale(&dvec3::new(2.0, 3.0, -4.0)));
+    let d = b.decompose().unwrap();
+    
+    assert d.rotation.to_mat3().fuzzy_eq(&r);
+    assert d.scale.fuzzy_eq(&dvec3::new(2.0, 3.0, -4.0));
+    assert d.shear.fuzzy_eq(&dvec3::new(0.5, 0.25, -0.5));
+    assert d.compose().fuzzy_eq(&b);
+    
+    // a reflection in another axis is moved to the z axis
+    let c = t.mul_m(&dmat4::from_scale(&dvec3::new(-2.0, 1.0, 1.0)));
+    let d = c.decompose().unwrap();
+    
+    assert d.scale.z < 0.0;
+    assert d.compose().fuzzy_eq(&c);
+    
+    // a small uniform scale
+    let e = t.mul_m(&r.to_mat4()).mul_m(&dmat4::from_scale(&dvec3::new(5e-4, 5e-4, 5e-4)));
+    let d = e.decompose().unwrap();
+    
+    assert d.rotation.to_mat3().fuzzy_eq(&r);
+    assert d.scale.mul_t(1e4).fuzzy_eq(&dvec3::new(5.0, 5.0, 5.0));
+    assert d.shear.fuzzy_eq(&dvec3::new(0.0, 0.0, 0.0));
+    
+    // projective and singular
+    assert dmat4::new(1.0, 0.0, 0.0, 0.0,
+                      0.0, 1.0, 0.0, 0.0,
+                      0.0, 0.0, 1.0, -1.0,
+                      0.0, 0.0, 0.0, 0.0).decompose().is_none();
+    assert dmat4::from_scale(&dvec3::new(1.0, 0.0, 1.0)).decompose().is_none();
+}
+
+#[test]
+fn test_mat3_from_two_vectors() {
+    let a = dvec3::new(1.0, 2.0, 3.0);
+    let b = dvec3::new(-2.0, 0.5, 1.0);
+    let m = dmat3::from_two_vectors(&a, &b);
+    
+    assert m.mul_v(&a.normalize()).fuzzy_eq(&b.normalize());
+    assert m.fuzzy_eq(&dquat::from_two_vectors(&a, &b).to_mat3());
+    assert dmat3::from_two_vectors(&a, &a).fuzzy_eq(&dmat3::identity());
+    assert dmat3::from_two_vectors(&a, &-a).mul_v(&a).fuzzy_eq(&-a);
+}
+
+#[test]
+fn test_mat_truncate() {
+    let a = dmat4::new( 1.0,  2.0,  3.0,  4.0,
+                        5.0,  6.0,  7.0,  8.0,
+                        9.0, 10.0, 11.0, 12.0,
+                       13.0, 14.0, 15.0, 16.0);
+    
+    assert a.to_mat3() == dmat3::new(1.0,  2.0,  3.0,
+                                     5.0,  6.0,  7.0,
+                                     9.0, 10.0, 11.0);
+    assert a.to_mat3().to_mat2() == dmat2::new(1.0, 2.0,
+                                               5.0, 6.0);
+    assert dmat2::new(1.0, 2.0, 3.0, 4.0).to_mat3().to_mat2() == dmat2::new(1.0, 2.0, 3.0, 4.0);
+    assert dmat3::from_angle_z(Rad(0.5)).to_mat4().to_mat3() == dmat3::from_angle_z(Rad(0.5));
+}
+
+#[test]
+fn test_mat4_to_quat() {
+    let q = dquat::from_angle_axis(Rad(1.0), &dvec3::new(0.6, 0.0, 0.8));
+    
+    assert dmat4::from_quat(&q).to_quat().fuzzy_eq(&q);
+    
+    // the translation and scale are ignored
+    let m = dmat4::from_translation(&dvec3::new(1.0, 2.0, 3.0))
+        .mul_m(&dmat4::from_quat(&q))
+        .mul_m(&dmat4::from_scale(&dvec3::new(2.0, 0.5, 3.0)));
+    
+    assert m.to_quat().fuzzy_eq(&q);
+}
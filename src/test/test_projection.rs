@@ -0,0 +1,226 @@
+use std::cmp::FuzzyEq;
+use numeric::*;
+
+use angle::*;
+use mat::*;
+use projection::*;
+use vec::*;
+
+/**
+ * Transform a point in eye space by `proj`, returning the normalized device
+ * coordinates after the perspective divide
+ */
+fn to_ndc(proj: &mat4, x: f32, y: f32, z: f32) -> vec3 {
+    let clip = proj.mul_v(&vec4::new(x, y, z, 1.0));
+    vec3::new(clip.x / clip.w, clip.y / clip.w, clip.z / clip.w)
+}
+
+#[test]
+fn test_perspective() {
+    // a 90 degree field of view puts the edges of the near plane at +/- near
+    let p = perspective(Deg(90f32), 2f32, 1f32, 100f32);
+    
+    assert to_ndc(&p, 0.0, 0.0, -1.0).fuzzy_eq(&vec3::new(0.0, 0.0, -1.0));
+    assert to_ndc(&p, 0.0, 0.0, -100.0).fuzzy_eq(&vec3::new(0.0, 0.0, 1.0));
+    assert to_ndc(&p, 2.0, 1.0, -1.0).fuzzy_eq(&vec3::new(1.0, 1.0, -1.0));
+    assert to_ndc(&p, -20.0, -10.0, -10.0).fuzzy_eq_eps(&vec3::new(-1.0, -1.0, 0.8181818), &0.00001);
+    
+    assert p == frustum(-2f32, 2f32, -1f32, 1f32, 1f32, 100f32);
+    
+    let q = perspective_zo(Deg(90f32), 2f32, 1f32, 100f32);
+    
+    assert to_ndc(&q, 0.0, 0.0, -1.0).fuzzy_eq(&vec3::new(0.0, 0.0, 0.0));
+    assert to_ndc(&q, 0.0, 0.0, -100.0).fuzzy_eq(&vec3::new(0.0, 0.0, 1.0));
+    assert to_ndc(&q, -2.0, 1.0, -1.0).fuzzy_eq(&vec3::new(-1.0, 1.0, 0.0));
+}
+
+#[test]
+fn test_perspective_half_angle() {
+    // `fovy` spans the whole view, so the top of the near plane is at
+    // `near * tan(fovy / 2)`, like `gluPerspective`
+    let t = 1.0 / sqrt(3.0);
+    let p = perspective(Deg(60f64), 1.5f64, 2f64, 10f64);
+    
+    assert p.fuzzy_eq(&frustum(-3.0 * t, 3.0 * t, -2.0 * t, 2.0 * t, 2f64, 10f64));
+    assert p[1][1].fuzzy_eq(&sqrt(3.0));
+    assert p[0][0].fuzzy_eq(&(sqrt(3.0) / 1.5));
+}
+
+#[test]
+fn test_frustum() {
+    let p = frustum(-1f32, 3f32, -2f32, 2f32, 2f32, 10f32);
+    
+    assert to_ndc(&p, -1.0, -2.0, -2.0).fuzzy_eq(&vec3::new(-1.0, -1.0, -1.0));
+    assert to_ndc(&p, 3.0, 2.0, -2.0).fuzzy_eq(&vec3::new(1.0, 1.0, -1.0));
+    assert to_ndc(&p, 15.0, 10.0, -10.0).fuzzy_eq(&vec3::new(1.0, 1.0, 1.0));
+    
+    let q = frustum_zo(-1f32, 3f32, -2f32, 2f32, 2f32, 10f32);
+    
+    assert to_ndc(&q, -1.0, -2.0, -2.0).fuzzy_eq(&vec3::new(-1.0, -1.0, 0.0));
+    assert to_ndc(&q, 15.0, 10.0, -10.0).fuzzy_eq(&vec3::new(1.0, 1.0, 1.0));
+}
+
+#[test]
+fn test_infinite_perspective() {
+    let p = infinite_perspective(Deg(90f32), 1f32, 0.5f32);
+    
+    assert to_ndc(&p, 0.5, 0.5, -0.5).fuzzy_eq(&vec3::new(1.0, 1.0, -1.0));
+    assert to_ndc(&p, 0.0, 0.0, -1.0).fuzzy_eq(&vec3::new(0.0, 0.0, 0.0));
+    assert to_ndc(&p, 0.0, 0.0, -1000000.0).fuzzy_eq_eps(&vec3::new(0.0, 0.0, 1.0), &0.00001);
+    
+    let q = infinite_perspective_zo(Deg(90f32), 1f32, 0.5f32);
+    
+    assert to_ndc(&q, 0.5, 0.5, -0.5).fuzzy_eq(&vec3::new(1.0, 1.0, 0.0));
+    assert to_ndc(&q, 0.0, 0.0, -1.0).fuzzy_eq(&vec3::new(0.0, 0.0, 0.5));
+    assert to_ndc(&q, 0.0, 0.0, -1000000.0).fuzzy_eq_eps(&vec3::new(0.0, 0.0, 1.0), &0.00001);
+    
+    // the infinite projection is the limit of the finite projection
+    assert perspective(Deg(60f32), 1.5f32, 1f32, 10000000f32).fuzzy_eq_eps(&infinite_perspective(Deg(60f32), 1.5f32, 1f32), &0.00001);
+}
+
+#[test]
+fn test_reverse_perspective() {
+    let p = reverse_perspective(Deg(90f32), 1f32, 1f32, 100f32);
+    
+    assert to_ndc(&p, 0.0, 0.0, -1.0).fuzzy_eq(&vec3::new(0.0, 0.0, 1.0));
+    assert to_ndc(&p, 0.0, 0.0, -100.0).fuzzy_eq(&vec3::new(0.0, 0.0, 0.0));
+    assert to_ndc(&p, 1.0, -1.0, -1.0).fuzzy_eq(&vec3::new(1.0, -1.0, 1.0));
+    
+    // depth decreases as the distance from the eye increases
+    assert to_ndc(&p, 0.0, 0.0, -2.0).z > to_ndc(&p, 0.0, 0.0, -3.0).z;
+    
+    let q = reverse_infinite_perspective(Deg(90f32), 1f32, 1f32);
+    
+    assert to_ndc(&q, 0.0, 0.0, -1.0).fuzzy_eq(&vec3::new(0.0, 0.0, 1.0));
+    assert to_ndc(&q, 0.0, 0.0, -4.0).fuzzy_eq(&vec3::new(0.0, 0.0, 0.25));
+    assert to_ndc(&q, 0.0, 0.0, -1000000.0).fuzzy_eq_eps(&vec3::new(0.0, 0.0, 0.0), &0.00001);
+}
+
+#[test]
+fn test_ortho() {
+    let p = ortho(-1f32, 3f32, -2f32, 2f32, 1f32, 5f32);
+    
+    assert to_ndc(&p, -1.0, -2.0, -1.0).fuzzy_eq(&vec3::new(-1.0, -1.0, -1.0));
+    assert to_ndc(&p, 3.0, 2.0, -5.0).fuzzy_eq(&vec3::new(1.0, 1.0, 1.0));
+    assert to_ndc(&p, 1.0, 0.0, -3.0).fuzzy_eq(&vec3::new(0.0, 0.0, 0.0));
+    
+    // an orthographic projection is affine
+    assert p.row(3) == vec4::new(0.0, 0.0, 0.0, 1.0);
+    
+    let q = ortho_zo(-1f32, 3f32, -2f32, 2f32, 1f32, 5f32);
+    
+    assert to_ndc(&q, -1.0, -2.0, -1.0).fuzzy_eq(&vec3::new(-1.0, -1.0, 0.0));
+    assert to_ndc(&q, 3.0, 2.0, -5.0).fuzzy_eq(&vec3::new(1.0, 1.0, 1.0));
+    assert to_ndc(&q, 1.0, 0.0, -3.0).fuzzy_eq(&vec3::new(0.0, 0.0, 0.5));
+    
+    let r = ortho2d(0f32, 800f32, 600f32, 0f32);
+    
+    assert r == ortho(0f32, 800f32, 600f32, 0f32, -1f32, 1f32);
+    assert to_ndc(&r, 0.0, 0.0, 0.0).fuzzy_eq(&vec3::new(-1.0, 1.0, 0.0));
+    assert to_ndc(&r, 800.0, 600.0, 0.0).fuzzy_eq(&vec3::new(1.0, -1.0, 0.0));
+}
+
+#[test]
+fn test_project() {
+    let modelview = dmat4::identity();
+    let proj = perspective(Deg(90f64), 1f64, 1f64, 100f64);
+    let viewport = dvec4::new(0.0, 0.0, 800.0, 600.0);
+    
+    assert project(&dvec3::new(0.0, 0.0, -1.0), &modelview, &proj, &viewport).unwrap().fuzzy_eq(&dvec3::new(400.0, 300.0, 0.0));
+    assert project(&dvec3::new(1.0, 1.0, -1.0), &modelview, &proj, &viewport).unwrap().fuzzy_eq(&dvec3::new(800.0, 600.0, 0.0));
+    assert project(&dvec3::new(-100.0, -100.0, -100.0), &modelview, &proj, &viewport).unwrap().fuzzy_eq(&dvec3::new(0.0, 0.0, 1.0));
+    
+    // points on the plane of the eye can't be projected
+    assert project(&dvec3::new(1.0, 0.0, 0.0), &modelview, &proj, &viewport).is_none();
+}
+
+#[test]
+fn test_unproject() {
+    let modelview = dmat4::look_at(&dvec3::new(0.0, 0.0, 5.0),
+                                   &dvec3::new(0.0, 0.0, 0.0),
+                                   &dvec3::new(0.0, 1.0, 0.0));
+    let proj = perspective(Deg(90f64), 1f64, 1f64, 100f64);
+    let viewport = dvec4::new(10.0, 20.0, 800.0, 600.0);
+    
+    assert unproject(&dvec3::new(410.0, 320.0, 0.0), &modelview, &proj, &viewport).unwrap().fuzzy_eq(&dvec3::new(0.0, 0.0, 4.0));
+    assert unproject(&dvec3::new(810.0, 620.0, 1.0), &modelview, &proj, &viewport).unwrap().fuzzy_eq_eps(&dvec3::new(100.0, 100.0, -95.0), &0.000001);
+    
+    let obj = dvec3::new(1.0, -2.0, -3.0);
+    let win = project(&obj, &modelview, &proj, &viewport).unwrap();
+    
+    assert unproject(&win, &modelview, &proj, &viewport).unwrap().fuzzy_eq(&obj);
+    
+    // singular matrices can't be inverted
+    assert unproject(&win, &dmat4::zero(), &proj, &viewport).is_none();
+}
+
+#[test]
+fn test_pick_ray() {
+    let modelview = dmat4::from_translation(&dvec3::new(0.0, 0.0, -5.0));
+    let proj = perspective(Deg(90f64), 1f64, 1f64, 100f64);
+    let viewport = dvec4::new(0.0, 0.0, 800.0, 800.0);
+    
+    let (origin, dir) = pick_ray(400f64, 400f64, &modelview, &proj, &viewport).unwrap();
+    assert origin.fuzzy_eq(&dvec3::new(0.0, 0.0, 4.0));
+    assert dir.fuzzy_eq(&dvec3::new(0.0, 0.0, -1.0));
+    
+    // the corners of the viewport lie on the edges of the 90 degree frustum
+    let (origin, dir) = pick_ray(800f64, 800f64, &modelview, &proj, &viewport).unwrap();
+    assert origin.fuzzy_eq(&dvec3::new(1.0, 1.0, 4.0));
+    assert dir.fuzzy_eq(&dvec3::new(1.0, 1.0, -1.0).normalize());
+    
+    assert pick_ray(400f64, 400f64, &modelview, &dmat4::zero(), &viewport).is_none();
+    
+    // the far plane of an infinite projection is at infinity, but the ray is
+    // still well defined
+    let inf = infinite_perspective(Deg(90f64), 1f64, 1f64);
+    
+    let (origin, dir) = pick_ray(800f64, 800f64, &modelview, &inf, &viewport).unwrap();
+    assert origin.fuzzy_eq(&dvec3::new(1.0, 1.0, 4.0));
+    assert dir.fuzzy_eq(&dvec3::new(1.0, 1.0, -1.0).normalize());
+}
+
+#[test]
+fn test_project_zo() {
+    let modelview = dmat4::from_translation(&dvec3::new(0.0, 0.0, -5.0));
+    let viewport = dvec4::new(0.0, 0.0, 800.0, 800.0);
+    let obj = dvec3::new(1.0, -2.0, -3.0);
+    
+    let proj = perspective_zo(Deg(90f64), 1f64, 1f64, 100f64);
+    
+    assert project_zo(&dvec3::new(0.0, 0.0, 4.0), &modelview, &proj, &viewport).unwrap().fuzzy_eq(&dvec3::new(400.0, 400.0, 0.0));
+    assert project_zo(&dvec3::new(100.0, 100.0, -95.0), &modelview, &proj, &viewport).unwrap().fuzzy_eq(&dvec3::new(800.0, 800.0, 1.0));
+    assert unproject_zo(&project_zo(&obj, &modelview, &proj, &viewport).unwrap(), &modelview, &proj, &viewport).unwrap().fuzzy_eq(&obj);
+    
+    // the window depth is the same as with the [-1, 1] convention
+    let gl = perspective(Deg(90f64), 1f64, 1f64, 100f64);
+    
+    assert project_zo(&obj, &modelview, &proj, &viewport).unwrap().fuzzy_eq(&project(&obj, &modelview, &gl, &viewport).unwrap());
+    
+    // a reversed-z projection puts the near plane at a depth of 1
+    let rev = reverse_perspective(Deg(90f64), 1f64, 1f64, 100f64);
+    
+    assert project_zo(&dvec3::new(0.0, 0.0, 4.0), &modelview, &rev, &viewport).unwrap().fuzzy_eq(&dvec3::new(400.0, 400.0, 1.0));
+    assert unproject_zo(&project_zo(&obj, &modelview, &rev, &viewport).unwrap(), &modelview, &rev, &viewport).unwrap().fuzzy_eq(&obj);
+    
+    // the origin of the ray is on the near plane for each projection
+    let projs = ~[proj, rev, infinite_perspective_zo(Deg(90f64), 1f64, 1f64),
+                 reverse_infinite_perspective(Deg(90f64), 1f64, 1f64)];
+    
+    for projs.each |p| {
+        let (origin, dir) = pick_ray_zo(800f64, 800f64, &modelview, p, &viewport).unwrap();
+        assert origin.fuzzy_eq(&dvec3::new(1.0, 1.0, 4.0));
+        assert dir.fuzzy_eq(&dvec3::new(1.0, 1.0, -1.0).normalize());
+    }
+}
+
+#[test]
+fn test_unproject_distant() {
+    // the homogeneous `w` of a point on a distant far plane is tiny, but the
+    // point can still be unprojected
+    let proj = perspective(Deg(90f64), 1f64, 1f64, 10000000f64);
+    let viewport = dvec4::new(0.0, 0.0, 800.0, 800.0);
+    let far = unproject(&dvec3::new(400.0, 400.0, 1.0), &dmat4::identity(), &proj, &viewport).unwrap();
+    
+    assert (far.z / -10000000.0).fuzzy_eq_eps(&1.0, &0.0001);
+}
@@ -1,5 +1,7 @@
 use numeric::*;
+use numeric::float::Float;
 
+use angle::*;
 use mat::*;
 use quat::*;
 use vec::*;
@@ -31,7 +33,7 @@ fn test_quat() {
 fn test_quat_2() {
     let v = vec3::new(1f32, 0f32, 0f32);
     
-    let q = quat::from_angle_axis(radians(-45f32), &vec3::new(0f32, 0f32, -1f32));
+    let q = quat::from_angle_axis(Deg(-45f32), &vec3::new(0f32, 0f32, -1f32));
     
     // http://www.wolframalpha.com/input/?i={1,0}+rotate+-45+degrees
     assert q.mul_v(&v).fuzzy_eq(&vec3::new(1f32/sqrt(2f32), 1f32/sqrt(2f32), 0f32));
@@ -45,4 +47,251 @@ fn test_quat_2() {
 fn test_quat_fuzzy_eq() {
     assert !quat::new(0.000001, 0.000001, 0.000001, 0.000001).fuzzy_eq(&quat::new(0.0, 0.0, 0.0, 0.0));
     assert quat::new(0.0000001, 0.0000001, 0.0000001, 0.0000001).fuzzy_eq(&quat::new(0.0, 0.0, 0.0, 0.0));
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_quat_ops() {
+    let a = quat::new(1.0, 2.0, 3.0, 4.0);
+    let b = quat::new(5.0, 6.0, 7.0, 8.0);
+    
+    assert a + b == a.add_q(&b);
+    assert a - b == a.sub_q(&b);
+    assert a * b == a.mul_q(&b);
+    assert a * 2.0f32 == a.mul_t(2.0);
+    assert a / 2.0f32 == a.div_t(2.0);
+    
+    let q = quat::from_angle_axis(Deg(-45f32), &vec3::new(0f32, 0f32, -1f32));
+    let v = vec3::new(1f32, 0f32, 0f32);
+    
+    assert q * v == q.mul_v(&v);
+    assert (q * q * v).fuzzy_eq(&vec3::new(0f32, 1f32, 0f32));
+}
+
+#[test]
+fn test_quat_from_angle() {
+    assert quat::from_angle_x(Deg(30f32)).to_mat3().fuzzy_eq(&mat3::from_angle_x(Deg(30f32)));
+    assert quat::from_angle_y(Rad(1f32)).to_mat3().fuzzy_eq(&mat3::from_angle_y(Rad(1f32)));
+    assert quat::from_angle_z(Rad(-2f32)).to_mat3().fuzzy_eq(&mat3::from_angle_z(Rad(-2f32)));
+    assert quat::from_angle_x(Deg(90f32)).fuzzy_eq(&quat::from_angle_axis(Rad(Float::frac_pi_2()), &vec3::unit_x()));
+}
+
+#[test]
+fn test_quat_to_mat4() {
+    // a third of a turn around `(1, 1, 1)` cycles the axes
+    assert dquat::new(0.5, 0.5, 0.5, 0.5).to_mat4() == dmat4::new(0.0, 1.0, 0.0, 0.0,
+                                                                  0.0, 0.0, 1.0, 0.0,
+                                                                  1.0, 0.0, 0.0, 0.0,
+                                                                  0.0, 0.0, 0.0, 1.0);
+    
+    let (c, s) = (cos(0.5), sin(0.5));
+    
+    assert dquat::new(c, 0.0, 0.0, s).to_mat4().fuzzy_eq(&dmat4::new( cos(1.0), sin(1.0), 0.0, 0.0,
+                                                                      -sin(1.0), cos(1.0), 0.0, 0.0,
+                                                                            0.0,      0.0, 1.0, 0.0,
+                                                                            0.0,      0.0, 0.0, 1.0));
+    
+    let q = dquat::from_angle_axis(Rad(1.0), &dvec3::new(0.6, 0.0, 0.8));
+    
+    let p = q.mul_v(&dvec3::new(1.0, 2.0, 3.0));
+    
+    assert q.to_mat4().mul_v(&dvec4::new(1.0, 2.0, 3.0, 1.0)).fuzzy_eq(&dvec4::new(p.x, p.y, p.z, 1.0));
+    assert q.to_mat4().to_quat().fuzzy_eq(&q);
+}
+
+#[test]
+fn test_quat_from_two_vectors() {
+    let a = dvec3::new(1.0, 2.0, 3.0);
+    let b = dvec3::new(-2.0, 0.5, 1.0);
+    let q = dquat::from_two_vectors(&a, &b);
+    
+    assert q.mul_v(&a.normalize()).fuzzy_eq(&b.normalize());
+    
+    let (theta, _) = q.get_angle_axis();
+    
+    assert theta.fuzzy_eq(&Rad(acos(a.normalize().dot(&b.normalize()))));
+    assert dquat::from_two_vectors(&dvec3::unit_x(), &dvec3::new(0.0, 2.0, 0.0))
+        .fuzzy_eq(&dquat::from_angle_z(Rad(Float::frac_pi_2())));
+    
+    // parallel vectors
+    assert dquat::from_two_vectors(&a, &a.mul_t(2.0)).fuzzy_eq(&dquat::identity());
+    
+    // anti-parallel vectors
+    for [dvec3::unit_x(), dvec3::unit_y(), dvec3::unit_z(),
+         dvec3::new(1.0, 1.0, 1.0), dvec3::new(0.0, -3.0, 4.0)].each |v| {
+        let q = dquat::from_two_vectors(v, &-*v);
+        
+        assert q.s.fuzzy_eq(&0.0);
+        assert q.v.dot(v).fuzzy_eq(&0.0);
+        assert q.mul_v(v).fuzzy_eq(&-*v);
+    }
+    
+    // nearly anti-parallel vectors still use the general formula
+    let c = dvec3::new(-1.0, 0.00001, 0.0);
+    let q = dquat::from_two_vectors(&dvec3::unit_x(), &c);
+    
+    assert q.mul_v(&dvec3::unit_x()).fuzzy_eq_eps(&c.normalize(), &0.0000000001);
+    assert q.fuzzy_eq(&dquat::from_angle_z(Rad(atan2(0.00001, -1.0))));
+}
+
+#[test]
+fn test_quat_exp_ln_pow() {
+    let axis = dvec3::new(0.0, 0.6, 0.8);
+    let q = dquat::from_angle_axis(Rad(1.2), &axis);
+    
+    assert q.ln().fuzzy_eq(&dquat::from_sv(0.0, axis.mul_t(0.6)));
+    assert q.ln().exp().fuzzy_eq(&q);
+    assert dquat::identity().ln().fuzzy_eq(&dquat::zero());
+    assert dquat::zero().exp().fuzzy_eq(&dquat::identity());
+    
+    // quaternions that aren't normalized
+    let a = dquat::new(0.9, 0.2, -0.3, 0.1);
+    assert a.ln().exp().fuzzy_eq(&a);
+    assert dquat::new(2.0, 0.0, 0.0, 0.0).ln().fuzzy_eq(&dquat::new(ln(2.0), 0.0, 0.0, 0.0));
+    assert dquat::new(-1.0, 0.0, 0.0, 0.0).ln().exp().fuzzy_eq(&dquat::new(-1.0, 0.0, 0.0, 0.0));
+    
+    // a tiny quaternion that is still far from the real axis
+    let b = dquat::new(1e-7, 1e-7, 0.0, 0.0);
+    assert b.ln().v.fuzzy_eq(&dvec3::new(Float::frac_pi_4(), 0.0, 0.0));
+    
+    assert q.pow(0.5).fuzzy_eq(&dquat::from_angle_axis(Rad(0.6), &axis));
+    assert q.pow(0.5).mul_q(&q.pow(0.5)).fuzzy_eq(&q);
+    assert q.pow(-1.0).fuzzy_eq(&q.inverse());
+    assert q.pow(0.3).fuzzy_eq(&dquat::identity().slerp(&q, 0.3));
+}
+
+#[test]
+fn test_quat_nearest() {
+    let a = dquat::from_angle_axis(Rad(0.5), &dvec3::unit_z());
+    let b = dquat::from_angle_axis(Rad(1.5), &dvec3::unit_z());
+    
+    assert a.nearest(&b) == b;
+    assert a.nearest(&-b) == b;
+    
+    // interpolating towards `-b` would go the long way around
+    assert a.slerp(&a.nearest(&-b), 0.5).fuzzy_eq(&dquat::from_angle_axis(Rad(1.0), &dvec3::unit_z()));
+}
+
+#[test]
+fn test_quat_squad() {
+    let keys = ~[dquat::identity(),
+                 dquat::from_angle_axis(Rad(0.8), &dvec3::unit_x()),
+                 dquat::from_angle_axis(Rad(1.5), &dvec3::new(0.3, 1.0, 0.2).normalize()),
+                 dquat::from_angle_axis(Rad(2.0), &dvec3::new(0.0, 0.5, 1.0).normalize())];
+    let c = squad_controls(keys);
+    
+    assert c.len() == 4;
+    assert c[0] == keys[0];
+    assert c[3] == keys[3];
+    
+    assert keys[1].squad(&c[1], &c[2], &keys[2], 0.0).fuzzy_eq(&keys[1]);
+    assert keys[1].squad(&c[1], &c[2], &keys[2], 1.0).fuzzy_eq(&keys[2]);
+    
+    // with the keyframes as control points the curve is a slerp
+    assert keys[1].squad(&keys[1], &keys[2], &keys[2], 0.3).fuzzy_eq(&keys[1].slerp(&keys[2], 0.3));
+    
+    // the angular velocity is continuous across a keyframe
+    let eps = 0.0001;
+    let left = keys[0].squad(&c[0], &c[1], &keys[1], 1.0 - eps);
+    let right = keys[1].squad(&c[1], &c[2], &keys[2], eps);
+    
+    assert left.inverse().mul_q(&keys[1]).fuzzy_eq(&keys[1].inverse().mul_q(&right));
+    
+    // evenly spaced keyframes around a single axis need no correction
+    let z = dvec3::unit_z();
+    let even = ~[dquat::from_angle_axis(Rad(0.0), &z),
+                 dquat::from_angle_axis(Rad(0.5), &z),
+                 dquat::from_angle_axis(Rad(1.0), &z)];
+    
+    assert squad_controls(even)[1].fuzzy_eq(&even[1]);
+}
+
+#[test]
+fn test_quat_swing_twist() {
+    let swing = dquat::from_angle_x(Rad(0.3));
+    let twist = dquat::from_angle_z(Rad(0.8));
+    let q = swing.mul_q(&twist);
+    let z = dvec3::new(0.0, 0.0, 2.0);
+    
+    let (s, t) = q.swing_twist(&z);
+    assert s.fuzzy_eq(&swing);
+    assert t.fuzzy_eq(&twist);
+    assert s.mul_q(&t).fuzzy_eq(&q);
+    
+    // a rotation that is entirely a twist, or entirely a swing
+    let (s, t) = twist.swing_twist(&z);
+    assert s.fuzzy_eq(&dquat::identity());
+    assert t.fuzzy_eq(&twist);
+    
+    let (s, t) = swing.swing_twist(&z);
+    assert s.fuzzy_eq(&swing);
+    assert t.fuzzy_eq(&dquat::identity());
+    
+    // a half turn around a perpendicular axis has no defined twist
+    let half_turn = dquat::from_angle_y(Rad(2.0 * Float::frac_pi_2()));
+    
+    let (s, t) = half_turn.swing_twist(&z);
+    assert s.fuzzy_eq(&half_turn);
+    assert t.fuzzy_eq(&dquat::identity());
+}
+
+#[test]
+fn test_quat_clamp_swing_twist() {
+    let z = dvec3::unit_z();
+    let q = dquat::from_angle_x(Rad(0.3)).mul_q(&dquat::from_angle_z(Rad(0.8)));
+    
+    assert q.clamp_twist(&z, Rad(-1.0), Rad(1.0)).fuzzy_eq(&q);
+    assert q.clamp_twist(&z, Rad(-0.5), Rad(0.5))
+        .fuzzy_eq(&dquat::from_angle_x(Rad(0.3)).mul_q(&dquat::from_angle_z(Rad(0.5))));
+    assert q.clamp_twist(&z, Deg(10.0), Deg(90.0)).fuzzy_eq(&q);
+    assert dquat::from_angle_z(Rad(-0.8)).clamp_twist(&z, Rad(-0.5), Rad(0.5))
+        .fuzzy_eq(&dquat::from_angle_z(Rad(-0.5)));
+    
+    // the sign of the quaternion doesn't affect the twist angle
+    assert (-q).clamp_twist(&z, Rad(-0.5), Rad(0.5))
+        .fuzzy_eq(&dquat::from_angle_x(Rad(0.3)).mul_q(&dquat::from_angle_z(Rad(0.5))));
+    
+    assert q.clamp_swing(&z, Rad(0.5)).fuzzy_eq(&q);
+    assert q.clamp_swing(&z, Rad(0.2))
+        .fuzzy_eq(&dquat::from_angle_x(Rad(0.2)).mul_q(&dquat::from_angle_z(Rad(0.8))));
+    assert dquat::from_angle_y(Rad(2.0 * Float::frac_pi_2())).clamp_swing(&z, Rad(0.2))
+        .fuzzy_eq(&dquat::from_angle_y(Rad(0.2)));
+}
+
+#[test]
+fn test_quat_average() {
+    let a = dquat::from_angle_z(Rad(0.2));
+    let b = dquat::from_angle_z(Rad(1.4));
+    
+    // two equally weighted rotations average to their midpoint
+    assert average_quats(~[a, b], ~[1.0, 1.0]).unwrap().fuzzy_eq(&dquat::from_angle_z(Rad(0.8)));
+    assert average_quats(~[a, -b], ~[1.0, 1.0]).unwrap().fuzzy_eq(&dquat::from_angle_z(Rad(0.8)));
+    assert average_quats(~[-a, b], ~[1.0, 1.0]).unwrap().fuzzy_eq(&-dquat::from_angle_z(Rad(0.8)));
+    assert average_quats_fast(~[a, -b], ~[1.0, 1.0]).unwrap().fuzzy_eq(&dquat::from_angle_z(Rad(0.8)));
+    
+    assert average_quats(~[a, b], ~[0.0, 2.0]).unwrap().fuzzy_eq(&b);
+    assert average_quats(~[a], ~[1.0]).unwrap().fuzzy_eq(&a);
+    
+    // rotations spread symmetrically around a common rotation
+    let c = dquat::from_angle_axis(Rad(1.0), &dvec3::new(0.6, 0.0, 0.8));
+    let offsets = ~[dquat::from_angle_x(Rad(0.3)), dquat::from_angle_x(Rad(-0.3)),
+                    dquat::from_angle_y(Rad(0.3)), dquat::from_angle_y(Rad(-0.3))];
+    let quats = ~[c.mul_q(&offsets[0]), -c.mul_q(&offsets[1]),
+                  c.mul_q(&offsets[2]), -c.mul_q(&offsets[3])];
+    
+    assert average_quats(quats, ~[1.0, 1.0, 1.0, 1.0]).unwrap().fuzzy_eq(&c);
+    assert average_quats_fast(quats, ~[1.0, 1.0, 1.0, 1.0]).unwrap().fuzzy_eq(&c);
+    
+    // the result doesn't depend on the scale of the weights
+    let w = 10000000000.0;
+    
+    assert average_quats(quats, ~[w, w, w, w]).unwrap().fuzzy_eq(&c);
+    assert average_quats(~[a, b], ~[w, 3.0 * w]).unwrap().fuzzy_eq(&average_quats(~[a, b], ~[1.0, 3.0]).unwrap());
+    
+    // there is no average of nothing
+    let none: ~[dquat] = ~[];
+    
+    assert average_quats(none, ~[]).is_none();
+    assert average_quats_fast(none, ~[]).is_none();
+    assert average_quats(~[a, b], ~[0.0, 0.0]).is_none();
+    assert average_quats_fast(~[a, b], ~[0.0, 0.0]).is_none();
+}
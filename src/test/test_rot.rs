@@ -0,0 +1,80 @@
+use std::cmp::FuzzyEq;
+use numeric::*;
+use numeric::float::Float;
+
+use angle::*;
+use mat::*;
+use quat::*;
+use rot::*;
+use vec::*;
+
+#[test]
+fn test_quat_get_angle_axis() {
+    let axis = vec3::new(2.0, 3.0, 6.0).normalize();
+    let q = quat::from_angle_axis(Rad(1.25), &axis);
+    
+    let (angle, v) = q.get_angle_axis();
+    assert angle.fuzzy_eq(&Rad(1.25));
+    assert v.fuzzy_eq(&axis);
+    
+    // the quaternion does not need to be normalized
+    let (angle, v) = q.mul_t(3.0).get_angle_axis();
+    assert angle.fuzzy_eq(&Rad(1.25));
+    assert v.fuzzy_eq(&axis);
+    
+    let (angle, v) = q.mul_t(0.0000001).get_angle_axis();
+    assert angle.fuzzy_eq(&Rad(1.25));
+    assert v.fuzzy_eq(&axis);
+    
+    // 180 degree rotation
+    let (angle, v) = quat::from_angle_axis(Rad(2.0 * Float::frac_pi_2()), &vec3::unit_y()).get_angle_axis();
+    assert angle.fuzzy_eq(&Rad(2.0 * Float::frac_pi_2()));
+    assert v.fuzzy_eq(&vec3::unit_y());
+    
+    // the axis of the identity rotation is undefined
+    let (angle, v) = quat::identity().get_angle_axis();
+    assert angle == Rad(0.0);
+    assert v == vec3::unit_x();
+    
+    let (angle, _) = quat::from_angle_axis(Rad(0.00000001), &vec3::unit_z()).get_angle_axis();
+    assert angle == Rad(0.0);
+}
+
+#[test]
+fn test_axis_angle() {
+    let axis = vec3::new(1.0, 4.0, 8.0).normalize();
+    let a = axis_angle::new(axis, Rad(0.75));
+    
+    assert a.axis == axis;
+    assert a.angle == Rad(0.75);
+    assert axis_angle::new(axis, Deg(90.0)).angle.fuzzy_eq(&Rad(Float::frac_pi_2()));
+    
+    assert a.to_quat().fuzzy_eq(&quat::from_angle_axis(Rad(0.75), &axis));
+    assert a.to_mat3().fuzzy_eq(&mat3::from_angle_axis(Rad(0.75), &axis));
+    
+    assert axis_angle::from_quat(&a.to_quat()).fuzzy_eq(&a);
+    assert axis_angle::from_mat3(&a.to_mat3()).fuzzy_eq(&a);
+    
+    assert a.inverse().to_quat().mul_q(&a.to_quat()).fuzzy_eq(&quat::identity());
+    assert axis_angle::new(vec3::new(0.0, 0.0, 2.0), Rad(0.5)).normalize() == axis_angle::new(vec3::unit_z(), Rad(0.5));
+    
+    assert axis_angle::identity().to_quat() == quat::identity();
+    assert axis_angle::identity().to_mat3().fuzzy_eq(&mat3::identity());
+}
+
+#[test]
+fn test_axis_angle_half_turn() {
+    // a rotation of pi radians has a trace of -1, so `Mat3::to_quat` can't
+    // use the trace to find the scalar component
+    let pi = 2.0 * Float::frac_pi_2();
+    
+    for [vec3::unit_x(), vec3::unit_y(), vec3::unit_z(),
+         vec3::new(1.0, 1.0, 0.0).normalize()].each |axis| {
+        let m = mat3::from_angle_axis(Rad(pi), axis);
+        let a = axis_angle::from_mat3(&m);
+        
+        assert a.angle.fuzzy_eq(&Rad(pi));
+        assert a.axis.fuzzy_eq(axis) || a.axis.fuzzy_eq(&-*axis);
+        assert a.to_mat3().fuzzy_eq(&m);
+    }
+}
@@ -0,0 +1,71 @@
+use std::cmp::FuzzyEq;
+use numeric::*;
+use numeric::float::Float;
+
+use angle::*;
+use mat::*;
+use quat::*;
+use transform::*;
+use vec::*;
+
+#[test]
+fn test_transform3() {
+    let t = dtransform3::from_uniform_scale(2.0,
+                                            dquat::from_angle_axis(Rad(Float::frac_pi_2()), &dvec3::new(0.0, 0.0, 1.0)),
+                                            dvec3::new(1.0, 2.0, 3.0));
+    
+    assert t.transform_point(&dvec3::new(1.0, 0.0, 0.0)).fuzzy_eq(&dvec3::new(1.0, 4.0, 3.0));
+    assert t.transform_vector(&dvec3::new(1.0, 0.0, 0.0)).fuzzy_eq(&dvec3::new(0.0, 2.0, 0.0));
+    assert t.to_mat4().mul_v(&dvec4::new(1.0, 0.0, 0.0, 1.0)).fuzzy_eq(&dvec4::new(1.0, 4.0, 3.0, 1.0));
+    
+    assert t.inverse().transform_point(&dvec3::new(1.0, 4.0, 3.0)).fuzzy_eq(&dvec3::new(1.0, 0.0, 0.0));
+    assert t.concat(&t.inverse()).fuzzy_eq(&dtransform3::identity());
+    assert t.inverse().to_mat4().fuzzy_eq(&t.to_mat4().inverse().unwrap());
+    
+    let u = dtransform3::new(dvec3::new(0.5, 1.0, 2.0),
+                             dquat::from_angle_axis(Rad(1.0), &dvec3::new(0.6, 0.0, 0.8)),
+                             dvec3::new(-1.0, 0.0, 2.0));
+    
+    assert t.concat(&u).to_mat4().fuzzy_eq(&t.to_mat4().mul_m(&u.to_mat4()));
+    
+    let a = dtransform3::identity().lerp(&t, 0.5);
+    
+    assert a.scale.fuzzy_eq(&dvec3::new(1.5, 1.5, 1.5));
+    assert a.rotation.fuzzy_eq(&dquat::from_angle_axis(Rad(Float::frac_pi_4()), &dvec3::new(0.0, 0.0, 1.0)));
+    assert a.translation.fuzzy_eq(&dvec3::new(0.5, 1.0, 1.5));
+    
+    // the rotation takes the shortest path, regardless of the sign of the quaternion
+    let b = dtransform3::new(t.scale, -t.rotation, t.translation);
+    
+    assert dtransform3::identity().lerp(&b, 0.5).fuzzy_eq(&a);
+}
+
+#[test]
+fn test_transform2() {
+    let t = dtransform2::new(dvec2::new(2.0, 3.0), Rad(0.5), dvec2::new(1.0, -1.0));
+    let p = dvec2::new(1.0, 2.0);
+    let q = dmat2::from_angle(Rad(0.5)).mul_v(&dvec2::new(2.0, 6.0)).add_v(&dvec2::new(1.0, -1.0));
+    
+    assert t.transform_point(&p).fuzzy_eq(&q);
+    assert t.transform_vector(&p).fuzzy_eq(&q.sub_v(&dvec2::new(1.0, -1.0)));
+    assert t.to_mat3().mul_v(&dvec3::new(1.0, 2.0, 1.0)).fuzzy_eq(&dvec3::new(q.x, q.y, 1.0));
+    
+    let u = dtransform2::from_uniform_scale(2.0, Rad(0.25), dvec2::new(3.0, 4.0));
+    
+    assert u.concat(&t).to_mat3().fuzzy_eq(&u.to_mat3().mul_m(&t.to_mat3()));
+    assert u.inverse().transform_point(&u.transform_point(&p)).fuzzy_eq(&p);
+    assert u.concat(&u.inverse()).fuzzy_eq(&dtransform2::identity());
+    
+    assert dtransform2::identity().lerp(&u, 0.5).fuzzy_eq(&dtransform2::new(dvec2::new(1.5, 1.5),
+                                                                            Rad(0.125),
+                                                                            dvec2::new(1.5, 2.0)));
+    
+    // the rotation takes the shortest path, across the wrap-around at ±π
+    let a = dtransform2::new(dvec2::new(1.0, 1.0), Rad(3.0), dvec2::new(0.0, 0.0));
+    let b = dtransform2::new(dvec2::new(1.0, 1.0), Rad(-3.0), dvec2::new(0.0, 0.0));
+    let pi: f64 = Float::pi();
+    
+    assert a.lerp(&b, 0.5).rotation.fuzzy_eq(&Rad(pi));
+    assert b.lerp(&a, 0.5).rotation.fuzzy_eq(&Rad(-pi));
+    assert a.lerp(&b, 0.5).transform_point(&p).fuzzy_eq(&dvec2::new(-1.0, -2.0));
+}
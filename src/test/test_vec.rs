@@ -452,4 +452,262 @@ fn test_vec4_boolean() {
     assert tttt.any() == true;
     assert tttt.all() == true;
     assert tttt.not() == bvec4::new(false, false, false, false);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_vec_trig() {
+    // Section 8.1 of the GLSL 4.30.6 specification
+    
+    assert vec2::new(180.0, 90.0).radians().fuzzy_eq(&vec2::new(2.0 * Float::frac_pi_2(), Float::frac_pi_2()));
+    assert vec3::new(Float::frac_pi_2(), Float::frac_pi_3(), 0.0).degrees().fuzzy_eq(&vec3::new(90.0, 60.0, 0.0));
+    assert vec4::new(45.0, -90.0, 360.0, 0.0).radians().degrees().fuzzy_eq(&vec4::new(45.0, -90.0, 360.0, 0.0));
+    
+    let a = vec3::new(0.0, Float::frac_pi_2(), -Float::frac_pi_3());
+    
+    assert a.sin().fuzzy_eq(&vec3::new(0.0, 1.0, -sqrt(3.0) / 2.0));
+    assert a.cos().fuzzy_eq(&vec3::new(1.0, 0.0, 0.5));
+    assert vec2::new(0.0, -Float::frac_pi_3()).tan().fuzzy_eq(&vec2::new(0.0, -sqrt(3.0)));
+    
+    assert vec3::new(0.0, 1.0, -0.5).asin().fuzzy_eq(&vec3::new(0.0, Float::frac_pi_2(), -Float::frac_pi_3() / 2.0));
+    assert vec3::new(1.0, 0.0, 0.5).acos().fuzzy_eq(&vec3::new(0.0, Float::frac_pi_2(), Float::frac_pi_3()));
+    assert vec2::new(0.0, 1.0).atan().fuzzy_eq(&vec2::new(0.0, Float::frac_pi_2() / 2.0));
+    
+    // `atan2` treats `self` as `y` and `other` as `x`, like GLSL's `atan(y, x)`
+    assert vec4::new(1.0, 1.0, -1.0, 0.0).atan2(vec4::new(0.0, 1.0, 0.0, -1.0))
+        .fuzzy_eq(&vec4::new(Float::frac_pi_2(), Float::frac_pi_2() / 2.0, -Float::frac_pi_2(), 2.0 * Float::frac_pi_2()));
+    
+    let b = vec4::new(-2.0, -0.5, 0.0, 1.5);
+    
+    assert b.sinh().fuzzy_eq(&b.exp().sub_v(&b.neg().exp()).div_t(2.0));
+    assert b.cosh().fuzzy_eq(&b.exp().add_v(&b.neg().exp()).div_t(2.0));
+    assert b.tanh().fuzzy_eq(&b.sinh().div_v(&b.cosh()));
+    
+    assert b.sinh().asinh().fuzzy_eq(&b);
+    assert vec3::new(0.0, 0.5, 2.0).cosh().acosh().fuzzy_eq(&vec3::new(0.0, 0.5, 2.0));
+    assert vec2::new(-0.5, 0.25).tanh().atanh().fuzzy_eq(&vec2::new(-0.5, 0.25));
+}
+
+#[test]
+fn test_vec_exp() {
+    // Section 8.2 of the GLSL 4.30.6 specification
+    
+    let a = vec3::new(1.0, 2.0, 4.0);
+    
+    assert a.pow_t(2.0).fuzzy_eq(&vec3::new(1.0, 4.0, 16.0));
+    assert a.pow_v(&vec3::new(3.0, 0.5, -1.0)).fuzzy_eq(&vec3::new(1.0, sqrt(2.0), 0.25));
+    
+    assert vec2::new(0.0, 1.0).exp().fuzzy_eq(&vec2::new(1.0, Float::e()));
+    assert vec4::new(0.0, 1.0, 3.0, -1.0).exp2().fuzzy_eq(&vec4::new(1.0, 2.0, 8.0, 0.5));
+    assert vec2::new(1.0, Float::e()).ln().fuzzy_eq(&vec2::new(0.0, 1.0));
+    assert a.ln2().fuzzy_eq(&vec3::new(0.0, 1.0, 2.0));
+    
+    assert vec4::new(0.0, 1.0, 4.0, 16.0).sqrt().fuzzy_eq(&vec4::new(0.0, 1.0, 2.0, 4.0));
+    assert a.inv_sqrt().fuzzy_eq(&vec3::new(1.0, 1.0 / sqrt(2.0), 0.5));
+    
+    assert a.ln().exp().fuzzy_eq(&a);
+    assert a.ln2().exp2().fuzzy_eq(&a);
+}
+
+#[test]
+fn test_vec_approx() {
+    // Section 8.3 of the GLSL 4.30.6 specification
+    
+    let a = vec4::new(-1.5, -0.25, 0.5, 2.75);
+    
+    assert a.floor() == vec4::new(-2.0, -1.0, 0.0, 2.0);
+    assert a.trunc() == vec4::new(-1.0, -0.0, 0.0, 2.0);
+    assert a.ceil()  == vec4::new(-1.0, -0.0, 1.0, 3.0);
+    assert a.fract() == vec4::new( 0.5, 0.75, 0.5, 0.75);
+    
+    assert vec3::new(1.25, 1.75, -1.75).round() == vec3::new(1.0, 2.0, -2.0);
+    
+    assert vec4::new(0.5, 1.5, 2.5, -2.5).round_even() == vec4::new(0.0, 2.0, 2.0, -2.0);
+    assert vec4::new(3.5, -3.5, 2.25, -0.75).round_even() == vec4::new(4.0, -4.0, 2.0, -1.0);
+    assert vec2::new(4.0, -7.0).round_even() == vec2::new(4.0, -7.0);
+}
+
+#[test]
+fn test_vec_signed() {
+    // Section 8.3 of the GLSL 4.30.6 specification
+    
+    let a = vec3::new(-2.0, 0.0, 1.5);
+    
+    assert a.is_positive()    == bvec3::new(false, false, true);
+    assert a.is_negative()    == bvec3::new(true,  false, false);
+    assert a.is_nonpositive() == bvec3::new(true,  true,  false);
+    assert a.is_nonnegative() == bvec3::new(false, true,  true);
+    
+    assert a.abs()  == vec3::new(2.0, 0.0,  1.5);
+    assert a.sign() == vec3::new(-1.0, 0.0, 1.0);
+    assert a.copysign(vec3::new(1.0, -1.0, -0.5)) == vec3::new(2.0, -0.0, -1.5);
+    
+    let b = ivec4::new(-3, 0, 7, -1);
+    
+    assert b.is_positive() == bvec4::new(false, false, true, false);
+    assert b.is_negative() == bvec4::new(true, false, false, true);
+    
+    assert b.abs()  == ivec4::new(3, 0, 7, 1);
+    assert b.sign() == ivec4::new(-1, 0, 1, -1);
+    assert b.copysign(ivec4::new(1, -1, -2, 0)) == ivec4::new(3, 0, -7, 1);
+    
+    assert ivec2::new(-4, 4).abs() == ivec2::new(4, 4);
+    assert vec2::new(-0.5, 0.5).sign() == vec2::new(-1.0, 1.0);
+}
+
+#[test]
+fn test_vec_extent() {
+    // Section 8.3 of the GLSL 4.30.6 specification
+    
+    let c = vec3::new(-2.0, -1.0, 1.0);
+    let d = vec3::new( 1.0,  0.0, 0.5);
+    
+    assert c.min_v(&d) == vec3::new(-2.0, -1.0, 0.5);
+    assert c.max_v(&d) == vec3::new( 1.0,  0.0, 1.0);
+    assert c.min_t(0.0) == vec3::new(-2.0, -1.0, 0.0);
+    assert c.max_t(0.0) == vec3::new( 0.0,  0.0, 1.0);
+    
+    let a = ivec4::new(-5, 0, 3, 10);
+    
+    assert a.clamp_t(-1, 5) == ivec4::new(-1, 0, 3, 5);
+    assert a.clamp_v(&ivec4::new(0, 1, 4, 0), &ivec4::new(1, 2, 8, 9)) == ivec4::new(0, 1, 4, 9);
+    assert uvec2::new(3, 9).clamp_t(4, 8) == uvec2::new(4, 8);
+    assert uvec2::new(3, 9).min_v(&uvec2::new(5, 5)) == uvec2::new(3, 5);
+    
+    // the bounds are applied as `min(max(x, mn), mx)`, as in GLSL
+    assert vec2::new(0.0, 2.0).clamp_t(1.0, 1.0) == vec2::new(1.0, 1.0);
+}
+
+#[test]
+fn test_vec_mix() {
+    // Section 8.3 of the GLSL 4.30.6 specification
+    
+    let a = vec4::new(0.0, 1.0, -2.0, 4.0);
+    let b = vec4::new(1.0, 3.0,  2.0, 4.0);
+    
+    assert a.mix_t(&b, 0.0) == a;
+    assert a.mix_t(&b, 1.0) == b;
+    assert a.mix_t(&b, 0.5) == vec4::new(0.5, 2.0, 0.0, 4.0);
+    assert a.mix_v(&b, &vec4::new(0.0, 0.5, 0.25, 1.0)) == vec4::new(0.0, 2.0, -1.0, 4.0);
+    
+    let x = vec3::new(-1.0, 0.5, 2.0);
+    
+    assert x.step_t(0.5) == vec3::new(0.0, 1.0, 1.0);
+    assert x.step_v(&vec3::new(-2.0, 1.0, 2.0)) == vec3::new(1.0, 0.0, 1.0);
+    
+    assert x.smooth_step_t(0.0, 1.0) == vec3::new(0.0, 0.5, 1.0);
+    assert vec2::new(0.25, 0.75).smooth_step_t(0.0, 1.0).fuzzy_eq(&vec2::new(0.15625, 0.84375));
+    assert x.smooth_step_v(&vec3::new(-2.0, 0.0, 3.0), &vec3::new(0.0, 1.0, 4.0)) == vec3::new(0.5, 0.5, 0.0);
+    
+    // edge0 == edge1 degrades to a step at the edge instead of dividing by zero
+    assert x.smooth_step_t(0.5, 0.5) == x.step_t(0.5);
+    assert x.smooth_step_v(&vec3::new(-1.0, 1.0, 2.0), &vec3::new(-1.0, 1.0, 2.0)) == vec3::new(1.0, 0.0, 1.0);
+}
+
+#[test]
+fn test_vec_ops() {
+    let a = vec3::new(1.0, 2.0, 3.0);
+    let b = vec3::new(4.0, 5.0, 6.0);
+    
+    assert a + b == a.add_v(&b);
+    assert a - b == a.sub_v(&b);
+    assert a * b == a.mul_v(&b);
+    assert a / b == a.div_v(&b);
+    assert a * 2.0f32 == a.mul_t(2.0);
+    assert a / 2.0f32 == a.div_t(2.0);
+    
+    assert (a + b) * 0.5f32 == vec3::new(2.5, 3.5, 4.5);
+    
+    let mut c = a;
+    c += b;
+    assert c == a.add_v(&b);
+    c -= b;
+    assert c == a;
+    c *= 3.0f32;
+    assert c == vec3::new(3.0, 6.0, 9.0);
+    c /= a;
+    assert c == vec3::from_value(3.0);
+    
+    assert vec2::new(1.0, 2.0) + vec2::new(3.0, 4.0) == vec2::new(4.0, 6.0);
+    assert vec4::new(2.0, 4.0, 6.0, 8.0) / 2.0f32 == vec4::new(1.0, 2.0, 3.0, 4.0);
+    
+    assert ivec2::new(1, -2) * 3i32 == ivec2::new(3, -6);
+    assert ivec4::new(8, 6, 4, 2) / ivec4::new(2, 3, 4, 2) == ivec4::new(4, 2, 1, 1);
+}
+
+#[test]
+fn test_vec_swizzle() {
+    let a = vec2::new(1.0, 2.0);
+    
+    assert a.yx() == vec2::new(2.0, 1.0);
+    assert a.xyx() == vec3::new(1.0, 2.0, 1.0);
+    assert a.yyyy() == vec4::new(2.0, 2.0, 2.0, 2.0);
+    assert a.gr() == a.yx();
+    assert a.ts() == a.yx();
+    
+    let b = vec3::new(1.0, 2.0, 3.0);
+    
+    assert b.xy() == vec2::new(1.0, 2.0);
+    assert b.zyx() == vec3::new(3.0, 2.0, 1.0);
+    assert b.xxzz() == vec4::new(1.0, 1.0, 3.0, 3.0);
+    assert b.bgr() == b.zyx();
+    assert b.pts() == b.zyx();
+    
+    let c = vec4::new(1.0, 2.0, 3.0, 4.0);
+    
+    assert c.xyz() == vec3::new(1.0, 2.0, 3.0);
+    assert c.wz() == vec2::new(4.0, 3.0);
+    assert c.wzyx() == vec4::new(4.0, 3.0, 2.0, 1.0);
+    assert c.xxxx() == vec4::from_value(1.0);
+    assert c.rgba() == c;
+    assert c.abgr() == c.wzyx();
+    assert c.qpts() == c.wzyx();
+    
+    assert ivec3::new(1, 2, 3).zxy() == ivec3::new(3, 1, 2);
+    assert bvec4::new(true, false, false, true).xw() == bvec2::new(true, true);
+    
+    let mut d = vec4::new(1.0, 2.0, 3.0, 4.0);
+    d.set_xy(&vec2::new(5.0, 6.0));
+    assert d == vec4::new(5.0, 6.0, 3.0, 4.0);
+    d.set_wzy(&vec3::new(7.0, 8.0, 9.0));
+    assert d == vec4::new(5.0, 9.0, 8.0, 7.0);
+    d.set_abgr(&vec4::new(1.0, 2.0, 3.0, 4.0));
+    assert d == vec4::new(4.0, 3.0, 2.0, 1.0);
+    
+    let mut e = vec3::new(1.0, 2.0, 3.0);
+    e.set_zx(&vec2::new(0.0, 1.0));
+    assert e == vec3::new(1.0, 2.0, 0.0);
+    e.set_ts(&vec2::new(4.0, 5.0));
+    assert e == vec3::new(5.0, 4.0, 0.0);
+    
+    let mut f = vec2::new(1.0, 2.0);
+    let g = f.xy();
+    f.set_yx(&g);
+    assert f == vec2::new(2.0, 1.0);
+}
+
+#[test]
+fn test_gram_schmidt() {
+    let basis = gram_schmidt(&[vec3::new(3.0, 0.0, 0.0),
+                              vec3::new(1.0, 2.0, 0.0),
+                              vec3::new(2.0, 4.0, 0.0),     // linearly dependent
+                              vec3::new(1.0, 1.0, 5.0)]);
+    
+    assert basis.len() == 3;
+    assert basis[0] == vec3::new(1.0, 0.0, 0.0);
+    assert basis[1] == vec3::new(0.0, 1.0, 0.0);
+    assert basis[2] == vec3::new(0.0, 0.0, 1.0);
+    
+    let basis = gram_schmidt(&[dvec4::new(1.0, 1.0, 0.0, 0.0),
+                              dvec4::new(1.0, 0.0, 1.0, 0.0),
+                              dvec4::new(0.0, 1.0, 1.0, 1.0)]);
+    
+    assert basis.len() == 3;
+    
+    for uint::range(0, 3) |i| {
+        assert basis[i].length().fuzzy_eq(&1.0);
+        
+        for uint::range(i + 1, 3) |j| {
+            assert basis[i].dot(&basis[j]).fuzzy_eq(&0.0);
+        }
+    }
+}
@@ -0,0 +1,358 @@
+use core::cmp::Eq;
+
+use std::cmp::{FuzzyEq, FUZZY_EPSILON};
+use numeric::*;
+use numeric::number::Number;
+use numeric::number::Number::{zero,one};
+
+use angle::{Angle, Rad};
+
+use mat::{
+    Mat2,
+    Mat3,
+    Mat4,
+    Matrix,
+    Matrix2,
+    Matrix3,
+    Matrix4,
+};
+
+use quat::Quat;
+
+use vec::{
+    Vec2,
+    Vec3,
+    Vector2,
+    Vector3,
+    NumericVector,
+    EuclideanVector,
+    vec2,
+    dvec2,
+    vec3,
+    dvec3,
+};
+
+/**
+ * A three-dimensional transformation that scales, then rotates, then
+ * translates
+ *
+ * Composition and inversion are only exact if the scale is uniform, because
+ * a non-uniform scale followed by a rotation can introduce a shear that this
+ * representation can not express.
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the components. Should be a floating point type.
+ *
+ * # Fields
+ *
+ * * `scale` - the scale along each axis
+ * * `rotation` - the rotation. This should be normalized.
+ * * `translation` - the translation along each axis
+ */
+#[deriving_eq]
+pub struct Transform3<T> { scale: Vec3<T>, rotation: Quat<T>, translation: Vec3<T> }
+
+pub impl<T:Copy Float FuzzyEq<T>> Transform3<T> {
+    /**
+     * Construct the transformation from a scale, a rotation and a translation
+     */
+    #[inline(always)]
+    static pure fn new(scale: Vec3<T>, rotation: Quat<T>, translation: Vec3<T>) -> Transform3<T> {
+        Transform3 { scale: scale, rotation: rotation, translation: translation }
+    }
+    
+    /**
+     * Construct the transformation from a uniform scale, a rotation and a
+     * translation
+     */
+    #[inline(always)]
+    static pure fn from_uniform_scale(scale: T, rotation: Quat<T>, translation: Vec3<T>) -> Transform3<T> {
+        Transform3::new(Vector3::new(scale, scale, scale), rotation, translation)
+    }
+    
+    /**
+     * # Return value
+     *
+     * The transformation that leaves every point unchanged
+     */
+    #[inline(always)]
+    static pure fn identity() -> Transform3<T> {
+        Transform3::new(Vector3::new(one(), one(), one()),
+                        Quat::identity(),
+                        Vector3::new(zero(), zero(), zero()))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The point transformed by the scale, rotation and translation
+     */
+    #[inline(always)]
+    pure fn transform_point(&self, point: &Vec3<T>) -> Vec3<T> {
+        self.transform_vector(point).add_v(&self.translation)
+    }
+    
+    /**
+     * # Return value
+     *
+     * The vector transformed by the scale and rotation. Unlike a point, a
+     * vector is not affected by the translation.
+     */
+    #[inline(always)]
+    pure fn transform_vector(&self, vec: &Vec3<T>) -> Vec3<T> {
+        self.rotation.mul_v(&vec.mul_v(&self.scale))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The transformation that applies `other` and then this transformation,
+     * ie. the equivalent of `self.to_mat4() * other.to_mat4()`
+     */
+    #[inline(always)]
+    pure fn concat(&self, other: &Transform3<T>) -> Transform3<T> {
+        Transform3::new(self.scale.mul_v(&other.scale),
+                        self.rotation.mul_q(&other.rotation),
+                        self.transform_point(&other.translation))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The transformation that undoes this transformation
+     */
+    #[inline(always)]
+    pure fn inverse(&self) -> Transform3<T> {
+        let scale = Vector3::new(one::<T>() / self.scale.x,
+                                 one::<T>() / self.scale.y,
+                                 one::<T>() / self.scale.z);
+        let rotation = self.rotation.conjugate();
+        
+        Transform3::new(scale, rotation,
+                        rotation.mul_v(&-self.translation).mul_v(&scale))
+    }
+    
+    /**
+     * Interpolate between the transformation and `other`, using a spherical
+     * linear interpolation for the rotation and a linear interpolation for
+     * the scale and translation. The rotation takes the shortest path.
+     *
+     * # Return value
+     *
+     * The interpolated transformation
+     */
+    #[inline(always)]
+    pure fn lerp(&self, other: &Transform3<T>, amount: T) -> Transform3<T> {
+        Transform3::new(self.scale.lerp(&other.scale, amount),
+                        self.rotation.slerp(&self.rotation.nearest(&other.rotation), amount),
+                        self.translation.lerp(&other.translation, amount))
+    }
+    
+    /**
+     * Convert the transformation to a 4 x 4 affine transformation matrix
+     */
+    #[inline(always)]
+    pure fn to_mat4(&self) -> Mat4<T> {
+        let r = self.rotation.to_mat3();
+        let s = &self.scale;
+        let t = &self.translation;
+        
+        Matrix4::new(r[0][0] * s.x, r[0][1] * s.x, r[0][2] * s.x, zero(),
+                     r[1][0] * s.y, r[1][1] * s.y, r[1][2] * s.y, zero(),
+                     r[2][0] * s.z, r[2][1] * s.z, r[2][2] * s.z, zero(),
+                               t.x,           t.y,           t.z,  one())
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Transform3<T>: FuzzyEq<T> {
+    #[inline(always)]
+    pure fn fuzzy_eq(&self, other: &Transform3<T>) -> bool {
+        self.fuzzy_eq_eps(other, &Number::from(FUZZY_EPSILON))
+    }
+    
+    #[inline(always)]
+    pure fn fuzzy_eq_eps(&self, other: &Transform3<T>, epsilon: &T) -> bool {
+        self.scale.fuzzy_eq_eps(&other.scale, epsilon) &&
+        self.rotation.fuzzy_eq_eps(&other.rotation, epsilon) &&
+        self.translation.fuzzy_eq_eps(&other.translation, epsilon)
+    }
+}
+
+/**
+ * A two-dimensional transformation that scales, then rotates, then
+ * translates
+ *
+ * As with `Transform3`, composition and inversion are only exact if the scale
+ * is uniform.
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the components. Should be a floating point type.
+ *
+ * # Fields
+ *
+ * * `scale` - the scale along each axis
+ * * `rotation` - the angle of rotation, in the same direction as
+ *                `Matrix2::from_angle`
+ * * `translation` - the translation along each axis
+ */
+#[deriving_eq]
+pub struct Transform2<T> { scale: Vec2<T>, rotation: Rad<T>, translation: Vec2<T> }
+
+pub impl<T:Copy Float FuzzyEq<T>> Transform2<T> {
+    /**
+     * Construct the transformation from a scale, an angle of rotation and a
+     * translation
+     */
+    #[inline(always)]
+    static pure fn new<A:Angle<T>>(scale: Vec2<T>, rotation: A, translation: Vec2<T>) -> Transform2<T> {
+        Transform2 { scale: scale, rotation: rotation.to_rad(), translation: translation }
+    }
+    
+    /**
+     * Construct the transformation from a uniform scale, an angle of rotation
+     * and a translation
+     */
+    #[inline(always)]
+    static pure fn from_uniform_scale<A:Angle<T>>(scale: T, rotation: A, translation: Vec2<T>) -> Transform2<T> {
+        Transform2::new(Vector2::new(scale, scale), rotation, translation)
+    }
+    
+    /**
+     * # Return value
+     *
+     * The transformation that leaves every point unchanged
+     */
+    #[inline(always)]
+    static pure fn identity() -> Transform2<T> {
+        Transform2::new(Vector2::new(one(), one()), Rad(zero()), Vector2::new(zero(), zero()))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The point transformed by the scale, rotation and translation
+     */
+    #[inline(always)]
+    pure fn transform_point(&self, point: &Vec2<T>) -> Vec2<T> {
+        self.transform_vector(point).add_v(&self.translation)
+    }
+    
+    /**
+     * # Return value
+     *
+     * The vector transformed by the scale and rotation. Unlike a point, a
+     * vector is not affected by the translation.
+     */
+    #[inline(always)]
+    pure fn transform_vector(&self, vec: &Vec2<T>) -> Vec2<T> {
+        let r: Mat2<T> = Matrix2::from_angle(self.rotation);
+        r.mul_v(&vec.mul_v(&self.scale))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The transformation that applies `other` and then this transformation,
+     * ie. the equivalent of `self.to_mat3() * other.to_mat3()`
+     */
+    #[inline(always)]
+    pure fn concat(&self, other: &Transform2<T>) -> Transform2<T> {
+        Transform2::new(self.scale.mul_v(&other.scale),
+                        self.rotation + other.rotation,
+                        self.transform_point(&other.translation))
+    }
+    
+    /**
+     * # Return value
+     *
+     * The transformation that undoes this transformation
+     */
+    #[inline(always)]
+    pure fn inverse(&self) -> Transform2<T> {
+        let scale = Vector2::new(one::<T>() / self.scale.x,
+                                 one::<T>() / self.scale.y);
+        let r: Mat2<T> = Matrix2::from_angle(-self.rotation);
+        
+        Transform2::new(scale, -self.rotation,
+                        r.mul_v(&-self.translation).mul_v(&scale))
+    }
+    
+    /**
+     * Linearly interpolate the scale, angle and translation of the
+     * transformation towards `other`. The rotation takes the shortest path.
+     *
+     * # Return value
+     *
+     * The interpolated transformation
+     */
+    #[inline(always)]
+    pure fn lerp(&self, other: &Transform2<T>, amount: T) -> Transform2<T> {
+        Transform2::new(self.scale.lerp(&other.scale, amount),
+                        self.rotation + (other.rotation - self.rotation).wrap_signed().mul_t(amount),
+                        self.translation.lerp(&other.translation, amount))
+    }
+    
+    /**
+     * Convert the transformation to a 3 x 3 matrix that operates on
+     * homogeneous two-dimensional coordinates
+     */
+    #[inline(always)]
+    pure fn to_mat3(&self) -> Mat3<T> {
+        let r: Mat2<T> = Matrix2::from_angle(self.rotation);
+        let s = &self.scale;
+        let t = &self.translation;
+        
+        Matrix3::new(r[0][0] * s.x, r[0][1] * s.x, zero(),
+                     r[1][0] * s.y, r[1][1] * s.y, zero(),
+                               t.x,           t.y,  one())
+    }
+}
+
+pub impl<T:Copy Float FuzzyEq<T>> Transform2<T>: FuzzyEq<T> {
+    #[inline(always)]
+    pure fn fuzzy_eq(&self, other: &Transform2<T>) -> bool {
+        self.fuzzy_eq_eps(other, &Number::from(FUZZY_EPSILON))
+    }
+    
+    #[inline(always)]
+    pure fn fuzzy_eq_eps(&self, other: &Transform2<T>, epsilon: &T) -> bool {
+        self.scale.fuzzy_eq_eps(&other.scale, epsilon) &&
+        self.rotation.fuzzy_eq_eps(&other.rotation, epsilon) &&
+        self.translation.fuzzy_eq_eps(&other.translation, epsilon)
+    }
+}
+
+// Type aliases following the nomenclature of the GLSL-style types
+
+pub type transform2  = Transform2<f32>;     /// a single-precision floating-point 2D transformation
+pub type dtransform2 = Transform2<f64>;     /// a double-precision floating-point 2D transformation
+pub type transform3  = Transform3<f32>;     /// a single-precision floating-point 3D transformation
+pub type dtransform3 = Transform3<f64>;     /// a double-precision floating-point 3D transformation
+
+// Static method wrappers for GLSL-style types
+
+pub impl transform2 {
+    #[inline(always)] static pure fn new<A:Angle<f32>>(scale: vec2, rotation: A, translation: vec2) -> transform2 { Transform2::new(scale, rotation, translation) }
+    #[inline(always)] static pure fn from_uniform_scale<A:Angle<f32>>(scale: f32, rotation: A, translation: vec2) -> transform2 { Transform2::from_uniform_scale(scale, rotation, translation) }
+    #[inline(always)] static pure fn identity() -> transform2 { Transform2::identity() }
+}
+
+pub impl dtransform2 {
+    #[inline(always)] static pure fn new<A:Angle<f64>>(scale: dvec2, rotation: A, translation: dvec2) -> dtransform2 { Transform2::new(scale, rotation, translation) }
+    #[inline(always)] static pure fn from_uniform_scale<A:Angle<f64>>(scale: f64, rotation: A, translation: dvec2) -> dtransform2 { Transform2::from_uniform_scale(scale, rotation, translation) }
+    #[inline(always)] static pure fn identity() -> dtransform2 { Transform2::identity() }
+}
+
+pub impl transform3 {
+    #[inline(always)] static pure fn new(scale: vec3, rotation: Quat<f32>, translation: vec3) -> transform3 { Transform3::new(scale, rotation, translation) }
+    #[inline(always)] static pure fn from_uniform_scale(scale: f32, rotation: Quat<f32>, translation: vec3) -> transform3 { Transform3::from_uniform_scale(scale, rotation, translation) }
+    #[inline(always)] static pure fn identity() -> transform3 { Transform3::identity() }
+}
+
+pub impl dtransform3 {
+    #[inline(always)] static pure fn new(scale: dvec3, rotation: Quat<f64>, translation: dvec3) -> dtransform3 { Transform3::new(scale, rotation, translation) }
+    #[inline(always)] static pure fn from_uniform_scale(scale: f64, rotation: Quat<f64>, translation: dvec3) -> dtransform3 { Transform3::from_uniform_scale(scale, rotation, translation) }
+    #[inline(always)] static pure fn identity() -> dtransform3 { Transform3::identity() }
+}
@@ -2,7 +2,9 @@ use core::cmp::Eq;
 
 use std::cmp::FuzzyEq;
 
+use numeric::*;
 use numeric::Number;
+use numeric::number::Number::{zero,one};
 
 pub use vec2::{Vec2, vec2, dvec2, bvec2, ivec2, uvec2};
 pub use vec3::{Vec3, vec3, dvec3, bvec3, ivec3, uvec3};
@@ -227,6 +229,106 @@ pub trait MutableNumericVector3<T>: MutableNumericVector<&self/T> {
     fn cross_self(&mut self, other: &Self);
 }
 
+/**
+ * A type that can appear on the right hand side of the `*` and `/` operators
+ * when a vector of type `V` is on the left hand side. This allows vectors to be
+ * multiplied and divided by both scalars and other vectors. Note that the
+ * compound assignment operators (`*=`, `/=`) are desugared to the binary
+ * operators, and so are supported as well.
+ *
+ * Scalars can only appear on the right hand side, eg. `v * 2.0` rather than
+ * `2.0 * v`. The operator traits and the primitive numeric types are both
+ * defined in `core`, so the coherence rules don't allow this crate to
+ * implement `Mul<Vec3<f32>, Vec3<f32>>` for `f32`.
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the components of the vector
+ * * `V` - The type of the vector on the left hand side of the operator
+ */
+pub trait VectorMulRHS<T, V> {
+    /**
+     * # Return value
+     *
+     * The result of `lhs * self`
+     */
+    pure fn rmul_v(&self, lhs: &V) -> V;
+    
+    /**
+     * # Return value
+     *
+     * The result of `lhs / self`
+     */
+    pure fn rdiv_v(&self, lhs: &V) -> V;
+}
+
+// Scalar multiplication and division, eg. `v * 2.0` or `v / 2.0`
+
+pub impl<V:NumericVector<float>> float: VectorMulRHS<float, V> {
+    #[inline(always)] pure fn rmul_v(&self, lhs: &V) -> V { lhs.mul_t(*self) }
+    #[inline(always)] pure fn rdiv_v(&self, lhs: &V) -> V { lhs.div_t(*self) }
+}
+
+pub impl<V:NumericVector<f32>> f32: VectorMulRHS<f32, V> {
+    #[inline(always)] pure fn rmul_v(&self, lhs: &V) -> V { lhs.mul_t(*self) }
+    #[inline(always)] pure fn rdiv_v(&self, lhs: &V) -> V { lhs.div_t(*self) }
+}
+
+pub impl<V:NumericVector<f64>> f64: VectorMulRHS<f64, V> {
+    #[inline(always)] pure fn rmul_v(&self, lhs: &V) -> V { lhs.mul_t(*self) }
+    #[inline(always)] pure fn rdiv_v(&self, lhs: &V) -> V { lhs.div_t(*self) }
+}
+
+pub impl<V:NumericVector<int>> int: VectorMulRHS<int, V> {
+    #[inline(always)] pure fn rmul_v(&self, lhs: &V) -> V { lhs.mul_t(*self) }
+    #[inline(always)] pure fn rdiv_v(&self, lhs: &V) -> V { lhs.div_t(*self) }
+}
+
+pub impl<V:NumericVector<i8>> i8: VectorMulRHS<i8, V> {
+    #[inline(always)] pure fn rmul_v(&self, lhs: &V) -> V { lhs.mul_t(*self) }
+    #[inline(always)] pure fn rdiv_v(&self, lhs: &V) -> V { lhs.div_t(*self) }
+}
+
+pub impl<V:NumericVector<i16>> i16: VectorMulRHS<i16, V> {
+    #[inline(always)] pure fn rmul_v(&self, lhs: &V) -> V { lhs.mul_t(*self) }
+    #[inline(always)] pure fn rdiv_v(&self, lhs: &V) -> V { lhs.div_t(*self) }
+}
+
+pub impl<V:NumericVector<i32>> i32: VectorMulRHS<i32, V> {
+    #[inline(always)] pure fn rmul_v(&self, lhs: &V) -> V { lhs.mul_t(*self) }
+    #[inline(always)] pure fn rdiv_v(&self, lhs: &V) -> V { lhs.div_t(*self) }
+}
+
+pub impl<V:NumericVector<i64>> i64: VectorMulRHS<i64, V> {
+    #[inline(always)] pure fn rmul_v(&self, lhs: &V) -> V { lhs.mul_t(*self) }
+    #[inline(always)] pure fn rdiv_v(&self, lhs: &V) -> V { lhs.div_t(*self) }
+}
+
+pub impl<V:NumericVector<uint>> uint: VectorMulRHS<uint, V> {
+    #[inline(always)] pure fn rmul_v(&self, lhs: &V) -> V { lhs.mul_t(*self) }
+    #[inline(always)] pure fn rdiv_v(&self, lhs: &V) -> V { lhs.div_t(*self) }
+}
+
+pub impl<V:NumericVector<u8>> u8: VectorMulRHS<u8, V> {
+    #[inline(always)] pure fn rmul_v(&self, lhs: &V) -> V { lhs.mul_t(*self) }
+    #[inline(always)] pure fn rdiv_v(&self, lhs: &V) -> V { lhs.div_t(*self) }
+}
+
+pub impl<V:NumericVector<u16>> u16: VectorMulRHS<u16, V> {
+    #[inline(always)] pure fn rmul_v(&self, lhs: &V) -> V { lhs.mul_t(*self) }
+    #[inline(always)] pure fn rdiv_v(&self, lhs: &V) -> V { lhs.div_t(*self) }
+}
+
+pub impl<V:NumericVector<u32>> u32: VectorMulRHS<u32, V> {
+    #[inline(always)] pure fn rmul_v(&self, lhs: &V) -> V { lhs.mul_t(*self) }
+    #[inline(always)] pure fn rdiv_v(&self, lhs: &V) -> V { lhs.div_t(*self) }
+}
+
+pub impl<V:NumericVector<u64>> u64: VectorMulRHS<u64, V> {
+    #[inline(always)] pure fn rmul_v(&self, lhs: &V) -> V { lhs.mul_t(*self) }
+    #[inline(always)] pure fn rdiv_v(&self, lhs: &V) -> V { lhs.div_t(*self) }
+}
+
 pub trait ToHomogeneous<H> {
     /**
      * Convert to a homogenous coordinate
@@ -331,6 +433,34 @@ pub trait MutableEuclideanVector<T>: MutableNumericVector<&self/T>
     fn lerp_self(&mut self, other: &Self, amount: T);
 }
 
+/**
+ * Orthonormalize a set of vectors using the modified Gram-Schmidt process
+ *
+ * # Return value
+ *
+ * An orthonormal basis spanning the same space as `vectors`, with each
+ * vector in the basis derived from the corresponding input vector. Vectors
+ * that are linearly dependent on the vectors before them are skipped, so the
+ * result may contain fewer vectors than the input.
+ */
+pub pure fn gram_schmidt<T:Copy Float FuzzyEq<T>, V:Copy EuclideanVector<T>>(vectors: &[V]) -> ~[V] {
+    let mut basis = ~[];
+    
+    for vectors.each |v| {
+        let mut u = *v;
+        
+        for basis.each |b| {
+            u = u.sub_v(&b.mul_t(b.dot(&u)));
+        }
+        
+        if !u.length().fuzzy_eq(&zero()) {
+            unsafe { basis.push(u.normalize()); }
+        }
+    }
+    
+    basis
+}
+
 /**
  * Component-wise vector comparison methods
  *
@@ -409,6 +539,13 @@ pub trait BooleanVector: Vector<bool> {
     pure fn not(&self) -> Self;
 }
 
+/**
+ * Component-wise angle and trigonometry functions
+ *
+ * The methods contained in this trait correspond to the angle and
+ * trigonometry functions mentioned in Section 8.1 of the [GLSL 4.30.6 specification]
+ * (http://www.opengl.org/registry/doc/GLSLangSpec.4.30.6.pdf).
+ */
 pub trait TrigVec<T>: Vector<T> {
     pure fn radians(&self) -> Self;
     pure fn degrees(&self) -> Self;
@@ -428,15 +565,55 @@ pub trait TrigVec<T>: Vector<T> {
     pure fn sinh(&self)                     -> Self;
     pure fn cosh(&self)                     -> Self;
     pure fn tanh(&self)                     -> Self;
-    // pure fn asinh()                      -> Self;
-    // pure fn acosh()                      -> Self;
-    // pure fn atanh()                      -> Self;
+    
+    // Inverse hyperbolic triganometric functions
+    pure fn asinh(&self)                    -> Self;
+    pure fn acosh(&self)                    -> Self;
+    pure fn atanh(&self)                    -> Self;
+}
+
+/**
+ * # Return value
+ *
+ * The inverse hyperbolic sine of `x`
+ */
+#[inline(always)]
+pub pure fn asinh<T:Copy Float>(x: T) -> T {
+    ln(x + sqrt(x * x + one()))
+}
+
+/**
+ * # Return value
+ *
+ * The inverse hyperbolic cosine of `x`. The result is undefined if `x < 1`.
+ */
+#[inline(always)]
+pub pure fn acosh<T:Copy Float>(x: T) -> T {
+    ln(x + sqrt(x * x - one()))
 }
 
+/**
+ * # Return value
+ *
+ * The inverse hyperbolic tangent of `x`. The result is undefined if
+ * `|x| >= 1`.
+ */
+#[inline(always)]
+pub pure fn atanh<T:Copy Float>(x: T) -> T {
+    ln((one::<T>() + x) / (one::<T>() - x)) / Number::from(2)
+}
+
+/**
+ * Component-wise exponential functions
+ *
+ * The methods contained in this trait correspond to the exponential functions
+ * mentioned in Section 8.2 of the [GLSL 4.30.6 specification]
+ * (http://www.opengl.org/registry/doc/GLSLangSpec.4.30.6.pdf).
+ */
 pub trait ExpVec<T>: Vector<T> {
     // Exponential functions
-    pure fn pow_t(&self, n: Self)           -> Self;
-    pure fn pow_v(&self, n: T)              -> Self;
+    pure fn pow_t(&self, n: T)              -> Self;
+    pure fn pow_v(&self, n: &Self)          -> Self;
     pure fn exp(&self)                      -> Self;
     pure fn exp2(&self)                     -> Self;
     pure fn ln(&self)                       -> Self;
@@ -445,16 +622,61 @@ pub trait ExpVec<T>: Vector<T> {
     pure fn inv_sqrt(&self)                 -> Self;
 }
 
+/**
+ * Component-wise whole-number approximation functions
+ *
+ * The methods contained in this trait correspond to the common functions
+ * mentioned in Section 8.3 of the [GLSL 4.30.6 specification]
+ * (http://www.opengl.org/registry/doc/GLSLangSpec.4.30.6.pdf).
+ */
 pub trait ApproxVec<T>: Vector<T> {
     // Whole-number approximation functions
     pure fn floor(&self)                    -> Self;
     pure fn trunc(&self)                    -> Self;
     pure fn round(&self)                    -> Self;
-    // pure fn round_even(&self)            -> Self;
+    pure fn round_even(&self)               -> Self;
     pure fn ceil(&self)                     -> Self;
     pure fn fract(&self)                    -> Self;
 }
 
+/**
+ * # Return value
+ *
+ * `x` rounded to the nearest whole number, with halfway values rounded
+ * to the nearest even number (eg. both `1.5` and `2.5` round to `2.0`).
+ */
+#[inline(always)]
+pub pure fn round_even<T:Copy Float>(x: T) -> T {
+    let _2: T = Number::from(2);
+    let half: T = Number::from(0.5);
+    
+    let f = floor(x);
+    let d = x - f;
+    
+    if d < half {
+        f
+    } else if d > half {
+        f + one()
+    } else if floor(f / _2) * _2 == f {
+        f
+    } else {
+        f + one()
+    }
+}
+
+/**
+ * Component-wise sign functions
+ *
+ * The methods contained in this trait correspond to the common functions
+ * mentioned in Section 8.3 of the [GLSL 4.30.6 specification]
+ * (http://www.opengl.org/registry/doc/GLSLangSpec.4.30.6.pdf).
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the components. This should be a signed integer or
+ *         floating point type.
+ * * `BV` - The boolean vector type returned by the predicates
+ */
 pub trait SignedVec<T,BV>: Vector<T> {
     pure fn is_positive(&self)    -> BV;
     pure fn is_negative(&self)    -> BV;
@@ -466,6 +688,15 @@ pub trait SignedVec<T,BV>: Vector<T> {
     pure fn copysign(&self, other: Self) -> Self;
 }
 
+/**
+ * Component-wise minimum, maximum and clamping functions
+ *
+ * The methods contained in this trait correspond to the common functions
+ * mentioned in Section 8.3 of the [GLSL 4.30.6 specification]
+ * (http://www.opengl.org/registry/doc/GLSLangSpec.4.30.6.pdf). The `_v`
+ * methods take a vector argument, and the `_t` methods take a scalar
+ * argument that is applied to each component.
+ */
 pub trait ExtentVec<T>: Vector<T> {
     pure fn min_v(&self, other: &Self) -> Self;
     pure fn max_v(&self, other: &Self) -> Self;
@@ -476,9 +707,58 @@ pub trait ExtentVec<T>: Vector<T> {
     pure fn clamp_t(&self, mn: T, mx: T) -> Self;
 }
 
+/**
+ * # Return value
+ *
+ * `0` if `x <= edge0` and `1` if `x >= edge1`, performing a smooth Hermite
+ * interpolation between `0` and `1` when `edge0 < x < edge1`. If `edge0` is
+ * equal to `edge1` the result falls back to a hard step at `edge0`.
+ */
+#[inline(always)]
+pub pure fn smooth_step<T:Copy Float>(edge0: T, edge1: T, x: T) -> T {
+    if edge0 == edge1 {
+        if x < edge0 { zero() } else { one() }
+    } else {
+        let _2: T = Number::from(2);
+        let _3: T = Number::from(3);
+        
+        let t = ((x - edge0) / (edge1 - edge0)).clamp(zero(), one());
+        t * t * (_3 - _2 * t)
+    }
+}
+
+/**
+ * Component-wise blending functions
+ *
+ * The methods contained in this trait correspond to the common functions
+ * mentioned in Section 8.3 of the [GLSL 4.30.6 specification]
+ * (http://www.opengl.org/registry/doc/GLSLangSpec.4.30.6.pdf). As with
+ * `ExtentVec`, the `_v` methods take vector arguments and the `_t` methods
+ * take scalar arguments.
+ */
 pub trait MixVec<T>: Vector<T> {
-    // Functions for blending numbers together
-    pure fn mix(&self, other: Self, value: Self) -> Self;
-    pure fn smooth_step(&self, edge0: Self, edge1: Self) -> Self;
-    pure fn step(&self, edge: Self) -> Self;
-}
\ No newline at end of file
+    /**
+     * # Return value
+     *
+     * The linear blend of the vector and `other`, ie. `self * (1 - value) + other * value`
+     */
+    pure fn mix_v(&self, other: &Self, value: &Self) -> Self;
+    pure fn mix_t(&self, other: &Self, value: T) -> Self;
+    
+    /**
+     * # Return value
+     *
+     * `0` for components less than `edge0`, `1` for components greater than
+     * `edge1`, and a smooth Hermite interpolation for components in between
+     */
+    pure fn smooth_step_v(&self, edge0: &Self, edge1: &Self) -> Self;
+    pure fn smooth_step_t(&self, edge0: T, edge1: T) -> Self;
+    
+    /**
+     * # Return value
+     *
+     * `0` for components less than `edge`, otherwise `1`
+     */
+    pure fn step_v(&self, edge: &Self) -> Self;
+    pure fn step_t(&self, edge: T) -> Self;
+}
@@ -19,12 +19,24 @@ use vec::{
     NumericVector,
     NumericVector2,
     MutableNumericVector,
+    VectorMulRHS,
     ToHomogeneous,
     EuclideanVector,
     MutableEuclideanVector,
     EquableVector,
     OrdinalVector,
     BooleanVector,
+    TrigVec,
+    ExpVec,
+    ApproxVec,
+    SignedVec,
+    ExtentVec,
+    MixVec,
+    asinh,
+    acosh,
+    atanh,
+    round_even,
+    smooth_step,
 };
 
 /**
@@ -157,6 +169,46 @@ pub impl<T:Copy Number> Vec2<T>: Neg<Vec2<T>> {
     }
 }
 
+pub impl<T:Copy Number> Vec2<T>: Add<Vec2<T>, Vec2<T>> {
+    #[inline(always)]
+    pure fn add(&self, rhs: &Vec2<T>) -> Vec2<T> {
+        self.add_v(rhs)
+    }
+}
+
+pub impl<T:Copy Number> Vec2<T>: Sub<Vec2<T>, Vec2<T>> {
+    #[inline(always)]
+    pure fn sub(&self, rhs: &Vec2<T>) -> Vec2<T> {
+        self.sub_v(rhs)
+    }
+}
+
+pub impl<T:Copy Number, RHS:VectorMulRHS<T, Vec2<T>>> Vec2<T>: Mul<RHS, Vec2<T>> {
+    #[inline(always)]
+    pure fn mul(&self, rhs: &RHS) -> Vec2<T> {
+        rhs.rmul_v(self)
+    }
+}
+
+pub impl<T:Copy Number, RHS:VectorMulRHS<T, Vec2<T>>> Vec2<T>: Div<RHS, Vec2<T>> {
+    #[inline(always)]
+    pure fn div(&self, rhs: &RHS) -> Vec2<T> {
+        rhs.rdiv_v(self)
+    }
+}
+
+pub impl<T:Copy Number> Vec2<T>: VectorMulRHS<T, Vec2<T>> {
+    #[inline(always)]
+    pure fn rmul_v(&self, lhs: &Vec2<T>) -> Vec2<T> {
+        lhs.mul_v(self)
+    }
+    
+    #[inline(always)]
+    pure fn rdiv_v(&self, lhs: &Vec2<T>) -> Vec2<T> {
+        lhs.div_v(self)
+    }
+}
+
 pub impl<T:Copy Number> Vec2<T>: NumericVector2<T> {
     #[inline(always)]
     static pure fn unit_x() -> Vec2<T> {
@@ -285,6 +337,305 @@ pub impl<T:Copy Float> Vec2<T>: MutableEuclideanVector<&self/T> {
     }
 }
 
+pub impl<T:Copy Float> Vec2<T>: TrigVec<T> {
+    #[inline(always)]
+    pure fn radians(&self) -> Vec2<T> {
+        Vector2::new(radians(self[0]),
+                     radians(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn degrees(&self) -> Vec2<T> {
+        Vector2::new(degrees(self[0]),
+                     degrees(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn sin(&self) -> Vec2<T> {
+        Vector2::new(sin(self[0]),
+                     sin(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn cos(&self) -> Vec2<T> {
+        Vector2::new(cos(self[0]),
+                     cos(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn tan(&self) -> Vec2<T> {
+        Vector2::new(tan(self[0]),
+                     tan(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn asin(&self) -> Vec2<T> {
+        Vector2::new(asin(self[0]),
+                     asin(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn acos(&self) -> Vec2<T> {
+        Vector2::new(acos(self[0]),
+                     acos(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn atan(&self) -> Vec2<T> {
+        Vector2::new(atan(self[0]),
+                     atan(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn atan2(&self, other: Vec2<T>) -> Vec2<T> {
+        Vector2::new(atan2(self[0], other[0]),
+                     atan2(self[1], other[1]))
+    }
+    
+    #[inline(always)]
+    pure fn sinh(&self) -> Vec2<T> {
+        Vector2::new(sinh(self[0]),
+                     sinh(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn cosh(&self) -> Vec2<T> {
+        Vector2::new(cosh(self[0]),
+                     cosh(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn tanh(&self) -> Vec2<T> {
+        Vector2::new(tanh(self[0]),
+                     tanh(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn asinh(&self) -> Vec2<T> {
+        Vector2::new(asinh(self[0]),
+                     asinh(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn acosh(&self) -> Vec2<T> {
+        Vector2::new(acosh(self[0]),
+                     acosh(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn atanh(&self) -> Vec2<T> {
+        Vector2::new(atanh(self[0]),
+                     atanh(self[1]))
+    }
+}
+
+pub impl<T:Copy Float> Vec2<T>: ExpVec<T> {
+    #[inline(always)]
+    pure fn pow_t(&self, n: T) -> Vec2<T> {
+        Vector2::new(pow(self[0], n),
+                     pow(self[1], n))
+    }
+    
+    #[inline(always)]
+    pure fn pow_v(&self, n: &Vec2<T>) -> Vec2<T> {
+        Vector2::new(pow(self[0], n[0]),
+                     pow(self[1], n[1]))
+    }
+    
+    #[inline(always)]
+    pure fn exp(&self) -> Vec2<T> {
+        Vector2::new(exp(self[0]),
+                     exp(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn exp2(&self) -> Vec2<T> {
+        Vector2::new(exp2(self[0]),
+                     exp2(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn ln(&self) -> Vec2<T> {
+        Vector2::new(ln(self[0]),
+                     ln(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn ln2(&self) -> Vec2<T> {
+        Vector2::new(log2(self[0]),
+                     log2(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn sqrt(&self) -> Vec2<T> {
+        Vector2::new(sqrt(self[0]),
+                     sqrt(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn inv_sqrt(&self) -> Vec2<T> {
+        Vector2::new(inv_sqrt(self[0]),
+                     inv_sqrt(self[1]))
+    }
+}
+
+pub impl<T:Copy Float> Vec2<T>: ApproxVec<T> {
+    #[inline(always)]
+    pure fn floor(&self) -> Vec2<T> {
+        Vector2::new(floor(self[0]),
+                     floor(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn trunc(&self) -> Vec2<T> {
+        Vector2::new(trunc(self[0]),
+                     trunc(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn round(&self) -> Vec2<T> {
+        Vector2::new(round(self[0]),
+                     round(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn round_even(&self) -> Vec2<T> {
+        Vector2::new(round_even(self[0]),
+                     round_even(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn ceil(&self) -> Vec2<T> {
+        Vector2::new(ceil(self[0]),
+                     ceil(self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn fract(&self) -> Vec2<T> {
+        Vector2::new(fract(self[0]),
+                     fract(self[1]))
+    }
+}
+
+pub impl<T:Copy Number Ord> Vec2<T>: SignedVec<T, Vec2<bool>> {
+    #[inline(always)]
+    pure fn is_positive(&self) -> Vec2<bool> {
+        Vector2::new(self[0] > zero(),
+                     self[1] > zero())
+    }
+    
+    #[inline(always)]
+    pure fn is_negative(&self) -> Vec2<bool> {
+        Vector2::new(self[0] < zero(),
+                     self[1] < zero())
+    }
+    
+    #[inline(always)]
+    pure fn is_nonpositive(&self) -> Vec2<bool> {
+        Vector2::new(self[0] <= zero(),
+                     self[1] <= zero())
+    }
+    
+    #[inline(always)]
+    pure fn is_nonnegative(&self) -> Vec2<bool> {
+        Vector2::new(self[0] >= zero(),
+                     self[1] >= zero())
+    }
+    
+    #[inline(always)]
+    pure fn abs(&self) -> Vec2<T> {
+        Vector2::new(if self[0] < zero() { -self[0] } else { self[0] },
+                     if self[1] < zero() { -self[1] } else { self[1] })
+    }
+    
+    #[inline(always)]
+    pure fn sign(&self) -> Vec2<T> {
+        Vector2::new(if self[0] > zero() { one() } else if self[0] < zero() { -one::<T>() } else { zero() },
+                     if self[1] > zero() { one() } else if self[1] < zero() { -one::<T>() } else { zero() })
+    }
+    
+    #[inline(always)]
+    pure fn copysign(&self, other: Vec2<T>) -> Vec2<T> {
+        let a = self.abs();
+        Vector2::new(if other[0] < zero() { -a[0] } else { a[0] },
+                     if other[1] < zero() { -a[1] } else { a[1] })
+    }
+}
+
+pub impl<T:Copy Number Ord> Vec2<T>: ExtentVec<T> {
+    #[inline(always)]
+    pure fn min_v(&self, other: &Vec2<T>) -> Vec2<T> {
+        Vector2::new(if other[0] < self[0] { other[0] } else { self[0] },
+                     if other[1] < self[1] { other[1] } else { self[1] })
+    }
+    
+    #[inline(always)]
+    pure fn max_v(&self, other: &Vec2<T>) -> Vec2<T> {
+        Vector2::new(if other[0] > self[0] { other[0] } else { self[0] },
+                     if other[1] > self[1] { other[1] } else { self[1] })
+    }
+    
+    #[inline(always)]
+    pure fn clamp_v(&self, mn: &Vec2<T>, mx: &Vec2<T>) -> Vec2<T> {
+        self.max_v(mn).min_v(mx)
+    }
+    
+    #[inline(always)]
+    pure fn min_t(&self, other: T) -> Vec2<T> {
+        Vector2::new(if other < self[0] { other } else { self[0] },
+                     if other < self[1] { other } else { self[1] })
+    }
+    
+    #[inline(always)]
+    pure fn max_t(&self, other: T) -> Vec2<T> {
+        Vector2::new(if other > self[0] { other } else { self[0] },
+                     if other > self[1] { other } else { self[1] })
+    }
+    
+    #[inline(always)]
+    pure fn clamp_t(&self, mn: T, mx: T) -> Vec2<T> {
+        self.max_t(mn).min_t(mx)
+    }
+}
+
+pub impl<T:Copy Float> Vec2<T>: MixVec<T> {
+    #[inline(always)]
+    pure fn mix_v(&self, other: &Vec2<T>, value: &Vec2<T>) -> Vec2<T> {
+        Vector2::new(self[0] * (one::<T>() - value[0]) + other[0] * value[0],
+                     self[1] * (one::<T>() - value[1]) + other[1] * value[1])
+    }
+    
+    #[inline(always)]
+    pure fn mix_t(&self, other: &Vec2<T>, value: T) -> Vec2<T> {
+        Vector2::new(self[0] * (one::<T>() - value) + other[0] * value,
+                     self[1] * (one::<T>() - value) + other[1] * value)
+    }
+    
+    #[inline(always)]
+    pure fn smooth_step_v(&self, edge0: &Vec2<T>, edge1: &Vec2<T>) -> Vec2<T> {
+        Vector2::new(smooth_step(edge0[0], edge1[0], self[0]),
+                     smooth_step(edge0[1], edge1[1], self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn smooth_step_t(&self, edge0: T, edge1: T) -> Vec2<T> {
+        Vector2::new(smooth_step(edge0, edge1, self[0]),
+                     smooth_step(edge0, edge1, self[1]))
+    }
+    
+    #[inline(always)]
+    pure fn step_v(&self, edge: &Vec2<T>) -> Vec2<T> {
+        Vector2::new(if self[0] < edge[0] { zero() } else { one() },
+                     if self[1] < edge[1] { zero() } else { one() })
+    }
+    
+    #[inline(always)]
+    pure fn step_t(&self, edge: T) -> Vec2<T> {
+        Vector2::new(if self[0] < edge { zero() } else { one() },
+                     if self[1] < edge { zero() } else { one() })
+    }
+}
+
 pub impl<T:Copy Float FuzzyEq<T>> Vec2<T>: FuzzyEq<T> {
     #[inline(always)]
     pure fn fuzzy_eq(&self, other: &Vec2<T>) -> bool {
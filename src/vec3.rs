@@ -19,6 +19,7 @@ use vec::{
     NumericVector,
     NumericVector3,
     MutableNumericVector,
+    VectorMulRHS,
     MutableNumericVector3,
     ToHomogeneous,
     EuclideanVector,
@@ -26,6 +27,17 @@ use vec::{
     EquableVector,
     OrdinalVector,
     BooleanVector,
+    TrigVec,
+    ExpVec,
+    ApproxVec,
+    SignedVec,
+    ExtentVec,
+    MixVec,
+    asinh,
+    acosh,
+    atanh,
+    round_even,
+    smooth_step,
 };
 
 /**
@@ -168,6 +180,46 @@ pub impl<T:Copy Number> Vec3<T>: Neg<Vec3<T>> {
     }
 }
 
+pub impl<T:Copy Number> Vec3<T>: Add<Vec3<T>, Vec3<T>> {
+    #[inline(always)]
+    pure fn add(&self, rhs: &Vec3<T>) -> Vec3<T> {
+        self.add_v(rhs)
+    }
+}
+
+pub impl<T:Copy Number> Vec3<T>: Sub<Vec3<T>, Vec3<T>> {
+    #[inline(always)]
+    pure fn sub(&self, rhs: &Vec3<T>) -> Vec3<T> {
+        self.sub_v(rhs)
+    }
+}
+
+pub impl<T:Copy Number, RHS:VectorMulRHS<T, Vec3<T>>> Vec3<T>: Mul<RHS, Vec3<T>> {
+    #[inline(always)]
+    pure fn mul(&self, rhs: &RHS) -> Vec3<T> {
+        rhs.rmul_v(self)
+    }
+}
+
+pub impl<T:Copy Number, RHS:VectorMulRHS<T, Vec3<T>>> Vec3<T>: Div<RHS, Vec3<T>> {
+    #[inline(always)]
+    pure fn div(&self, rhs: &RHS) -> Vec3<T> {
+        rhs.rdiv_v(self)
+    }
+}
+
+pub impl<T:Copy Number> Vec3<T>: VectorMulRHS<T, Vec3<T>> {
+    #[inline(always)]
+    pure fn rmul_v(&self, lhs: &Vec3<T>) -> Vec3<T> {
+        lhs.mul_v(self)
+    }
+    
+    #[inline(always)]
+    pure fn rdiv_v(&self, lhs: &Vec3<T>) -> Vec3<T> {
+        lhs.div_v(self)
+    }
+}
+
 pub impl<T:Copy Number> Vec3<T>: NumericVector3<T> {
     #[inline(always)]
     static pure fn unit_x() -> Vec3<T> {
@@ -317,6 +369,351 @@ pub impl<T:Copy Float> Vec3<T>: MutableEuclideanVector<&self/T> {
     }
 }
 
+pub impl<T:Copy Float> Vec3<T>: TrigVec<T> {
+    #[inline(always)]
+    pure fn radians(&self) -> Vec3<T> {
+        Vector3::new(radians(self[0]),
+                     radians(self[1]),
+                     radians(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn degrees(&self) -> Vec3<T> {
+        Vector3::new(degrees(self[0]),
+                     degrees(self[1]),
+                     degrees(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn sin(&self) -> Vec3<T> {
+        Vector3::new(sin(self[0]),
+                     sin(self[1]),
+                     sin(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn cos(&self) -> Vec3<T> {
+        Vector3::new(cos(self[0]),
+                     cos(self[1]),
+                     cos(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn tan(&self) -> Vec3<T> {
+        Vector3::new(tan(self[0]),
+                     tan(self[1]),
+                     tan(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn asin(&self) -> Vec3<T> {
+        Vector3::new(asin(self[0]),
+                     asin(self[1]),
+                     asin(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn acos(&self) -> Vec3<T> {
+        Vector3::new(acos(self[0]),
+                     acos(self[1]),
+                     acos(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn atan(&self) -> Vec3<T> {
+        Vector3::new(atan(self[0]),
+                     atan(self[1]),
+                     atan(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn atan2(&self, other: Vec3<T>) -> Vec3<T> {
+        Vector3::new(atan2(self[0], other[0]),
+                     atan2(self[1], other[1]),
+                     atan2(self[2], other[2]))
+    }
+    
+    #[inline(always)]
+    pure fn sinh(&self) -> Vec3<T> {
+        Vector3::new(sinh(self[0]),
+                     sinh(self[1]),
+                     sinh(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn cosh(&self) -> Vec3<T> {
+        Vector3::new(cosh(self[0]),
+                     cosh(self[1]),
+                     cosh(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn tanh(&self) -> Vec3<T> {
+        Vector3::new(tanh(self[0]),
+                     tanh(self[1]),
+                     tanh(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn asinh(&self) -> Vec3<T> {
+        Vector3::new(asinh(self[0]),
+                     asinh(self[1]),
+                     asinh(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn acosh(&self) -> Vec3<T> {
+        Vector3::new(acosh(self[0]),
+                     acosh(self[1]),
+                     acosh(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn atanh(&self) -> Vec3<T> {
+        Vector3::new(atanh(self[0]),
+                     atanh(self[1]),
+                     atanh(self[2]))
+    }
+}
+
+pub impl<T:Copy Float> Vec3<T>: ExpVec<T> {
+    #[inline(always)]
+    pure fn pow_t(&self, n: T) -> Vec3<T> {
+        Vector3::new(pow(self[0], n),
+                     pow(self[1], n),
+                     pow(self[2], n))
+    }
+    
+    #[inline(always)]
+    pure fn pow_v(&self, n: &Vec3<T>) -> Vec3<T> {
+        Vector3::new(pow(self[0], n[0]),
+                     pow(self[1], n[1]),
+                     pow(self[2], n[2]))
+    }
+    
+    #[inline(always)]
+    pure fn exp(&self) -> Vec3<T> {
+        Vector3::new(exp(self[0]),
+                     exp(self[1]),
+                     exp(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn exp2(&self) -> Vec3<T> {
+        Vector3::new(exp2(self[0]),
+                     exp2(self[1]),
+                     exp2(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn ln(&self) -> Vec3<T> {
+        Vector3::new(ln(self[0]),
+                     ln(self[1]),
+                     ln(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn ln2(&self) -> Vec3<T> {
+        Vector3::new(log2(self[0]),
+                     log2(self[1]),
+                     log2(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn sqrt(&self) -> Vec3<T> {
+        Vector3::new(sqrt(self[0]),
+                     sqrt(self[1]),
+                     sqrt(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn inv_sqrt(&self) -> Vec3<T> {
+        Vector3::new(inv_sqrt(self[0]),
+                     inv_sqrt(self[1]),
+                     inv_sqrt(self[2]))
+    }
+}
+
+pub impl<T:Copy Float> Vec3<T>: ApproxVec<T> {
+    #[inline(always)]
+    pure fn floor(&self) -> Vec3<T> {
+        Vector3::new(floor(self[0]),
+                     floor(self[1]),
+                     floor(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn trunc(&self) -> Vec3<T> {
+        Vector3::new(trunc(self[0]),
+                     trunc(self[1]),
+                     trunc(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn round(&self) -> Vec3<T> {
+        Vector3::new(round(self[0]),
+                     round(self[1]),
+                     round(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn round_even(&self) -> Vec3<T> {
+        Vector3::new(round_even(self[0]),
+                     round_even(self[1]),
+                     round_even(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn ceil(&self) -> Vec3<T> {
+        Vector3::new(ceil(self[0]),
+                     ceil(self[1]),
+                     ceil(self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn fract(&self) -> Vec3<T> {
+        Vector3::new(fract(self[0]),
+                     fract(self[1]),
+                     fract(self[2]))
+    }
+}
+
+pub impl<T:Copy Number Ord> Vec3<T>: SignedVec<T, Vec3<bool>> {
+    #[inline(always)]
+    pure fn is_positive(&self) -> Vec3<bool> {
+        Vector3::new(self[0] > zero(),
+                     self[1] > zero(),
+                     self[2] > zero())
+    }
+    
+    #[inline(always)]
+    pure fn is_negative(&self) -> Vec3<bool> {
+        Vector3::new(self[0] < zero(),
+                     self[1] < zero(),
+                     self[2] < zero())
+    }
+    
+    #[inline(always)]
+    pure fn is_nonpositive(&self) -> Vec3<bool> {
+        Vector3::new(self[0] <= zero(),
+                     self[1] <= zero(),
+                     self[2] <= zero())
+    }
+    
+    #[inline(always)]
+    pure fn is_nonnegative(&self) -> Vec3<bool> {
+        Vector3::new(self[0] >= zero(),
+                     self[1] >= zero(),
+                     self[2] >= zero())
+    }
+    
+    #[inline(always)]
+    pure fn abs(&self) -> Vec3<T> {
+        Vector3::new(if self[0] < zero() { -self[0] } else { self[0] },
+                     if self[1] < zero() { -self[1] } else { self[1] },
+                     if self[2] < zero() { -self[2] } else { self[2] })
+    }
+    
+    #[inline(always)]
+    pure fn sign(&self) -> Vec3<T> {
+        Vector3::new(if self[0] > zero() { one() } else if self[0] < zero() { -one::<T>() } else { zero() },
+                     if self[1] > zero() { one() } else if self[1] < zero() { -one::<T>() } else { zero() },
+                     if self[2] > zero() { one() } else if self[2] < zero() { -one::<T>() } else { zero() })
+    }
+    
+    #[inline(always)]
+    pure fn copysign(&self, other: Vec3<T>) -> Vec3<T> {
+        let a = self.abs();
+        Vector3::new(if other[0] < zero() { -a[0] } else { a[0] },
+                     if other[1] < zero() { -a[1] } else { a[1] },
+                     if other[2] < zero() { -a[2] } else { a[2] })
+    }
+}
+
+pub impl<T:Copy Number Ord> Vec3<T>: ExtentVec<T> {
+    #[inline(always)]
+    pure fn min_v(&self, other: &Vec3<T>) -> Vec3<T> {
+        Vector3::new(if other[0] < self[0] { other[0] } else { self[0] },
+                     if other[1] < self[1] { other[1] } else { self[1] },
+                     if other[2] < self[2] { other[2] } else { self[2] })
+    }
+    
+    #[inline(always)]
+    pure fn max_v(&self, other: &Vec3<T>) -> Vec3<T> {
+        Vector3::new(if other[0] > self[0] { other[0] } else { self[0] },
+                     if other[1] > self[1] { other[1] } else { self[1] },
+                     if other[2] > self[2] { other[2] } else { self[2] })
+    }
+    
+    #[inline(always)]
+    pure fn clamp_v(&self, mn: &Vec3<T>, mx: &Vec3<T>) -> Vec3<T> {
+        self.max_v(mn).min_v(mx)
+    }
+    
+    #[inline(always)]
+    pure fn min_t(&self, other: T) -> Vec3<T> {
+        Vector3::new(if other < self[0] { other } else { self[0] },
+                     if other < self[1] { other } else { self[1] },
+                     if other < self[2] { other } else { self[2] })
+    }
+    
+    #[inline(always)]
+    pure fn max_t(&self, other: T) -> Vec3<T> {
+        Vector3::new(if other > self[0] { other } else { self[0] },
+                     if other > self[1] { other } else { self[1] },
+                     if other > self[2] { other } else { self[2] })
+    }
+    
+    #[inline(always)]
+    pure fn clamp_t(&self, mn: T, mx: T) -> Vec3<T> {
+        self.max_t(mn).min_t(mx)
+    }
+}
+
+pub impl<T:Copy Float> Vec3<T>: MixVec<T> {
+    #[inline(always)]
+    pure fn mix_v(&self, other: &Vec3<T>, value: &Vec3<T>) -> Vec3<T> {
+        Vector3::new(self[0] * (one::<T>() - value[0]) + other[0] * value[0],
+                     self[1] * (one::<T>() - value[1]) + other[1] * value[1],
+                     self[2] * (one::<T>() - value[2]) + other[2] * value[2])
+    }
+    
+    #[inline(always)]
+    pure fn mix_t(&self, other: &Vec3<T>, value: T) -> Vec3<T> {
+        Vector3::new(self[0] * (one::<T>() - value) + other[0] * value,
+                     self[1] * (one::<T>() - value) + other[1] * value,
+                     self[2] * (one::<T>() - value) + other[2] * value)
+    }
+    
+    #[inline(always)]
+    pure fn smooth_step_v(&self, edge0: &Vec3<T>, edge1: &Vec3<T>) -> Vec3<T> {
+        Vector3::new(smooth_step(edge0[0], edge1[0], self[0]),
+                     smooth_step(edge0[1], edge1[1], self[1]),
+                     smooth_step(edge0[2], edge1[2], self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn smooth_step_t(&self, edge0: T, edge1: T) -> Vec3<T> {
+        Vector3::new(smooth_step(edge0, edge1, self[0]),
+                     smooth_step(edge0, edge1, self[1]),
+                     smooth_step(edge0, edge1, self[2]))
+    }
+    
+    #[inline(always)]
+    pure fn step_v(&self, edge: &Vec3<T>) -> Vec3<T> {
+        Vector3::new(if self[0] < edge[0] { zero() } else { one() },
+                     if self[1] < edge[1] { zero() } else { one() },
+                     if self[2] < edge[2] { zero() } else { one() })
+    }
+    
+    #[inline(always)]
+    pure fn step_t(&self, edge: T) -> Vec3<T> {
+        Vector3::new(if self[0] < edge { zero() } else { one() },
+                     if self[1] < edge { zero() } else { one() },
+                     if self[2] < edge { zero() } else { one() })
+    }
+}
+
 pub impl<T:Copy Float FuzzyEq<T>> Vec3<T>: FuzzyEq<T> {
     #[inline(always)]
     pure fn fuzzy_eq(&self, other: &Vec3<T>) -> bool {
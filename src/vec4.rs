@@ -17,12 +17,24 @@ use vec::{
     NumericVector,
     NumericVector4,
     MutableNumericVector,
+    VectorMulRHS,
     ToHomogeneous,
     EuclideanVector,
     MutableEuclideanVector,
     EquableVector,
     OrdinalVector,
     BooleanVector,
+    TrigVec,
+    ExpVec,
+    ApproxVec,
+    SignedVec,
+    ExtentVec,
+    MixVec,
+    asinh,
+    acosh,
+    atanh,
+    round_even,
+    smooth_step,
 };
 
 /**
@@ -175,6 +187,46 @@ pub impl<T:Copy Number> Vec4<T>: Neg<Vec4<T>> {
     }
 }
 
+pub impl<T:Copy Number> Vec4<T>: Add<Vec4<T>, Vec4<T>> {
+    #[inline(always)]
+    pure fn add(&self, rhs: &Vec4<T>) -> Vec4<T> {
+        self.add_v(rhs)
+    }
+}
+
+pub impl<T:Copy Number> Vec4<T>: Sub<Vec4<T>, Vec4<T>> {
+    #[inline(always)]
+    pure fn sub(&self, rhs: &Vec4<T>) -> Vec4<T> {
+        self.sub_v(rhs)
+    }
+}
+
+pub impl<T:Copy Number, RHS:VectorMulRHS<T, Vec4<T>>> Vec4<T>: Mul<RHS, Vec4<T>> {
+    #[inline(always)]
+    pure fn mul(&self, rhs: &RHS) -> Vec4<T> {
+        rhs.rmul_v(self)
+    }
+}
+
+pub impl<T:Copy Number, RHS:VectorMulRHS<T, Vec4<T>>> Vec4<T>: Div<RHS, Vec4<T>> {
+    #[inline(always)]
+    pure fn div(&self, rhs: &RHS) -> Vec4<T> {
+        rhs.rdiv_v(self)
+    }
+}
+
+pub impl<T:Copy Number> Vec4<T>: VectorMulRHS<T, Vec4<T>> {
+    #[inline(always)]
+    pure fn rmul_v(&self, lhs: &Vec4<T>) -> Vec4<T> {
+        lhs.mul_v(self)
+    }
+    
+    #[inline(always)]
+    pure fn rdiv_v(&self, lhs: &Vec4<T>) -> Vec4<T> {
+        lhs.div_v(self)
+    }
+}
+
 pub impl<T:Copy Number> Vec4<T>: NumericVector4<T> {
     #[inline(always)]
     static pure fn unit_x() -> Vec4<T> {
@@ -315,6 +367,397 @@ pub impl<T:Copy Float> Vec4<T>: MutableEuclideanVector<&self/T> {
     }
 }
 
+pub impl<T:Copy Float> Vec4<T>: TrigVec<T> {
+    #[inline(always)]
+    pure fn radians(&self) -> Vec4<T> {
+        Vector4::new(radians(self[0]),
+                     radians(self[1]),
+                     radians(self[2]),
+                     radians(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn degrees(&self) -> Vec4<T> {
+        Vector4::new(degrees(self[0]),
+                     degrees(self[1]),
+                     degrees(self[2]),
+                     degrees(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn sin(&self) -> Vec4<T> {
+        Vector4::new(sin(self[0]),
+                     sin(self[1]),
+                     sin(self[2]),
+                     sin(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn cos(&self) -> Vec4<T> {
+        Vector4::new(cos(self[0]),
+                     cos(self[1]),
+                     cos(self[2]),
+                     cos(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn tan(&self) -> Vec4<T> {
+        Vector4::new(tan(self[0]),
+                     tan(self[1]),
+                     tan(self[2]),
+                     tan(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn asin(&self) -> Vec4<T> {
+        Vector4::new(asin(self[0]),
+                     asin(self[1]),
+                     asin(self[2]),
+                     asin(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn acos(&self) -> Vec4<T> {
+        Vector4::new(acos(self[0]),
+                     acos(self[1]),
+                     acos(self[2]),
+                     acos(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn atan(&self) -> Vec4<T> {
+        Vector4::new(atan(self[0]),
+                     atan(self[1]),
+                     atan(self[2]),
+                     atan(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn atan2(&self, other: Vec4<T>) -> Vec4<T> {
+        Vector4::new(atan2(self[0], other[0]),
+                     atan2(self[1], other[1]),
+                     atan2(self[2], other[2]),
+                     atan2(self[3], other[3]))
+    }
+    
+    #[inline(always)]
+    pure fn sinh(&self) -> Vec4<T> {
+        Vector4::new(sinh(self[0]),
+                     sinh(self[1]),
+                     sinh(self[2]),
+                     sinh(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn cosh(&self) -> Vec4<T> {
+        Vector4::new(cosh(self[0]),
+                     cosh(self[1]),
+                     cosh(self[2]),
+                     cosh(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn tanh(&self) -> Vec4<T> {
+        Vector4::new(tanh(self[0]),
+                     tanh(self[1]),
+                     tanh(self[2]),
+                     tanh(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn asinh(&self) -> Vec4<T> {
+        Vector4::new(asinh(self[0]),
+                     asinh(self[1]),
+                     asinh(self[2]),
+                     asinh(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn acosh(&self) -> Vec4<T> {
+        Vector4::new(acosh(self[0]),
+                     acosh(self[1]),
+                     acosh(self[2]),
+                     acosh(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn atanh(&self) -> Vec4<T> {
+        Vector4::new(atanh(self[0]),
+                     atanh(self[1]),
+                     atanh(self[2]),
+                     atanh(self[3]))
+    }
+}
+
+pub impl<T:Copy Float> Vec4<T>: ExpVec<T> {
+    #[inline(always)]
+    pure fn pow_t(&self, n: T) -> Vec4<T> {
+        Vector4::new(pow(self[0], n),
+                     pow(self[1], n),
+                     pow(self[2], n),
+                     pow(self[3], n))
+    }
+    
+    #[inline(always)]
+    pure fn pow_v(&self, n: &Vec4<T>) -> Vec4<T> {
+        Vector4::new(pow(self[0], n[0]),
+                     pow(self[1], n[1]),
+                     pow(self[2], n[2]),
+                     pow(self[3], n[3]))
+    }
+    
+    #[inline(always)]
+    pure fn exp(&self) -> Vec4<T> {
+        Vector4::new(exp(self[0]),
+                     exp(self[1]),
+                     exp(self[2]),
+                     exp(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn exp2(&self) -> Vec4<T> {
+        Vector4::new(exp2(self[0]),
+                     exp2(self[1]),
+                     exp2(self[2]),
+                     exp2(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn ln(&self) -> Vec4<T> {
+        Vector4::new(ln(self[0]),
+                     ln(self[1]),
+                     ln(self[2]),
+                     ln(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn ln2(&self) -> Vec4<T> {
+        Vector4::new(log2(self[0]),
+                     log2(self[1]),
+                     log2(self[2]),
+                     log2(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn sqrt(&self) -> Vec4<T> {
+        Vector4::new(sqrt(self[0]),
+                     sqrt(self[1]),
+                     sqrt(self[2]),
+                     sqrt(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn inv_sqrt(&self) -> Vec4<T> {
+        Vector4::new(inv_sqrt(self[0]),
+                     inv_sqrt(self[1]),
+                     inv_sqrt(self[2]),
+                     inv_sqrt(self[3]))
+    }
+}
+
+pub impl<T:Copy Float> Vec4<T>: ApproxVec<T> {
+    #[inline(always)]
+    pure fn floor(&self) -> Vec4<T> {
+        Vector4::new(floor(self[0]),
+                     floor(self[1]),
+                     floor(self[2]),
+                     floor(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn trunc(&self) -> Vec4<T> {
+        Vector4::new(trunc(self[0]),
+                     trunc(self[1]),
+                     trunc(self[2]),
+                     trunc(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn round(&self) -> Vec4<T> {
+        Vector4::new(round(self[0]),
+                     round(self[1]),
+                     round(self[2]),
+                     round(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn round_even(&self) -> Vec4<T> {
+        Vector4::new(round_even(self[0]),
+                     round_even(self[1]),
+                     round_even(self[2]),
+                     round_even(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn ceil(&self) -> Vec4<T> {
+        Vector4::new(ceil(self[0]),
+                     ceil(self[1]),
+                     ceil(self[2]),
+                     ceil(self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn fract(&self) -> Vec4<T> {
+        Vector4::new(fract(self[0]),
+                     fract(self[1]),
+                     fract(self[2]),
+                     fract(self[3]))
+    }
+}
+
+pub impl<T:Copy Number Ord> Vec4<T>: SignedVec<T, Vec4<bool>> {
+    #[inline(always)]
+    pure fn is_positive(&self) -> Vec4<bool> {
+        Vector4::new(self[0] > zero(),
+                     self[1] > zero(),
+                     self[2] > zero(),
+                     self[3] > zero())
+    }
+    
+    #[inline(always)]
+    pure fn is_negative(&self) -> Vec4<bool> {
+        Vector4::new(self[0] < zero(),
+                     self[1] < zero(),
+                     self[2] < zero(),
+                     self[3] < zero())
+    }
+    
+    #[inline(always)]
+    pure fn is_nonpositive(&self) -> Vec4<bool> {
+        Vector4::new(self[0] <= zero(),
+                     self[1] <= zero(),
+                     self[2] <= zero(),
+                     self[3] <= zero())
+    }
+    
+    #[inline(always)]
+    pure fn is_nonnegative(&self) -> Vec4<bool> {
+        Vector4::new(self[0] >= zero(),
+                     self[1] >= zero(),
+                     self[2] >= zero(),
+                     self[3] >= zero())
+    }
+    
+    #[inline(always)]
+    pure fn abs(&self) -> Vec4<T> {
+        Vector4::new(if self[0] < zero() { -self[0] } else { self[0] },
+                     if self[1] < zero() { -self[1] } else { self[1] },
+                     if self[2] < zero() { -self[2] } else { self[2] },
+                     if self[3] < zero() { -self[3] } else { self[3] })
+    }
+    
+    #[inline(always)]
+    pure fn sign(&self) -> Vec4<T> {
+        Vector4::new(if self[0] > zero() { one() } else if self[0] < zero() { -one::<T>() } else { zero() },
+                     if self[1] > zero() { one() } else if self[1] < zero() { -one::<T>() } else { zero() },
+                     if self[2] > zero() { one() } else if self[2] < zero() { -one::<T>() } else { zero() },
+                     if self[3] > zero() { one() } else if self[3] < zero() { -one::<T>() } else { zero() })
+    }
+    
+    #[inline(always)]
+    pure fn copysign(&self, other: Vec4<T>) -> Vec4<T> {
+        let a = self.abs();
+        Vector4::new(if other[0] < zero() { -a[0] } else { a[0] },
+                     if other[1] < zero() { -a[1] } else { a[1] },
+                     if other[2] < zero() { -a[2] } else { a[2] },
+                     if other[3] < zero() { -a[3] } else { a[3] })
+    }
+}
+
+pub impl<T:Copy Number Ord> Vec4<T>: ExtentVec<T> {
+    #[inline(always)]
+    pure fn min_v(&self, other: &Vec4<T>) -> Vec4<T> {
+        Vector4::new(if other[0] < self[0] { other[0] } else { self[0] },
+                     if other[1] < self[1] { other[1] } else { self[1] },
+                     if other[2] < self[2] { other[2] } else { self[2] },
+                     if other[3] < self[3] { other[3] } else { self[3] })
+    }
+    
+    #[inline(always)]
+    pure fn max_v(&self, other: &Vec4<T>) -> Vec4<T> {
+        Vector4::new(if other[0] > self[0] { other[0] } else { self[0] },
+                     if other[1] > self[1] { other[1] } else { self[1] },
+                     if other[2] > self[2] { other[2] } else { self[2] },
+                     if other[3] > self[3] { other[3] } else { self[3] })
+    }
+    
+    #[inline(always)]
+    pure fn clamp_v(&self, mn: &Vec4<T>, mx: &Vec4<T>) -> Vec4<T> {
+        self.max_v(mn).min_v(mx)
+    }
+    
+    #[inline(always)]
+    pure fn min_t(&self, other: T) -> Vec4<T> {
+        Vector4::new(if other < self[0] { other } else { self[0] },
+                     if other < self[1] { other } else { self[1] },
+                     if other < self[2] { other } else { self[2] },
+                     if other < self[3] { other } else { self[3] })
+    }
+    
+    #[inline(always)]
+    pure fn max_t(&self, other: T) -> Vec4<T> {
+        Vector4::new(if other > self[0] { other } else { self[0] },
+                     if other > self[1] { other } else { self[1] },
+                     if other > self[2] { other } else { self[2] },
+                     if other > self[3] { other } else { self[3] })
+    }
+    
+    #[inline(always)]
+    pure fn clamp_t(&self, mn: T, mx: T) -> Vec4<T> {
+        self.max_t(mn).min_t(mx)
+    }
+}
+
+pub impl<T:Copy Float> Vec4<T>: MixVec<T> {
+    #[inline(always)]
+    pure fn mix_v(&self, other: &Vec4<T>, value: &Vec4<T>) -> Vec4<T> {
+        Vector4::new(self[0] * (one::<T>() - value[0]) + other[0] * value[0],
+                     self[1] * (one::<T>() - value[1]) + other[1] * value[1],
+                     self[2] * (one::<T>() - value[2]) + other[2] * value[2],
+                     self[3] * (one::<T>() - value[3]) + other[3] * value[3])
+    }
+    
+    #[inline(always)]
+    pure fn mix_t(&self, other: &Vec4<T>, value: T) -> Vec4<T> {
+        Vector4::new(self[0] * (one::<T>() - value) + other[0] * value,
+                     self[1] * (one::<T>() - value) + other[1] * value,
+                     self[2] * (one::<T>() - value) + other[2] * value,
+                     self[3] * (one::<T>() - value) + other[3] * value)
+    }
+    
+    #[inline(always)]
+    pure fn smooth_step_v(&self, edge0: &Vec4<T>, edge1: &Vec4<T>) -> Vec4<T> {
+        Vector4::new(smooth_step(edge0[0], edge1[0], self[0]),
+                     smooth_step(edge0[1], edge1[1], self[1]),
+                     smooth_step(edge0[2], edge1[2], self[2]),
+                     smooth_step(edge0[3], edge1[3], self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn smooth_step_t(&self, edge0: T, edge1: T) -> Vec4<T> {
+        Vector4::new(smooth_step(edge0, edge1, self[0]),
+                     smooth_step(edge0, edge1, self[1]),
+                     smooth_step(edge0, edge1, self[2]),
+                     smooth_step(edge0, edge1, self[3]))
+    }
+    
+    #[inline(always)]
+    pure fn step_v(&self, edge: &Vec4<T>) -> Vec4<T> {
+        Vector4::new(if self[0] < edge[0] { zero() } else { one() },
+                     if self[1] < edge[1] { zero() } else { one() },
+                     if self[2] < edge[2] { zero() } else { one() },
+                     if self[3] < edge[3] { zero() } else { one() })
+    }
+    
+    #[inline(always)]
+    pure fn step_t(&self, edge: T) -> Vec4<T> {
+        Vector4::new(if self[0] < edge { zero() } else { one() },
+                     if self[1] < edge { zero() } else { one() },
+                     if self[2] < edge { zero() } else { one() },
+                     if self[3] < edge { zero() } else { one() })
+    }
+}
+
 pub impl<T:Copy Float FuzzyEq<T>> Vec4<T>: FuzzyEq<T> {
     #[inline(always)]
     pure fn fuzzy_eq(&self, other: &Vec4<T>) -> bool {